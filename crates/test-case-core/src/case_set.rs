@@ -0,0 +1,129 @@
+use crate::TestCase;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, parenthesized, Attribute, Ident, Token, Visibility};
+
+/// Contents of `case_set! { name => { (1, 2 => 3), (4, 5 => 9) } }`, declaring named sets of
+/// cases, which are applied to functions with `#[use_case_set(name)]`. Sets declared as `pub` are
+/// exported from the crate, `pub(crate)` ones can be used by path within the crate.
+#[derive(Debug)]
+pub struct CaseSets {
+    sets: Vec<CaseSet>,
+}
+
+#[derive(Debug)]
+struct CaseSet {
+    /// Attributes of the macro, e.g. documentation.
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    /// Arguments of `#[test_case(...)]` of each case.
+    cases: Vec<TokenStream2>,
+}
+
+impl Parse for CaseSets {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut sets = vec![];
+        while !input.is_empty() {
+            let attrs = input.call(Attribute::parse_outer)?;
+            let vis = input.parse()?;
+            let name = input.parse()?;
+            let _: Token![=>] = input.parse()?;
+            let content;
+            braced!(content in input);
+            let cases = Punctuated::<CaseArgs, Token![,]>::parse_terminated(&content)?;
+            sets.push(CaseSet {
+                attrs,
+                vis,
+                name,
+                cases: cases.into_iter().map(|case| case.0).collect(),
+            });
+            if !input.is_empty() {
+                let _: Option<Token![,]> = input.parse()?;
+            }
+        }
+        Ok(Self { sets })
+    }
+}
+
+/// Arguments of a case in parentheses, e.g. `(1, 2 => 3)`.
+struct CaseArgs(TokenStream2);
+
+impl Parse for CaseArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let tokens: TokenStream2 = content.parse()?;
+        // Reported where the set is declared, rather than where it is used
+        syn::parse2::<TestCase>(tokens.clone())?;
+        Ok(Self(tokens))
+    }
+}
+
+impl CaseSets {
+    /// Macros named after the sets, which add `#[test_case(...)]` of their cases to the function
+    /// they are invoked with.
+    pub fn render(&self) -> TokenStream2 {
+        let macros = self.sets.iter().map(|set| {
+            let CaseSet {
+                attrs,
+                vis,
+                name,
+                cases,
+            } = set;
+            let (export, reexport) = match vis {
+                Visibility::Public(_) => (Some(quote! { #[macro_export] }), None),
+                Visibility::Restricted(_) => (
+                    None,
+                    Some(quote! {
+                        #[allow(unused_imports)]
+                        #vis use #name;
+                    }),
+                ),
+                Visibility::Inherited => (None, None),
+            };
+            quote! {
+                #(#attrs)*
+                #export
+                #[allow(unused_macros)]
+                macro_rules! #name {
+                    ($($item:tt)*) => {
+                        #(#[test_case::test_case(#cases)])*
+                        $($item)*
+                    };
+                }
+                #reexport
+            }
+        });
+        quote! { #(#macros)* }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CaseSets;
+    use syn::parse_quote;
+
+    #[test]
+    fn parses_sets() {
+        let sets: CaseSets = parse_quote! {
+            sums => { (1, 2 => 3), (4, 5 => 9 ; "larger"), },
+            /// Exported set
+            pub empty => {}
+        };
+        assert_eq!(sets.sets.len(), 2);
+        assert_eq!(sets.sets[1].attrs.len(), 1);
+        assert!(matches!(sets.sets[1].vis, syn::Visibility::Public(_)));
+        assert_eq!(sets.sets[0].name, "sums");
+        assert_eq!(sets.sets[0].cases.len(), 2);
+        assert!(sets.sets[1].cases.is_empty());
+    }
+
+    #[test]
+    fn reports_invalid_case() {
+        let error = syn::parse2::<CaseSets>(quote::quote! { sums => { (=> 3) } }).unwrap_err();
+        assert_eq!(error.to_string(), "expected an expression");
+    }
+}
@@ -0,0 +1,135 @@
+use crate::expr::TestCaseExpression;
+use crate::TestCase;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{Error, Expr, FnArg, Ident, Path, Signature, Token};
+
+/// Arguments of `#[test_cases(from = CASES)]`.
+///
+/// `from` is an array of cases, e.g. `[(1, 2, 3), (4, 5, 9)]`, or a const declared with
+/// `#[test_case::cases]`, whose macro expands to the former. Elements are tuples of arguments,
+/// optionally followed by expected result, functions with a single parameter take elements as is.
+#[derive(Debug)]
+pub struct TestCasesFrom {
+    from: Expr,
+}
+
+impl Parse for TestCasesFrom {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        if key != "from" {
+            return Err(Error::new(key.span(), "expected `from = ...`"));
+        }
+        let _: Token![=] = input.parse()?;
+        Ok(Self {
+            from: input.parse()?,
+        })
+    }
+}
+
+impl TestCasesFrom {
+    /// Macro carrying cases of const named by `from`, which has to be expanded first.
+    pub fn carrier(&self) -> Option<&Path> {
+        match &self.from {
+            Expr::Path(path) if path.qself.is_none() => Some(&path.path),
+            _ => None,
+        }
+    }
+
+    /// Cases for elements of array `from`, for function with signature `sig`.
+    pub fn cases(&self, sig: &Signature) -> syn::Result<Vec<TestCase>> {
+        let elements = match &self.from {
+            Expr::Array(array) => &array.elems,
+            Expr::Reference(reference) => match reference.expr.as_ref() {
+                Expr::Array(array) => &array.elems,
+                _ => return Err(Self::expected_array(&self.from)),
+            },
+            _ => return Err(Self::expected_array(&self.from)),
+        };
+        let params = sig
+            .inputs
+            .iter()
+            .filter(|input| matches!(input, FnArg::Typed(_)))
+            .count();
+
+        elements
+            .iter()
+            .map(|element| {
+                let mut args: Vec<Expr> = match element {
+                    Expr::Tuple(tuple)
+                        if tuple.elems.len() == params || tuple.elems.len() == params + 1 =>
+                    {
+                        tuple.elems.iter().cloned().collect()
+                    }
+                    _ if params == 1 => vec![element.clone()],
+                    _ => {
+                        return Err(Error::new(
+                            element.span(),
+                            format!(
+                                "expected tuple of {params} arguments, optionally followed by \
+                                 expected result"
+                            ),
+                        ))
+                    }
+                };
+                let expected = if args.len() > params {
+                    args.pop().map(|expected| quote! { => #expected })
+                } else {
+                    None
+                };
+                let expression = expected
+                    .clone()
+                    .map(syn::parse2::<TestCaseExpression>)
+                    .transpose()?;
+                let source = quote! { #(#args),* #expected };
+                Ok(TestCase::new(args, expression, None).with_source(source))
+            })
+            .collect()
+    }
+
+    fn expected_array(from: &Expr) -> Error {
+        Error::new(
+            from.span(),
+            "expected array of cases or const declared with `#[test_case::cases]`",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TestCasesFrom;
+    use syn::parse_quote;
+
+    #[test]
+    fn splits_elements_into_arguments_and_expected_result() {
+        let from: TestCasesFrom = parse_quote! { from = [(1, 2), (3, 4, 7)] };
+        let cases = from
+            .cases(&parse_quote! { fn add(a: i32, b: i32) })
+            .unwrap();
+        let names: Vec<_> = cases
+            .iter()
+            .map(|case| case.test_case_name().to_string())
+            .collect();
+        assert_eq!(names, ["_1_2_expects", "_3_4_expects_7"]);
+        assert!(from.carrier().is_none());
+    }
+
+    #[test]
+    fn takes_cases_of_const() {
+        let from: TestCasesFrom = parse_quote! { from = CASES };
+        assert!(from.carrier().unwrap().is_ident("CASES"));
+    }
+
+    #[test]
+    fn reports_elements_of_wrong_size() {
+        let from: TestCasesFrom = parse_quote! { from = [(1, 2, 3, 4)] };
+        let error = from
+            .cases(&parse_quote! { fn add(a: i32, b: i32) })
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected tuple of 2 arguments, optionally followed by expected result"
+        );
+    }
+}
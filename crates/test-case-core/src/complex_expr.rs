@@ -1,13 +1,16 @@
 use crate::utils::fmt_syn;
+use proc_macro2::Delimiter;
 use proc_macro2::Group;
 use proc_macro2::Span;
 use proc_macro2::TokenStream;
+use proc_macro2::TokenTree;
 use quote::{quote, TokenStreamExt};
 use std::fmt::{Display, Formatter};
 use syn::parse::{Parse, ParseStream};
-use syn::{parse_quote, Expr};
+use syn::punctuated::Punctuated;
+use syn::{braced, parenthesized, parse_quote, Expr, Member, Pat, Token};
 
-mod kw {
+pub(crate) mod kw {
     syn::custom_keyword!(eq);
     syn::custom_keyword!(equal_to);
     syn::custom_keyword!(lt);
@@ -21,12 +24,40 @@ mod kw {
     syn::custom_keyword!(almost);
     syn::custom_keyword!(almost_equal_to);
     syn::custom_keyword!(precision);
+    syn::custom_keyword!(eq_nan);
+    syn::custom_keyword!(bitwise_eq);
+    syn::custom_keyword!(close_to);
+    syn::custom_keyword!(of);
+    syn::custom_keyword!(within);
     syn::custom_keyword!(existing_path);
     syn::custom_keyword!(directory);
     syn::custom_keyword!(dir);
     syn::custom_keyword!(file);
     syn::custom_keyword!(contains);
     syn::custom_keyword!(contains_in_order);
+    syn::custom_keyword!(eq_unordered);
+    syn::custom_keyword!(cmp);
+    syn::custom_keyword!(has_key);
+    syn::custom_keyword!(has_entry);
+    syn::custom_keyword!(has_fields);
+    syn::custom_keyword!(json);
+    syn::custom_keyword!(serde_eq);
+    syn::custom_keyword!(debug_eq);
+    syn::custom_keyword!(bytes_eq);
+    syn::custom_keyword!(all);
+    syn::custom_keyword!(any);
+    syn::custom_keyword!(some);
+    syn::custom_keyword!(ok);
+    syn::custom_keyword!(err);
+    syn::custom_keyword!(matches);
+    syn::custom_keyword!(via);
+    syn::custom_keyword!(map);
+    syn::custom_keyword!(eq_ignore_case);
+    syn::custom_keyword!(unicode);
+    syn::custom_keyword!(eq_trimmed);
+    syn::custom_keyword!(eq_normalized_ws);
+    syn::custom_keyword!(starts_with);
+    syn::custom_keyword!(ends_with);
     syn::custom_keyword!(not);
     syn::custom_keyword!(and);
     syn::custom_keyword!(or);
@@ -37,6 +68,12 @@ mod kw {
     syn::custom_keyword!(empty);
     syn::custom_keyword!(matching_regex);
     syn::custom_keyword!(matches_regex);
+    syn::custom_keyword!(abs_diff_eq);
+    syn::custom_keyword!(relative_eq);
+    syn::custom_keyword!(ulps_eq);
+    syn::custom_keyword!(epsilon);
+    syn::custom_keyword!(max_relative);
+    syn::custom_keyword!(max_ulps);
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -55,6 +92,40 @@ pub enum PathToken {
     File,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StrEqToken {
+    IgnoreAsciiCase,
+    IgnoreCase,
+    Trimmed,
+    NormalizedWs,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WrappedToken {
+    Some,
+    Ok,
+    Err,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AffixToken {
+    Prefix,
+    Suffix,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FloatEqToken {
+    Nan,
+    Bitwise,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ApproxToken {
+    AbsDiff,
+    Relative,
+    Ulps,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Ord {
     pub token: OrderingToken,
@@ -67,6 +138,24 @@ pub struct AlmostEqual {
     pub precision: Box<Expr>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FloatEq {
+    pub token: FloatEqToken,
+    pub expected_value: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloseTo {
+    pub expected_value: Box<Expr>,
+    pub percentage: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Within {
+    pub tolerance: Box<Expr>,
+    pub expected_value: Box<Expr>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Path {
     pub token: PathToken,
@@ -82,13 +171,108 @@ pub struct ContainsInOrder {
     pub expected_slice: Box<Expr>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EqUnordered {
+    pub expected_elements: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InRange {
+    pub range: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cmp {
+    pub comparator: Box<Expr>,
+    pub expected_value: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HasKey {
+    pub expected_key: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HasEntry {
+    pub expected_key: Box<Expr>,
+    pub expected_value: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HasFields {
+    pub fields: Vec<FieldMatcher>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldMatcher {
+    pub member: Member,
+    pub matcher: ComplexTestCase,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tuple {
+    pub elements: Vec<ComplexTestCase>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DebugEq {
+    pub expected_value: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BytesEq {
+    pub expected_value: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct All {
+    pub predicate: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Any {
+    pub predicate: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matches {
+    pub pattern: Box<Pat>,
+    pub guard: Option<Box<Expr>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Wrapped {
+    pub token: WrappedToken,
+    pub inner: Box<ComplexTestCase>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Via {
+    pub projection: Box<Expr>,
+    pub inner: Box<ComplexTestCase>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StrEq {
+    pub token: StrEqToken,
+    pub expected_value: Box<Expr>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Affix {
+    pub token: AffixToken,
+    pub expected_affix: Box<Expr>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Len {
+    pub token: OrderingToken,
     pub expected_len: Box<Expr>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Count {
+    pub token: OrderingToken,
     pub expected_len: Box<Expr>,
 }
 
@@ -98,6 +282,35 @@ pub struct Regex {
     pub expected_regex: Box<Expr>,
 }
 
+#[cfg(feature = "with-json")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Json {
+    pub expected_value: syn::Macro,
+}
+
+#[cfg(feature = "with-json")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerdeEq {
+    pub expected_value: Box<Expr>,
+}
+
+#[cfg(not(feature = "with-approx"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ulps {
+    pub expected_value: Box<Expr>,
+    pub max_ulps: Option<Box<Expr>>,
+}
+
+#[cfg(feature = "with-approx")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Approx {
+    pub token: ApproxToken,
+    pub expected_value: Box<Expr>,
+    pub epsilon: Option<Box<Expr>>,
+    pub max_relative: Option<Box<Expr>>,
+    pub max_ulps: Option<Box<Expr>>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum ComplexTestCase {
     Not(Box<ComplexTestCase>),
@@ -105,24 +318,49 @@ pub enum ComplexTestCase {
     Or(Vec<ComplexTestCase>),
     Ord(Ord),
     AlmostEqual(AlmostEqual),
+    FloatEq(FloatEq),
+    CloseTo(CloseTo),
+    Within(Within),
     Path(Path),
     Contains(Contains),
     ContainsInOrder(ContainsInOrder),
+    EqUnordered(EqUnordered),
+    InRange(InRange),
+    Cmp(Cmp),
+    HasKey(HasKey),
+    HasEntry(HasEntry),
+    HasFields(HasFields),
+    Tuple(Tuple),
+    DebugEq(DebugEq),
+    BytesEq(BytesEq),
+    All(All),
+    Any(Any),
+    Wrapped(Wrapped),
+    Via(Via),
+    Matches(Matches),
+    StrEq(StrEq),
+    Affix(Affix),
     Len(Len),
     Count(Count),
     Empty,
     #[cfg(feature = "with-regex")]
     Regex(Regex),
+    #[cfg(feature = "with-json")]
+    Json(Json),
+    #[cfg(feature = "with-json")]
+    SerdeEq(SerdeEq),
+    #[cfg(not(feature = "with-approx"))]
+    Ulps(Ulps),
+    #[cfg(feature = "with-approx")]
+    Approx(Approx),
 }
 
 impl Parse for ComplexTestCase {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let item = Self::parse_single_item(input)?;
+        let item = Self::parse_and(input)?;
 
-        Ok(if input.peek(kw::and) {
-            ComplexTestCase::And(parse_kw_repeat::<kw::and>(item, input)?)
-        } else if input.peek(kw::or) {
-            ComplexTestCase::Or(parse_kw_repeat::<kw::or>(item, input)?)
+        Ok(if input.peek(kw::or) {
+            ComplexTestCase::Or(parse_kw_repeat::<kw::or>(item, input, Self::parse_and)?)
         } else {
             item
         })
@@ -141,6 +379,55 @@ impl Display for OrderingToken {
     }
 }
 
+impl Display for StrEqToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StrEqToken::IgnoreAsciiCase => f.write_str("eq_ignore_case"),
+            StrEqToken::IgnoreCase => f.write_str("eq_ignore_case unicode"),
+            StrEqToken::Trimmed => f.write_str("eq_trimmed"),
+            StrEqToken::NormalizedWs => f.write_str("eq_normalized_ws"),
+        }
+    }
+}
+
+impl Display for WrappedToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WrappedToken::Some => f.write_str("some"),
+            WrappedToken::Ok => f.write_str("ok"),
+            WrappedToken::Err => f.write_str("err"),
+        }
+    }
+}
+
+impl Display for AffixToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AffixToken::Prefix => f.write_str("starts_with"),
+            AffixToken::Suffix => f.write_str("ends_with"),
+        }
+    }
+}
+
+impl Display for FloatEqToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FloatEqToken::Nan => f.write_str("eq_nan"),
+            FloatEqToken::Bitwise => f.write_str("bitwise_eq"),
+        }
+    }
+}
+
+impl Display for ApproxToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApproxToken::AbsDiff => f.write_str("abs_diff_eq"),
+            ApproxToken::Relative => f.write_str("relative_eq"),
+            ApproxToken::Ulps => f.write_str("ulps_eq"),
+        }
+    }
+}
+
 impl Display for PathToken {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -182,6 +469,28 @@ impl Display for ComplexTestCase {
                 fmt_syn(expected_value),
                 fmt_syn(precision)
             ),
+            ComplexTestCase::FloatEq(FloatEq {
+                token,
+                expected_value,
+            }) => write!(f, "{} {}", token, fmt_syn(expected_value)),
+            ComplexTestCase::CloseTo(CloseTo {
+                expected_value,
+                percentage,
+            }) => write!(
+                f,
+                "close_to {} within {}%",
+                fmt_syn(expected_value),
+                fmt_syn(percentage)
+            ),
+            ComplexTestCase::Within(Within {
+                tolerance,
+                expected_value,
+            }) => write!(
+                f,
+                "within {} of {}",
+                fmt_syn(tolerance),
+                fmt_syn(expected_value)
+            ),
             ComplexTestCase::Path(Path { token }) => write!(f, "path {token}"),
             ComplexTestCase::Contains(Contains { expected_element }) => {
                 write!(f, "contains {}", fmt_syn(expected_element))
@@ -189,12 +498,80 @@ impl Display for ComplexTestCase {
             ComplexTestCase::ContainsInOrder(ContainsInOrder { expected_slice }) => {
                 write!(f, "contains in order {}", fmt_syn(expected_slice))
             }
-            ComplexTestCase::Len(Len { expected_len }) => {
-                write!(f, "len {}", fmt_syn(expected_len))
+            ComplexTestCase::EqUnordered(EqUnordered { expected_elements }) => {
+                write!(f, "eq unordered {}", fmt_syn(expected_elements))
             }
-            ComplexTestCase::Count(Count { expected_len }) => {
-                write!(f, "count {}", fmt_syn(expected_len))
+            ComplexTestCase::InRange(InRange { range }) => write!(f, "in {}", fmt_syn(range)),
+            ComplexTestCase::Cmp(Cmp {
+                comparator,
+                expected_value,
+            }) => write!(f, "cmp {} {}", fmt_syn(comparator), fmt_syn(expected_value)),
+            ComplexTestCase::HasKey(HasKey { expected_key }) => {
+                write!(f, "has key {}", fmt_syn(expected_key))
+            }
+            ComplexTestCase::HasEntry(HasEntry {
+                expected_key,
+                expected_value,
+            }) => write!(
+                f,
+                "has entry {} {}",
+                fmt_syn(expected_key),
+                fmt_syn(expected_value)
+            ),
+            ComplexTestCase::HasFields(HasFields { fields }) => {
+                write!(f, "has fields")?;
+                for FieldMatcher { member, matcher } in fields {
+                    write!(f, " {} {}", fmt_syn(member), matcher)?;
+                }
+                Ok(())
             }
+            ComplexTestCase::Tuple(Tuple { elements }) => {
+                let elements: Vec<_> = elements.iter().map(ToString::to_string).collect();
+                write!(f, "({})", elements.join(", "))
+            }
+            ComplexTestCase::DebugEq(DebugEq { expected_value }) => {
+                write!(f, "debug_eq {}", fmt_syn(expected_value))
+            }
+            ComplexTestCase::BytesEq(BytesEq { expected_value }) => {
+                write!(f, "bytes_eq {}", fmt_syn(expected_value))
+            }
+            ComplexTestCase::All(All { predicate }) => write!(f, "all {}", fmt_syn(predicate)),
+            ComplexTestCase::Any(Any { predicate }) => write!(f, "any {}", fmt_syn(predicate)),
+            ComplexTestCase::Wrapped(Wrapped { token, inner }) => write!(f, "{token} {inner}"),
+            ComplexTestCase::Via(Via { projection, inner }) => {
+                write!(f, "via {} {}", fmt_syn(projection), inner)
+            }
+            ComplexTestCase::Matches(Matches { pattern, guard }) => {
+                write!(f, "matches {}", fmt_syn(pattern))?;
+                if let Some(guard) = guard {
+                    write!(f, " if {}", fmt_syn(guard))?;
+                }
+                Ok(())
+            }
+            ComplexTestCase::StrEq(StrEq {
+                token,
+                expected_value,
+            }) => write!(f, "{} {}", token, fmt_syn(expected_value)),
+            ComplexTestCase::Affix(Affix {
+                token,
+                expected_affix,
+            }) => write!(f, "{} {}", token, fmt_syn(expected_affix)),
+            ComplexTestCase::Len(Len {
+                token: OrderingToken::Eq,
+                expected_len,
+            }) => write!(f, "len {}", fmt_syn(expected_len)),
+            ComplexTestCase::Len(Len {
+                token,
+                expected_len,
+            }) => write!(f, "len {} {}", token, fmt_syn(expected_len)),
+            ComplexTestCase::Count(Count {
+                token: OrderingToken::Eq,
+                expected_len,
+            }) => write!(f, "count {}", fmt_syn(expected_len)),
+            ComplexTestCase::Count(Count {
+                token,
+                expected_len,
+            }) => write!(f, "count {} {}", token, fmt_syn(expected_len)),
             ComplexTestCase::Empty => {
                 write!(f, "empty")
             }
@@ -202,22 +579,138 @@ impl Display for ComplexTestCase {
             ComplexTestCase::Regex(Regex { expected_regex }) => {
                 write!(f, "regex {}", fmt_syn(expected_regex))
             }
+            #[cfg(feature = "with-json")]
+            ComplexTestCase::Json(Json { expected_value }) => {
+                write!(f, "json {}", fmt_syn(&expected_value.tokens))
+            }
+            #[cfg(feature = "with-json")]
+            ComplexTestCase::SerdeEq(SerdeEq { expected_value }) => {
+                write!(f, "serde_eq {}", fmt_syn(expected_value))
+            }
+            #[cfg(not(feature = "with-approx"))]
+            ComplexTestCase::Ulps(Ulps {
+                expected_value,
+                max_ulps,
+            }) => {
+                write!(f, "ulps_eq {}", fmt_syn(expected_value))?;
+                if let Some(max_ulps) = max_ulps {
+                    write!(f, " max_ulps {}", fmt_syn(max_ulps))?;
+                }
+                Ok(())
+            }
+            #[cfg(feature = "with-approx")]
+            ComplexTestCase::Approx(Approx {
+                token,
+                expected_value,
+                epsilon,
+                max_relative,
+                max_ulps,
+            }) => {
+                write!(f, "{} {}", token, fmt_syn(expected_value))?;
+                if let Some(epsilon) = epsilon {
+                    write!(f, " epsilon {}", fmt_syn(epsilon))?;
+                }
+                if let Some(max_relative) = max_relative {
+                    write!(f, " max_relative {}", fmt_syn(max_relative))?;
+                }
+                if let Some(max_ulps) = max_ulps {
+                    write!(f, " max_ulps {}", fmt_syn(max_ulps))?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
 impl ComplexTestCase {
     pub fn assertion(&self) -> TokenStream {
-        let tokens = self.boolean_check();
+        self.reusing_assertion(false)
+    }
+
+    /// Assertion which leaves `_result` to be checked again if `reused`, i.e. by the following
+    /// cases of `and` / `or`.
+    fn reusing_assertion(&self, reused: bool) -> TokenStream {
+        match self {
+            ComplexTestCase::All(All { predicate }) => all_detailed_assertion(predicate),
+            ComplexTestCase::Any(Any { predicate }) => any_detailed_assertion(predicate),
+            #[cfg(feature = "with-json")]
+            ComplexTestCase::Json(Json { expected_value }) => {
+                json_detailed_assertion(expected_value)
+            }
+            #[cfg(feature = "with-json")]
+            ComplexTestCase::SerdeEq(SerdeEq { expected_value }) => {
+                serde_eq_detailed_assertion(expected_value)
+            }
+            ComplexTestCase::HasFields(HasFields { fields }) => fields_detailed_assertion(
+                "field",
+                fields
+                    .iter()
+                    .map(|FieldMatcher { member, matcher }| (member.clone(), matcher)),
+            ),
+            ComplexTestCase::Tuple(Tuple { elements }) => {
+                fields_detailed_assertion("element", tuple_members(elements))
+            }
+            ComplexTestCase::DebugEq(DebugEq { expected_value }) => {
+                debug_eq_detailed_assertion(expected_value)
+            }
+            ComplexTestCase::BytesEq(BytesEq { expected_value }) => {
+                bytes_eq_detailed_assertion(expected_value)
+            }
+            ComplexTestCase::Within(Within {
+                tolerance,
+                expected_value,
+            }) => within_detailed_assertion(tolerance, expected_value),
+            ComplexTestCase::Wrapped(Wrapped { token, inner }) => {
+                wrapped_detailed_assertion(token, inner, reused)
+            }
+            ComplexTestCase::Via(Via { projection, inner }) => {
+                via_assertion(projection, inner.assertion())
+            }
+            ComplexTestCase::And(cases) => {
+                let assertions = cases
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, case)| case.reusing_assertion(reused || idx + 1 < cases.len()));
+                quote! { #(#assertions;)* }
+            }
+            ComplexTestCase::Not(_) | ComplexTestCase::Or(_) | ComplexTestCase::Cmp(_) => {
+                let tokens = self.reusing_check(reused);
+                let matcher = self.to_string();
+
+                quote! { assert!(#tokens, "expected result to satisfy `{}`", #matcher) }
+            }
+            _ => {
+                let tokens = self.reusing_check(reused);
 
-        quote! { assert!(#tokens) }
+                quote! { assert!(#tokens) }
+            }
+        }
+    }
+
+    fn parse_and(input: ParseStream) -> syn::Result<ComplexTestCase> {
+        let item = Self::parse_single_item(input)?;
+
+        Ok(if input.peek(kw::and) {
+            ComplexTestCase::And(parse_kw_repeat::<kw::and>(
+                item,
+                input,
+                Self::parse_single_item,
+            )?)
+        } else {
+            item
+        })
     }
 
     fn boolean_check(&self) -> TokenStream {
+        self.reusing_check(false)
+    }
+
+    /// Check which leaves `_result` to be checked again if `reused`.
+    fn reusing_check(&self, reused: bool) -> TokenStream {
         match self {
-            ComplexTestCase::Not(not) => not_assertion(not),
-            ComplexTestCase::And(cases) => and_assertion(cases),
-            ComplexTestCase::Or(cases) => or_assertion(cases),
+            ComplexTestCase::Not(not) => not_assertion(not, reused),
+            ComplexTestCase::And(cases) => and_assertion(cases, reused),
+            ComplexTestCase::Or(cases) => or_assertion(cases, reused),
             ComplexTestCase::Ord(Ord {
                 token,
                 expected_value,
@@ -226,6 +719,18 @@ impl ComplexTestCase {
                 expected_value,
                 precision,
             }) => almost_equal_assertion(expected_value, precision),
+            ComplexTestCase::FloatEq(FloatEq {
+                token,
+                expected_value,
+            }) => float_eq_assertion(token, expected_value),
+            ComplexTestCase::CloseTo(CloseTo {
+                expected_value,
+                percentage,
+            }) => close_to_assertion(expected_value, percentage),
+            ComplexTestCase::Within(Within {
+                tolerance,
+                expected_value,
+            }) => within_assertion(tolerance, expected_value),
             ComplexTestCase::Path(Path { token }) => path_assertion(token),
             ComplexTestCase::Contains(Contains { expected_element }) => {
                 contains_assertion(expected_element)
@@ -233,44 +738,91 @@ impl ComplexTestCase {
             ComplexTestCase::ContainsInOrder(ContainsInOrder { expected_slice }) => {
                 contains_in_order_assertion(expected_slice)
             }
-            ComplexTestCase::Len(Len { expected_len }) => len_assertion(expected_len),
-            ComplexTestCase::Count(Count { expected_len }) => count_assertion(expected_len),
+            ComplexTestCase::EqUnordered(EqUnordered { expected_elements }) => {
+                eq_unordered_assertion(expected_elements)
+            }
+            ComplexTestCase::InRange(InRange { range }) => in_range_assertion(range),
+            ComplexTestCase::Cmp(Cmp {
+                comparator,
+                expected_value,
+            }) => cmp_assertion(comparator, expected_value),
+            ComplexTestCase::HasKey(HasKey { expected_key }) => has_key_assertion(expected_key),
+            ComplexTestCase::HasEntry(HasEntry {
+                expected_key,
+                expected_value,
+            }) => has_entry_assertion(expected_key, expected_value),
+            ComplexTestCase::HasFields(HasFields { fields }) => fields_assertion(
+                fields
+                    .iter()
+                    .map(|FieldMatcher { member, matcher }| (member.clone(), matcher)),
+            ),
+            ComplexTestCase::Tuple(Tuple { elements }) => fields_assertion(tuple_members(elements)),
+            ComplexTestCase::DebugEq(DebugEq { expected_value }) => {
+                debug_eq_assertion(expected_value)
+            }
+            ComplexTestCase::BytesEq(BytesEq { expected_value }) => {
+                bytes_eq_assertion(expected_value)
+            }
+            ComplexTestCase::All(All { predicate }) => all_assertion(predicate),
+            ComplexTestCase::Any(Any { predicate }) => any_assertion(predicate),
+            ComplexTestCase::Wrapped(Wrapped { token, inner }) => {
+                wrapped_assertion(token, inner, reused)
+            }
+            ComplexTestCase::Via(Via { projection, inner }) => {
+                via_assertion(projection, inner.boolean_check())
+            }
+            ComplexTestCase::Matches(Matches { pattern, guard }) => {
+                matches_assertion(pattern, guard.as_deref())
+            }
+            ComplexTestCase::StrEq(StrEq {
+                token,
+                expected_value,
+            }) => str_eq_assertion(token, expected_value),
+            ComplexTestCase::Affix(Affix {
+                token,
+                expected_affix,
+            }) => affix_assertion(token, expected_affix),
+            ComplexTestCase::Len(Len {
+                token,
+                expected_len,
+            }) => len_assertion(token, expected_len),
+            ComplexTestCase::Count(Count {
+                token,
+                expected_len,
+            }) => count_assertion(token, expected_len),
             ComplexTestCase::Empty => empty_assertion(),
             #[cfg(feature = "with-regex")]
             ComplexTestCase::Regex(Regex { expected_regex }) => regex_assertion(expected_regex),
+            #[cfg(feature = "with-json")]
+            ComplexTestCase::Json(Json { expected_value }) => json_assertion(expected_value),
+            #[cfg(feature = "with-json")]
+            ComplexTestCase::SerdeEq(SerdeEq { expected_value }) => {
+                serde_eq_assertion(expected_value)
+            }
+            #[cfg(not(feature = "with-approx"))]
+            ComplexTestCase::Ulps(Ulps {
+                expected_value,
+                max_ulps,
+            }) => ulps_assertion(expected_value, max_ulps.as_deref()),
+            #[cfg(feature = "with-approx")]
+            ComplexTestCase::Approx(approx) => approx_assertion(approx),
         }
     }
 
     fn parse_single_item(input: ParseStream) -> syn::Result<ComplexTestCase> {
         Ok(if let Ok(group) = Group::parse(input) {
-            syn::parse2(group.stream())?
-        } else if input.parse::<kw::eq>().is_ok() || input.parse::<kw::equal_to>().is_ok() {
-            ComplexTestCase::Ord(Ord {
-                token: OrderingToken::Eq,
-                expected_value: input.parse()?,
-            })
-        } else if input.parse::<kw::lt>().is_ok() || input.parse::<kw::less_than>().is_ok() {
-            ComplexTestCase::Ord(Ord {
-                token: OrderingToken::Lt,
-                expected_value: input.parse()?,
-            })
-        } else if input.parse::<kw::gt>().is_ok() || input.parse::<kw::greater_than>().is_ok() {
-            ComplexTestCase::Ord(Ord {
-                token: OrderingToken::Gt,
-                expected_value: input.parse()?,
-            })
-        } else if input.parse::<kw::leq>().is_ok()
-            || input.parse::<kw::less_or_equal_than>().is_ok()
-        {
-            ComplexTestCase::Ord(Ord {
-                token: OrderingToken::Leq,
-                expected_value: input.parse()?,
-            })
-        } else if input.parse::<kw::geq>().is_ok()
-            || input.parse::<kw::greater_or_equal_than>().is_ok()
-        {
+            // Commas may belong to the matcher, e.g. `(eq HashMap::<u8, u8>::new())`, parentheses
+            // hold a tuple only if they don't hold a single matcher
+            match syn::parse2(group.stream()) {
+                Ok(case) => case,
+                Err(err) if group.delimiter() == Delimiter::Parenthesis => {
+                    ComplexTestCase::Tuple(syn::parse2(group.stream()).map_err(|_| err)?)
+                }
+                Err(err) => return Err(err),
+            }
+        } else if let Some(token) = parse_ordering_token(input) {
             ComplexTestCase::Ord(Ord {
-                token: OrderingToken::Geq,
+                token,
                 expected_value: input.parse()?,
             })
         } else if input.parse::<kw::almost>().is_ok()
@@ -283,8 +835,33 @@ impl ComplexTestCase {
                 expected_value: target,
                 precision,
             })
-        } else if input.parse::<kw::existing_path>().is_ok() {
-            ComplexTestCase::Path(Path {
+        } else if input.parse::<kw::eq_nan>().is_ok() {
+            ComplexTestCase::FloatEq(FloatEq {
+                token: FloatEqToken::Nan,
+                expected_value: input.parse()?,
+            })
+        } else if input.parse::<kw::bitwise_eq>().is_ok() {
+            ComplexTestCase::FloatEq(FloatEq {
+                token: FloatEqToken::Bitwise,
+                expected_value: input.parse()?,
+            })
+        } else if input.parse::<kw::close_to>().is_ok() {
+            let target = input.parse()?;
+            let _ = input.parse::<kw::within>()?;
+            let percentage = parse_percentage(input)?;
+            ComplexTestCase::CloseTo(CloseTo {
+                expected_value: target,
+                percentage,
+            })
+        } else if input.parse::<kw::within>().is_ok() {
+            let tolerance = input.parse()?;
+            let _ = input.parse::<kw::of>()?;
+            ComplexTestCase::Within(Within {
+                tolerance,
+                expected_value: input.parse()?,
+            })
+        } else if input.parse::<kw::existing_path>().is_ok() {
+            ComplexTestCase::Path(Path {
                 token: PathToken::Any,
             })
         } else if input.parse::<kw::directory>().is_ok() || input.parse::<kw::dir>().is_ok() {
@@ -303,14 +880,125 @@ impl ComplexTestCase {
             ComplexTestCase::ContainsInOrder(ContainsInOrder {
                 expected_slice: input.parse()?,
             })
+        } else if input.parse::<kw::eq_unordered>().is_ok() {
+            ComplexTestCase::EqUnordered(EqUnordered {
+                expected_elements: input.parse()?,
+            })
+        } else if input.parse::<Token![in]>().is_ok() {
+            ComplexTestCase::InRange(InRange {
+                range: input.parse()?,
+            })
+        } else if input.parse::<kw::cmp>().is_ok() {
+            let comparator = input.parse()?;
+            let _ = input.parse::<Token![,]>()?;
+            ComplexTestCase::Cmp(Cmp {
+                comparator,
+                expected_value: input.parse()?,
+            })
+        } else if input.parse::<kw::has_key>().is_ok() {
+            ComplexTestCase::HasKey(HasKey {
+                expected_key: input.parse()?,
+            })
+        } else if input.parse::<kw::has_entry>().is_ok() {
+            let content;
+            let _ = parenthesized!(content in input);
+            let expected_key = content.parse()?;
+            let _ = content.parse::<Token![,]>()?;
+            let expected_value = content.parse()?;
+            let _ = content.parse::<Option<Token![,]>>()?;
+            ComplexTestCase::HasEntry(HasEntry {
+                expected_key,
+                expected_value,
+            })
+        } else if input.parse::<kw::has_fields>().is_ok() {
+            let content;
+            let _ = braced!(content in input);
+            let fields = Punctuated::<FieldMatcher, Token![,]>::parse_terminated(&content)?;
+            ComplexTestCase::HasFields(HasFields {
+                fields: fields.into_iter().collect(),
+            })
+        } else if input.parse::<kw::debug_eq>().is_ok() {
+            ComplexTestCase::DebugEq(DebugEq {
+                expected_value: input.parse()?,
+            })
+        } else if input.parse::<kw::bytes_eq>().is_ok() {
+            ComplexTestCase::BytesEq(BytesEq {
+                expected_value: input.parse()?,
+            })
+        } else if input.parse::<kw::all>().is_ok() {
+            ComplexTestCase::All(All {
+                predicate: input.parse()?,
+            })
+        } else if input.parse::<kw::any>().is_ok() {
+            ComplexTestCase::Any(Any {
+                predicate: input.parse()?,
+            })
+        } else if let Some(token) = parse_wrapped_token(input) {
+            ComplexTestCase::Wrapped(Wrapped {
+                token,
+                inner: Box::new(Self::parse_single_item(input)?),
+            })
+        } else if input.parse::<kw::via>().is_ok() {
+            ComplexTestCase::Via(Via {
+                projection: input.parse()?,
+                inner: Box::new(Self::parse_single_item(input)?),
+            })
+        } else if input.parse::<kw::map>().is_ok() {
+            let projection = input.parse()?;
+            let _ = input.parse::<Token![=>]>()?;
+            ComplexTestCase::Via(Via {
+                projection,
+                inner: Box::new(Self::parse_single_item(input)?),
+            })
+        } else if input.parse::<kw::matches>().is_ok() {
+            ComplexTestCase::Matches(Matches {
+                pattern: Box::new(Pat::parse_single(input)?),
+                guard: if input.parse::<Token![if]>().is_ok() {
+                    Some(input.parse()?)
+                } else {
+                    None
+                },
+            })
+        } else if input.parse::<kw::eq_ignore_case>().is_ok() {
+            let token = if input.parse::<kw::unicode>().is_ok() {
+                StrEqToken::IgnoreCase
+            } else {
+                StrEqToken::IgnoreAsciiCase
+            };
+            ComplexTestCase::StrEq(StrEq {
+                token,
+                expected_value: input.parse()?,
+            })
+        } else if input.parse::<kw::eq_trimmed>().is_ok() {
+            ComplexTestCase::StrEq(StrEq {
+                token: StrEqToken::Trimmed,
+                expected_value: input.parse()?,
+            })
+        } else if input.parse::<kw::eq_normalized_ws>().is_ok() {
+            ComplexTestCase::StrEq(StrEq {
+                token: StrEqToken::NormalizedWs,
+                expected_value: input.parse()?,
+            })
+        } else if input.parse::<kw::starts_with>().is_ok() {
+            ComplexTestCase::Affix(Affix {
+                token: AffixToken::Prefix,
+                expected_affix: input.parse()?,
+            })
+        } else if input.parse::<kw::ends_with>().is_ok() {
+            ComplexTestCase::Affix(Affix {
+                token: AffixToken::Suffix,
+                expected_affix: input.parse()?,
+            })
         } else if input.parse::<kw::not>().is_ok() {
-            ComplexTestCase::Not(Box::new(input.parse()?))
+            ComplexTestCase::Not(Box::new(Self::parse_single_item(input)?))
         } else if input.parse::<kw::len>().is_ok() || input.parse::<kw::has_length>().is_ok() {
             ComplexTestCase::Len(Len {
+                token: parse_ordering_token(input).unwrap_or(OrderingToken::Eq),
                 expected_len: input.parse()?,
             })
         } else if input.parse::<kw::count>().is_ok() || input.parse::<kw::has_count>().is_ok() {
             ComplexTestCase::Count(Count {
+                token: parse_ordering_token(input).unwrap_or(OrderingToken::Eq),
                 expected_len: input.parse()?,
             })
         } else if input.parse::<kw::empty>().is_ok() {
@@ -327,18 +1015,184 @@ impl ComplexTestCase {
                     return Err(input.error("'with-regex' feature is required to use 'matches-regex' keyword"));
                 }
             }
+        } else if input.peek(kw::json) && input.peek2(Token![!]) {
+            cfg_if::cfg_if! {
+                if #[cfg(feature = "with-json")] {
+                    ComplexTestCase::Json(Json {
+                        expected_value: input.parse()?,
+                    })
+                } else {
+                    return Err(input.error("'with-json' feature is required to use 'json!' matcher"));
+                }
+            }
+        } else if input.parse::<kw::serde_eq>().is_ok() {
+            cfg_if::cfg_if! {
+                if #[cfg(feature = "with-json")] {
+                    ComplexTestCase::SerdeEq(SerdeEq {
+                        expected_value: input.parse()?,
+                    })
+                } else {
+                    return Err(input.error("'with-json' feature is required to use 'serde_eq' keyword"));
+                }
+            }
+        } else if let Some(token) = parse_approx_token(input) {
+            cfg_if::cfg_if! {
+                if #[cfg(feature = "with-approx")] {
+                    ComplexTestCase::Approx(parse_approx(input, token)?)
+                } else {
+                    match token {
+                        ApproxToken::Ulps => ComplexTestCase::Ulps(parse_ulps(input)?),
+                        _ => return Err(input.error(format!("'with-approx' feature is required to use '{token}' keyword"))),
+                    }
+                }
+            }
         } else {
             return Err(input.error("cannot parse complex expression"));
         })
     }
 }
 
-fn and_assertion(cases: &[ComplexTestCase]) -> TokenStream {
-    let ts = cases[0].boolean_check();
+impl Parse for FieldMatcher {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let member = input.parse()?;
+        let _ = input.parse::<Token![:]>()?;
+        let matcher = parse_expected_or_matcher(input)?;
+        Ok(FieldMatcher { member, matcher })
+    }
+}
+
+impl Parse for Tuple {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let elements =
+            Punctuated::<_, Token![,]>::parse_terminated_with(input, parse_expected_or_matcher)?;
+        Ok(Tuple {
+            elements: elements.into_iter().collect(),
+        })
+    }
+}
+
+/// Plain expression is compared for equality, anything else is parsed as a nested matcher.
+fn parse_expected_or_matcher(input: ParseStream) -> syn::Result<ComplexTestCase> {
+    let fork = input.fork();
+    Ok(
+        if fork.parse::<Expr>().is_ok() && (fork.is_empty() || fork.peek(Token![,])) {
+            ComplexTestCase::Ord(Ord {
+                token: OrderingToken::Eq,
+                expected_value: input.parse()?,
+            })
+        } else {
+            input.parse()?
+        },
+    )
+}
+
+fn parse_ordering_token(input: ParseStream) -> Option<OrderingToken> {
+    if input.parse::<kw::eq>().is_ok() || input.parse::<kw::equal_to>().is_ok() {
+        Some(OrderingToken::Eq)
+    } else if input.parse::<kw::lt>().is_ok() || input.parse::<kw::less_than>().is_ok() {
+        Some(OrderingToken::Lt)
+    } else if input.parse::<kw::gt>().is_ok() || input.parse::<kw::greater_than>().is_ok() {
+        Some(OrderingToken::Gt)
+    } else if input.parse::<kw::leq>().is_ok() || input.parse::<kw::less_or_equal_than>().is_ok() {
+        Some(OrderingToken::Leq)
+    } else if input.parse::<kw::geq>().is_ok() || input.parse::<kw::greater_or_equal_than>().is_ok()
+    {
+        Some(OrderingToken::Geq)
+    } else {
+        None
+    }
+}
+
+fn parse_wrapped_token(input: ParseStream) -> Option<WrappedToken> {
+    if input.parse::<kw::some>().is_ok() {
+        Some(WrappedToken::Some)
+    } else if input.parse::<kw::ok>().is_ok() {
+        Some(WrappedToken::Ok)
+    } else if input.parse::<kw::err>().is_ok() {
+        Some(WrappedToken::Err)
+    } else {
+        None
+    }
+}
+
+fn parse_percentage(input: ParseStream) -> syn::Result<Box<Expr>> {
+    let mut tokens = TokenStream::new();
+    while !input.peek(Token![%]) {
+        if input.is_empty() {
+            return Err(input.error("expected percentage, e.g. `within 2%`"));
+        }
+        tokens.append(input.parse::<TokenTree>()?);
+    }
+    let _ = input.parse::<Token![%]>()?;
+    syn::parse2(tokens)
+}
+
+fn parse_approx_token(input: ParseStream) -> Option<ApproxToken> {
+    if input.parse::<kw::abs_diff_eq>().is_ok() {
+        Some(ApproxToken::AbsDiff)
+    } else if input.parse::<kw::relative_eq>().is_ok() {
+        Some(ApproxToken::Relative)
+    } else if input.parse::<kw::ulps_eq>().is_ok() {
+        Some(ApproxToken::Ulps)
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "with-approx")]
+fn parse_approx(input: ParseStream, token: ApproxToken) -> syn::Result<Approx> {
+    let expected_value = input.parse()?;
+    let epsilon = input
+        .parse::<kw::epsilon>()
+        .is_ok()
+        .then(|| input.parse())
+        .transpose()?;
+    let max_relative = (token == ApproxToken::Relative
+        && input.parse::<kw::max_relative>().is_ok())
+    .then(|| input.parse())
+    .transpose()?;
+    let max_ulps = if token == ApproxToken::Ulps {
+        parse_max_ulps(input)?
+    } else {
+        None
+    };
+
+    Ok(Approx {
+        token,
+        expected_value,
+        epsilon,
+        max_relative,
+        max_ulps,
+    })
+}
+
+#[cfg(not(feature = "with-approx"))]
+fn parse_ulps(input: ParseStream) -> syn::Result<Ulps> {
+    let expected_value = input.parse()?;
+    if input.peek(kw::epsilon) {
+        return Err(input
+            .error("'with-approx' feature is required to use 'epsilon' with 'ulps_eq' keyword"));
+    }
+    let max_ulps = parse_max_ulps(input)?;
+
+    Ok(Ulps {
+        expected_value,
+        max_ulps,
+    })
+}
+
+fn parse_max_ulps(input: ParseStream) -> syn::Result<Option<Box<Expr>>> {
+    (input.parse::<Token![,]>().is_ok() || input.parse::<kw::max_ulps>().is_ok())
+        .then(|| input.parse())
+        .transpose()
+}
+
+fn and_assertion(cases: &[ComplexTestCase], reused: bool) -> TokenStream {
+    let ts = cases[0].reusing_check(reused || cases.len() > 1);
     let mut ts: TokenStream = parse_quote! { #ts };
 
-    for case in cases.iter().skip(1) {
-        let case = case.boolean_check();
+    for (idx, case) in cases.iter().enumerate().skip(1) {
+        let case = case.reusing_check(reused || idx + 1 < cases.len());
         let case: TokenStream = parse_quote! { && #case };
         ts.append_all(case);
     }
@@ -346,12 +1200,12 @@ fn and_assertion(cases: &[ComplexTestCase]) -> TokenStream {
     ts
 }
 
-fn or_assertion(cases: &[ComplexTestCase]) -> TokenStream {
-    let ts = cases[0].boolean_check();
+fn or_assertion(cases: &[ComplexTestCase], reused: bool) -> TokenStream {
+    let ts = cases[0].reusing_check(reused || cases.len() > 1);
     let mut ts: TokenStream = parse_quote! { #ts };
 
-    for case in cases.iter().skip(1) {
-        let case = case.boolean_check();
+    for (idx, case) in cases.iter().enumerate().skip(1) {
+        let case = case.reusing_check(reused || idx + 1 < cases.len());
         let case: TokenStream = parse_quote! { || #case };
         ts.append_all(case);
     }
@@ -362,10 +1216,11 @@ fn or_assertion(cases: &[ComplexTestCase]) -> TokenStream {
 fn parse_kw_repeat<Keyword: Parse>(
     first: ComplexTestCase,
     input: ParseStream,
+    parse_item: fn(ParseStream) -> syn::Result<ComplexTestCase>,
 ) -> syn::Result<Vec<ComplexTestCase>> {
     let mut acc = vec![first];
     while input.parse::<Keyword>().is_ok() {
-        acc.push(ComplexTestCase::parse_single_item(input)?);
+        acc.push(parse_item(input)?);
     }
     Ok(acc)
 }
@@ -379,19 +1234,412 @@ fn negate(tokens: TokenStream) -> TokenStream {
 fn contains_in_order_assertion(expected_slice: &Expr) -> TokenStream {
     parse_quote! {
         {
-            let mut _tc_outcome = false;
-            for i in 0..=_result.len() - #expected_slice.len() {
-                if #expected_slice == _result[i..i+#expected_slice.len()] {
-                    _tc_outcome = true;
+            trait _TcContainsInOrder<I: ?Sized, E> {
+                fn _tc_contains_in_order<X: ?Sized>(&self, expected: &X) -> bool
+                where
+                    for<'b> &'b X: ::std::iter::IntoIterator<Item = &'b E>;
+            }
+            impl<T: ?Sized, I, E> _TcContainsInOrder<I, E> for T
+            where
+                for<'a> &'a T: ::std::iter::IntoIterator<Item = &'a I>,
+                I: ::std::cmp::PartialEq<E>,
+            {
+                fn _tc_contains_in_order<X: ?Sized>(&self, expected: &X) -> bool
+                where
+                    for<'b> &'b X: ::std::iter::IntoIterator<Item = &'b E>,
+                {
+                    let mut expected = ::std::iter::IntoIterator::into_iter(expected).peekable();
+                    for i in self {
+                        if expected.peek().map_or(false, |e| i == *e) {
+                            expected.next();
+                        }
+                    }
+                    expected.peek().is_none()
                 }
             }
-            _tc_outcome
+            _result._tc_contains_in_order(&#expected_slice)
         }
     }
 }
 
 fn contains_assertion(expected_element: &Expr) -> TokenStream {
-    parse_quote! { _result.iter().find(|i| i.eq(&&#expected_element)).is_some() }
+    parse_quote! {
+        {
+            trait _TcContains<I: ?Sized, E: ?Sized> {
+                fn _tc_contains(&self, expected: &E) -> bool;
+            }
+            impl<T: ?Sized, I, E> _TcContains<I, E> for T
+            where
+                for<'a> &'a T: ::std::iter::IntoIterator<Item = &'a I>,
+                I: ::std::cmp::PartialEq<E>,
+            {
+                fn _tc_contains(&self, expected: &E) -> bool {
+                    ::std::iter::IntoIterator::into_iter(self).any(|i| i == expected)
+                }
+            }
+            _result._tc_contains(&#expected_element)
+        }
+    }
+}
+
+fn eq_unordered_assertion(expected_elements: &Expr) -> TokenStream {
+    parse_quote! {
+        {
+            trait _TcEqUnordered<I: ?Sized, E> {
+                fn _tc_eq_unordered<X: ?Sized>(&self, expected: &X) -> bool
+                where
+                    for<'b> &'b X: ::std::iter::IntoIterator<Item = &'b E>;
+            }
+            impl<T: ?Sized, I, E> _TcEqUnordered<I, E> for T
+            where
+                for<'a> &'a T: ::std::iter::IntoIterator<Item = &'a I>,
+                I: ::std::cmp::PartialEq<E>,
+            {
+                fn _tc_eq_unordered<X: ?Sized>(&self, expected: &X) -> bool
+                where
+                    for<'b> &'b X: ::std::iter::IntoIterator<Item = &'b E>,
+                {
+                    let mut expected: ::std::vec::Vec<::std::option::Option<&E>> =
+                        ::std::iter::IntoIterator::into_iter(expected).map(Some).collect();
+                    for i in self {
+                        match expected.iter_mut().find(|e| e.map_or(false, |e| i == e)) {
+                            Some(e) => *e = None,
+                            None => return false,
+                        }
+                    }
+                    expected.iter().all(|e| e.is_none())
+                }
+            }
+            _result._tc_eq_unordered(&#expected_elements)
+        }
+    }
+}
+
+fn in_range_assertion(range: &Expr) -> TokenStream {
+    quote! { ::std::ops::RangeBounds::contains(&(#range), &_result) }
+}
+
+fn cmp_assertion(comparator: &Expr, expected_value: &Expr) -> TokenStream {
+    quote! { (#comparator)(&_result, &#expected_value) }
+}
+
+fn has_key_assertion(expected_key: &Expr) -> TokenStream {
+    quote! {
+        {
+            let _tc_key = &(#expected_key);
+            _result.keys().any(|k| k == _tc_key)
+        }
+    }
+}
+
+fn has_entry_assertion(expected_key: &Expr, expected_value: &Expr) -> TokenStream {
+    quote! {
+        {
+            let _tc_key = &(#expected_key);
+            let _tc_value = &(#expected_value);
+            _result.iter().any(|(k, v)| k == _tc_key && v == _tc_value)
+        }
+    }
+}
+
+fn tuple_members(elements: &[ComplexTestCase]) -> impl Iterator<Item = (Member, &ComplexTestCase)> {
+    elements
+        .iter()
+        .enumerate()
+        .map(|(index, matcher)| (Member::Unnamed(index.into()), matcher))
+}
+
+fn fields_assertion<'a>(
+    fields: impl Iterator<Item = (Member, &'a ComplexTestCase)>,
+) -> TokenStream {
+    let checks = fields.map(|(member, matcher)| {
+        let check = matcher.boolean_check();
+        let field = reused_value(quote! { &_result.#member });
+        quote! {
+            {
+                let _result = #field;
+                #check
+            }
+        }
+    });
+    quote! { true #(&& #checks)* }
+}
+
+fn fields_detailed_assertion<'a>(
+    noun: &str,
+    fields: impl Iterator<Item = (Member, &'a ComplexTestCase)>,
+) -> TokenStream {
+    let message = format!("expected {noun} `{{}}` to satisfy `{{}}`");
+    let assertions = fields.map(|(member, matcher)| {
+        let check = matcher.boolean_check();
+        let member_str = fmt_syn(&member);
+        let matcher_str = matcher.to_string();
+        let field = reused_value(quote! { &_result.#member });
+        quote! {
+            {
+                let _result = #field;
+                assert!(
+                    #check,
+                    #message,
+                    #member_str,
+                    #matcher_str
+                )
+            }
+        }
+    });
+    quote! { #(#assertions;)* }
+}
+
+fn bytes_eq_assertion(expected_value: &Expr) -> TokenStream {
+    quote! {
+        ::std::convert::AsRef::<[u8]>::as_ref(&_result)
+            == ::std::convert::AsRef::<[u8]>::as_ref(&(#expected_value))
+    }
+}
+
+fn bytes_eq_detailed_assertion(expected_value: &Expr) -> TokenStream {
+    quote! {
+        {
+            let _tc_expected = &(#expected_value);
+            let _tc_expected: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref(_tc_expected);
+            let _tc_actual: &[u8] = ::std::convert::AsRef::<[u8]>::as_ref(&_result);
+            if _tc_actual != _tc_expected {
+                panic!(
+                    "bytes differ:\n{}",
+                    ::test_case::__private::bytes_diff(_tc_actual, _tc_expected)
+                )
+            }
+        }
+    }
+}
+
+fn debug_eq_assertion(expected_value: &Expr) -> TokenStream {
+    quote! {
+        ::std::format!("{:?}", _result) == ::std::convert::AsRef::<str>::as_ref(&#expected_value)
+    }
+}
+
+fn debug_eq_detailed_assertion(expected_value: &Expr) -> TokenStream {
+    quote! {
+        {
+            let _tc_actual = ::std::format!("{:?}", _result);
+            let _tc_expected = #expected_value;
+            let _tc_expected: &str = ::std::convert::AsRef::<str>::as_ref(&_tc_expected);
+            assert!(
+                _tc_actual == _tc_expected,
+                "expected Debug representation `{}`, found `{}`",
+                _tc_expected,
+                _tc_actual
+            )
+        }
+    }
+}
+
+/// Predicate of `all` takes elements by reference, e.g. `|x| *x > 0` or `|&x| x > 0`.
+fn first_failing_element(predicate: &Expr) -> TokenStream {
+    quote! {
+        {
+            trait _TcAll<I: ?Sized> {
+                fn _tc_first_failing<F: FnMut(&I) -> bool>(
+                    &self,
+                    predicate: F,
+                ) -> ::std::option::Option<(usize, &I)>;
+            }
+            impl<T: ?Sized, I> _TcAll<I> for T
+            where
+                for<'a> &'a T: ::std::iter::IntoIterator<Item = &'a I>,
+            {
+                fn _tc_first_failing<F: FnMut(&I) -> bool>(
+                    &self,
+                    mut predicate: F,
+                ) -> ::std::option::Option<(usize, &I)> {
+                    ::std::iter::IntoIterator::into_iter(self)
+                        .enumerate()
+                        .find(|(_, i)| !predicate(i))
+                }
+            }
+            _result._tc_first_failing(#predicate)
+        }
+    }
+}
+
+fn all_assertion(predicate: &Expr) -> TokenStream {
+    let first_failing = first_failing_element(predicate);
+    quote! { #first_failing.is_none() }
+}
+
+fn all_detailed_assertion(predicate: &Expr) -> TokenStream {
+    let first_failing = first_failing_element(predicate);
+    quote! {
+        if let ::std::option::Option::Some((_tc_index, _tc_element)) = #first_failing {
+            panic!(
+                "expected all elements to satisfy `{}`, but element at index {} does not: {:?}",
+                stringify!(#predicate),
+                _tc_index,
+                _tc_element
+            )
+        }
+    }
+}
+
+/// Predicate of `any` takes elements by reference, like the one of `all`.
+fn any_assertion(predicate: &Expr) -> TokenStream {
+    quote! {
+        {
+            trait _TcAny<I: ?Sized> {
+                fn _tc_any<F: FnMut(&I) -> bool>(&self, predicate: F) -> bool;
+            }
+            impl<T: ?Sized, I> _TcAny<I> for T
+            where
+                for<'a> &'a T: ::std::iter::IntoIterator<Item = &'a I>,
+            {
+                fn _tc_any<F: FnMut(&I) -> bool>(&self, predicate: F) -> bool {
+                    ::std::iter::IntoIterator::into_iter(self).any(predicate)
+                }
+            }
+            _result._tc_any(#predicate)
+        }
+    }
+}
+
+fn any_detailed_assertion(predicate: &Expr) -> TokenStream {
+    let any = any_assertion(predicate);
+    quote! {
+        assert!(
+            #any,
+            "expected any element to satisfy `{}`, but none of {:?} does",
+            stringify!(#predicate),
+            _result
+        )
+    }
+}
+
+/// Closures get their argument type from `_tc_via` signature, paths are called directly so
+/// deref coercion applies, e.g. `via str::len` on `String` result.
+fn via_assertion(projection: &Expr, inner: TokenStream) -> TokenStream {
+    let projected = if let Expr::Closure(_) = projection {
+        quote! {
+            {
+                fn _tc_via<T: ?Sized, R, F: FnOnce(&T) -> R>(value: &T, projection: F) -> R {
+                    projection(value)
+                }
+                _tc_via(&_result, #projection)
+            }
+        }
+    } else {
+        quote! { (#projection)(&_result) }
+    };
+    quote! {
+        {
+            let _result = #projected;
+            #inner
+        }
+    }
+}
+
+fn wrapped_pattern(token: &WrappedToken) -> TokenStream {
+    match token {
+        WrappedToken::Some => quote! { ::std::option::Option::Some(_result) },
+        WrappedToken::Ok => quote! { ::std::result::Result::Ok(_result) },
+        WrappedToken::Err => quote! { ::std::result::Result::Err(_result) },
+    }
+}
+
+/// Matches `_result` against `pattern`, binding the wrapped value as `_result`. If `reused`, the
+/// result is matched by reference and the wrapped value is cloned if possible, borrowed otherwise.
+fn wrapped_match(
+    pattern: TokenStream,
+    reused: bool,
+    arm: TokenStream,
+    other: TokenStream,
+) -> TokenStream {
+    if reused {
+        let value = reused_value(quote! { _result });
+        quote! {
+            match &_result {
+                #pattern => {
+                    let _result = #value;
+                    #arm
+                }
+                _ => #other,
+            }
+        }
+    } else {
+        quote! {
+            match _result {
+                #pattern => { #arm }
+                _ => #other,
+            }
+        }
+    }
+}
+
+/// Value behind reference `value`, cloned if possible, borrowed otherwise, so that the value it
+/// is taken from can be checked again.
+fn reused_value(value: TokenStream) -> TokenStream {
+    quote! {
+        {
+            use ::test_case::__private::{ReusedClone as _, ReusedRef as _};
+            (&::test_case::__private::Reused(#value)).reused()
+        }
+    }
+}
+
+fn wrapped_assertion(token: &WrappedToken, inner: &ComplexTestCase, reused: bool) -> TokenStream {
+    let pattern = wrapped_pattern(token);
+    let inner = inner.boolean_check();
+    wrapped_match(pattern, reused, inner, quote! { false })
+}
+
+fn wrapped_detailed_assertion(
+    token: &WrappedToken,
+    inner: &ComplexTestCase,
+    reused: bool,
+) -> TokenStream {
+    let pattern = wrapped_pattern(token);
+    let inner = inner.assertion();
+    let msg = match token {
+        WrappedToken::Some => "expected `Some(_)`, found `None`",
+        WrappedToken::Ok => "expected `Ok(_)`, found `Err(_)`",
+        WrappedToken::Err => "expected `Err(_)`, found `Ok(_)`",
+    };
+    wrapped_match(pattern, reused, inner, quote! { panic!(#msg) })
+}
+
+fn matches_assertion(pattern: &Pat, guard: Option<&Expr>) -> TokenStream {
+    match guard {
+        Some(guard) => quote! { ::std::matches!(_result, #pattern if #guard) },
+        None => quote! { ::std::matches!(_result, #pattern) },
+    }
+}
+
+fn str_eq_assertion(token: &StrEqToken, expected_value: &Expr) -> TokenStream {
+    let check = match token {
+        StrEqToken::IgnoreAsciiCase => quote! { _tc_actual.eq_ignore_ascii_case(_tc_expected) },
+        StrEqToken::IgnoreCase => {
+            quote! { _tc_actual.to_lowercase() == _tc_expected.to_lowercase() }
+        }
+        StrEqToken::Trimmed => quote! { _tc_actual.trim() == _tc_expected.trim() },
+        StrEqToken::NormalizedWs => quote! {
+            _tc_actual.split_whitespace().eq(_tc_expected.split_whitespace())
+        },
+    };
+
+    quote! {
+        {
+            let _tc_expected = #expected_value;
+            let _tc_actual: &str = ::std::convert::AsRef::<str>::as_ref(&_result);
+            let _tc_expected: &str = ::std::convert::AsRef::<str>::as_ref(&_tc_expected);
+            #check
+        }
+    }
+}
+
+fn affix_assertion(token: &AffixToken, expected_affix: &Expr) -> TokenStream {
+    match token {
+        AffixToken::Prefix => quote! { _result.starts_with(#expected_affix) },
+        AffixToken::Suffix => quote! { _result.ends_with(#expected_affix) },
+    }
 }
 
 fn path_assertion(token: &PathToken) -> TokenStream {
@@ -406,29 +1654,99 @@ fn almost_equal_assertion(expected_value: &Expr, precision: &Expr) -> TokenStrea
     quote! { (_result - #expected_value).abs() < #precision }
 }
 
-fn ord_assertion(token: &OrderingToken, expected_value: &Expr) -> TokenStream {
-    let ts: TokenStream = match token {
+fn float_eq_assertion(token: &FloatEqToken, expected_value: &Expr) -> TokenStream {
+    let check = match token {
+        FloatEqToken::Nan => quote! {
+            _tc_actual == _tc_expected || (_tc_actual.is_nan() && _tc_expected.is_nan())
+        },
+        FloatEqToken::Bitwise => quote! { _tc_actual.to_bits() == _tc_expected.to_bits() },
+    };
+
+    quote! {
+        {
+            fn _tc_same_type<T>(actual: T, expected: T) -> (T, T) {
+                (actual, expected)
+            }
+            let (_tc_actual, _tc_expected) = _tc_same_type(_result, #expected_value);
+            #check
+        }
+    }
+}
+
+fn close_to_assertion(expected_value: &Expr, percentage: &Expr) -> TokenStream {
+    quote! {
+        {
+            let _tc_expected = #expected_value as f64;
+            let _tc_tolerance = _tc_expected.abs() * (#percentage as f64) / 100.0;
+            (_result as f64 - _tc_expected).abs() <= _tc_tolerance
+        }
+    }
+}
+
+fn within_assertion(tolerance: &Expr, expected_value: &Expr) -> TokenStream {
+    quote! {
+        {
+            let _tc_expected = #expected_value;
+            let _tc_difference = if _result >= _tc_expected {
+                _result - _tc_expected
+            } else {
+                _tc_expected - _result
+            };
+            _tc_difference <= #tolerance
+        }
+    }
+}
+
+fn within_detailed_assertion(tolerance: &Expr, expected_value: &Expr) -> TokenStream {
+    quote! {
+        {
+            let _tc_expected = #expected_value;
+            let _tc_tolerance = #tolerance;
+            let _tc_difference = if _result >= _tc_expected {
+                _result - _tc_expected
+            } else {
+                _tc_expected - _result
+            };
+            assert!(
+                _tc_difference <= _tc_tolerance,
+                "expected {:?} to be within {:?} of {:?}, difference is {:?}",
+                _result,
+                _tc_tolerance,
+                _tc_expected,
+                _tc_difference
+            )
+        }
+    }
+}
+
+fn ordering_operator(token: &OrderingToken) -> TokenStream {
+    match token {
         OrderingToken::Eq => parse_quote! { == },
         OrderingToken::Lt => parse_quote! { < },
         OrderingToken::Gt => parse_quote! { > },
         OrderingToken::Leq => parse_quote! { <= },
         OrderingToken::Geq => parse_quote! { >= },
-    };
+    }
+}
 
+fn ord_assertion(token: &OrderingToken, expected_value: &Expr) -> TokenStream {
+    let ts = ordering_operator(token);
     quote! {
         _result #ts #expected_value
     }
 }
 
-fn len_assertion(expected_len: &Expr) -> TokenStream {
+fn len_assertion(token: &OrderingToken, expected_len: &Expr) -> TokenStream {
+    let ts = ordering_operator(token);
     quote! {
-        _result.len() == #expected_len
+        _result.len() #ts #expected_len
     }
 }
 
-fn count_assertion(expected_len: &Expr) -> TokenStream {
+fn count_assertion(token: &OrderingToken, expected_len: &Expr) -> TokenStream {
+    let ts = ordering_operator(token);
     quote! {
-        std::iter::IntoIterator::into_iter(_result).count() == #expected_len
+        std::iter::IntoIterator::into_iter(_result).count() #ts #expected_len
     }
 }
 
@@ -443,47 +1761,139 @@ fn regex_assertion(expected_regex: &Expr) -> TokenStream {
     quote! {
         {
             let re = ::test_case::Regex::new(#expected_regex).expect("Regex::new");
-            re.is_match(_result)
+            re.is_match(::std::convert::AsRef::<str>::as_ref(&_result))
+        }
+    }
+}
+
+#[cfg(feature = "with-json")]
+fn json_assertion(expected_value: &syn::Macro) -> TokenStream {
+    let tokens = &expected_value.tokens;
+    quote! {
+        {
+            use ::test_case::__private::AsJson as _;
+            _result.as_json() == ::test_case::serde_json::json!(#tokens)
+        }
+    }
+}
+
+#[cfg(feature = "with-json")]
+fn json_detailed_assertion(expected_value: &syn::Macro) -> TokenStream {
+    let tokens = &expected_value.tokens;
+    quote! {
+        {
+            use ::test_case::__private::AsJson as _;
+            let diffs = ::test_case::__private::json_diff(
+                &_result.as_json(),
+                &::test_case::serde_json::json!(#tokens),
+            );
+            assert!(diffs.is_empty(), "JSON values differ:\n{}", diffs.join("\n"));
+        }
+    }
+}
+
+#[cfg(feature = "with-json")]
+fn serde_eq_assertion(expected_value: &Expr) -> TokenStream {
+    quote! {
+        ::test_case::serde_json::to_value(&_result).expect("serializing result")
+            == ::test_case::serde_json::to_value(&#expected_value).expect("serializing expected value")
+    }
+}
+
+#[cfg(feature = "with-json")]
+fn serde_eq_detailed_assertion(expected_value: &Expr) -> TokenStream {
+    quote! {
+        {
+            let diffs = ::test_case::__private::json_diff(
+                &::test_case::serde_json::to_value(&_result).expect("serializing result"),
+                &::test_case::serde_json::to_value(&#expected_value)
+                    .expect("serializing expected value"),
+            );
+            assert!(diffs.is_empty(), "serialized values differ:\n{}", diffs.join("\n"));
+        }
+    }
+}
+
+#[cfg(not(feature = "with-approx"))]
+fn ulps_assertion(expected_value: &Expr, max_ulps: Option<&Expr>) -> TokenStream {
+    let max_ulps = max_ulps.map_or_else(|| quote! { 4 }, |max_ulps| quote! { #max_ulps });
+
+    quote! {
+        {
+            trait _TcUlps {
+                fn _tc_ulps(self, other: Self) -> u64;
+            }
+            impl _TcUlps for f32 {
+                fn _tc_ulps(self, other: Self) -> u64 {
+                    if self == other {
+                        0
+                    } else if self.is_nan() || other.is_nan() || self.is_sign_positive() != other.is_sign_positive() {
+                        u64::MAX
+                    } else {
+                        self.to_bits().abs_diff(other.to_bits()) as u64
+                    }
+                }
+            }
+            impl _TcUlps for f64 {
+                fn _tc_ulps(self, other: Self) -> u64 {
+                    if self == other {
+                        0
+                    } else if self.is_nan() || other.is_nan() || self.is_sign_positive() != other.is_sign_positive() {
+                        u64::MAX
+                    } else {
+                        self.to_bits().abs_diff(other.to_bits())
+                    }
+                }
+            }
+            _TcUlps::_tc_ulps(_result, #expected_value) <= #max_ulps
+        }
+    }
+}
+
+#[cfg(feature = "with-approx")]
+fn approx_assertion(approx: &Approx) -> TokenStream {
+    let expected_value = &approx.expected_value;
+    let mut params = vec![];
+    if let Some(epsilon) = &approx.epsilon {
+        params.push(quote! { epsilon = #epsilon });
+    }
+    if let Some(max_relative) = &approx.max_relative {
+        params.push(quote! { max_relative = #max_relative });
+    }
+    if let Some(max_ulps) = &approx.max_ulps {
+        params.push(quote! { max_ulps = #max_ulps });
+    }
+
+    match approx.token {
+        ApproxToken::AbsDiff => {
+            quote! { ::test_case::approx::abs_diff_eq!(_result, #expected_value #(, #params)*) }
+        }
+        ApproxToken::Relative => {
+            quote! { ::test_case::approx::relative_eq!(_result, #expected_value #(, #params)*) }
+        }
+        ApproxToken::Ulps => {
+            quote! { ::test_case::approx::ulps_eq!(_result, #expected_value #(, #params)*) }
         }
     }
 }
 
-fn not_assertion(not: &ComplexTestCase) -> TokenStream {
+fn not_assertion(not: &ComplexTestCase, reused: bool) -> TokenStream {
     match not {
         ComplexTestCase::Not(_) => {
             let msg = "multiple negations on single item are forbidden";
             syn::Error::new(Span::call_site(), msg).into_compile_error()
         }
-        ComplexTestCase::And(cases) => negate(and_assertion(cases)),
-        ComplexTestCase::Or(cases) => negate(or_assertion(cases)),
-        ComplexTestCase::Ord(Ord {
-            token,
-            expected_value,
-        }) => negate(ord_assertion(token, expected_value)),
-        ComplexTestCase::AlmostEqual(AlmostEqual {
-            expected_value,
-            precision,
-        }) => negate(almost_equal_assertion(expected_value, precision)),
-        ComplexTestCase::Path(Path { token }) => negate(path_assertion(token)),
-        ComplexTestCase::Contains(Contains { expected_element }) => {
-            negate(contains_assertion(expected_element))
-        }
-        ComplexTestCase::ContainsInOrder(ContainsInOrder { expected_slice }) => {
-            negate(contains_in_order_assertion(expected_slice))
-        }
-        ComplexTestCase::Len(Len { expected_len }) => negate(len_assertion(expected_len)),
-        ComplexTestCase::Count(Count { expected_len }) => negate(count_assertion(expected_len)),
-        ComplexTestCase::Empty => negate(empty_assertion()),
-        #[cfg(feature = "with-regex")]
-        ComplexTestCase::Regex(Regex { expected_regex }) => negate(regex_assertion(expected_regex)),
+        other => negate(other.reusing_check(reused)),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::complex_expr::{
-        AlmostEqual, ComplexTestCase, Contains, ContainsInOrder, Count, Len, OrderingToken, Path,
-        PathToken,
+        Affix, AffixToken, All, AlmostEqual, Any, BytesEq, CloseTo, Cmp, ComplexTestCase, Contains,
+        ContainsInOrder, Count, DebugEq, EqUnordered, FieldMatcher, FloatEq, FloatEqToken,
+        HasEntry, HasFields, HasKey, InRange, Len, Matches, OrderingToken, Path, PathToken, StrEq,
+        StrEqToken, Tuple, Via, Within, Wrapped, WrappedToken,
     };
     use syn::{parse_quote, LitFloat, LitInt, LitStr};
 
@@ -578,6 +1988,118 @@ mod tests {
         assert_almost_eq!(actual, 1.0, 0.0);
     }
 
+    #[test]
+    fn parses_float_eq_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { eq_nan f64::NAN };
+        assert_eq!(
+            actual,
+            ComplexTestCase::FloatEq(FloatEq {
+                token: FloatEqToken::Nan,
+                expected_value: Box::new(parse_quote! { f64::NAN }),
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { bitwise_eq -0.0 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::FloatEq(FloatEq {
+                token: FloatEqToken::Bitwise,
+                expected_value: Box::new(parse_quote! { -0.0 }),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_within_token_stream() {
+        let actual: ComplexTestCase =
+            parse_quote! { within Duration::from_millis(50) of Duration::from_secs(1) };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Within(Within {
+                tolerance: Box::new(parse_quote! { Duration::from_millis(50) }),
+                expected_value: Box::new(parse_quote! { Duration::from_secs(1) }),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_close_to_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { close_to 100.0 within 2% };
+        assert_eq!(
+            actual,
+            ComplexTestCase::CloseTo(CloseTo {
+                expected_value: Box::new(parse_quote! { 100.0 }),
+                percentage: Box::new(parse_quote! { 2 }),
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { close_to 1 within (0.5 * 2.0)% };
+        assert_eq!(
+            actual,
+            ComplexTestCase::CloseTo(CloseTo {
+                expected_value: Box::new(parse_quote! { 1 }),
+                percentage: Box::new(parse_quote! { (0.5 * 2.0) }),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-json")]
+    fn parses_json_token_stream() {
+        use crate::complex_expr::Json;
+
+        let actual: ComplexTestCase = parse_quote! { json!({"a": 1, "b": [2, 3]}) };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Json(Json {
+                expected_value: parse_quote! { json!({"a": 1, "b": [2, 3]}) },
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-json")]
+    fn parses_serde_eq_token_stream() {
+        use crate::complex_expr::SerdeEq;
+
+        let actual: ComplexTestCase = parse_quote! { serde_eq Point { x: 1, y: 2 } };
+        assert_eq!(
+            actual,
+            ComplexTestCase::SerdeEq(SerdeEq {
+                expected_value: Box::new(parse_quote! { Point { x: 1, y: 2 } }),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "with-approx"))]
+    fn parses_ulps_token_stream() {
+        use crate::complex_expr::Ulps;
+
+        let actual: ComplexTestCase = parse_quote! { ulps_eq 0.1 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Ulps(Ulps {
+                expected_value: Box::new(parse_quote! { 0.1 }),
+                max_ulps: None,
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { ulps_eq 0.1, 4 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Ulps(Ulps {
+                expected_value: Box::new(parse_quote! { 0.1 }),
+                max_ulps: Some(Box::new(parse_quote! { 4 })),
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { ulps_eq 0.1 max_ulps 2 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Ulps(Ulps {
+                expected_value: Box::new(parse_quote! { 0.1 }),
+                max_ulps: Some(Box::new(parse_quote! { 2 })),
+            })
+        );
+    }
+
     #[test]
     fn parses_path_token_stream() {
         let actual: ComplexTestCase = parse_quote! { existing_path };
@@ -646,13 +2168,257 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_eq_unordered_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { eq_unordered [3, 1, 2] };
+        assert_eq!(
+            actual,
+            ComplexTestCase::EqUnordered(EqUnordered {
+                expected_elements: Box::new(parse_quote! { [3, 1, 2] })
+            })
+        )
+    }
+
+    #[test]
+    fn parses_in_range_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { in 0..100 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::InRange(InRange {
+                range: Box::new(parse_quote! { 0..100 })
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { in 0.0..=1.0 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::InRange(InRange {
+                range: Box::new(parse_quote! { 0.0..=1.0 })
+            })
+        );
+    }
+
+    #[test]
+    fn parses_cmp_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { cmp my_domain_eq, Point { x: 1, y: 2 } };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Cmp(Cmp {
+                comparator: Box::new(parse_quote! { my_domain_eq }),
+                expected_value: Box::new(parse_quote! { Point { x: 1, y: 2 } })
+            })
+        );
+    }
+
+    #[test]
+    fn parses_has_key_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { has_key "id" };
+        assert_eq!(
+            actual,
+            ComplexTestCase::HasKey(HasKey {
+                expected_key: Box::new(parse_quote! { "id" })
+            })
+        );
+    }
+
+    #[test]
+    fn parses_has_entry_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { has_entry ("id", 7) };
+        assert_eq!(
+            actual,
+            ComplexTestCase::HasEntry(HasEntry {
+                expected_key: Box::new(parse_quote! { "id" }),
+                expected_value: Box::new(parse_quote! { 7 }),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_tuple_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { (eq 1, gt 0.5, matches Some(_)) };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Tuple(Tuple {
+                elements: vec![
+                    ComplexTestCase::Ord(crate::complex_expr::Ord {
+                        token: OrderingToken::Eq,
+                        expected_value: Box::new(parse_quote! { 1 }),
+                    }),
+                    ComplexTestCase::Ord(crate::complex_expr::Ord {
+                        token: OrderingToken::Gt,
+                        expected_value: Box::new(parse_quote! { 0.5 }),
+                    }),
+                    ComplexTestCase::Matches(Matches {
+                        pattern: Box::new(parse_quote! { Some(_) }),
+                        guard: None,
+                    }),
+                ]
+            })
+        );
+
+        let actual: ComplexTestCase = parse_quote! { ("x", not empty,) };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Tuple(Tuple {
+                elements: vec![
+                    ComplexTestCase::Ord(crate::complex_expr::Ord {
+                        token: OrderingToken::Eq,
+                        expected_value: Box::new(parse_quote! { "x" }),
+                    }),
+                    ComplexTestCase::Not(Box::new(ComplexTestCase::Empty)),
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn parses_has_fields_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { has_fields { id: 3, score: lt 10, 0: "x", } };
+        assert_eq!(
+            actual,
+            ComplexTestCase::HasFields(HasFields {
+                fields: vec![
+                    FieldMatcher {
+                        member: parse_quote! { id },
+                        matcher: ComplexTestCase::Ord(crate::complex_expr::Ord {
+                            token: OrderingToken::Eq,
+                            expected_value: Box::new(parse_quote! { 3 }),
+                        }),
+                    },
+                    FieldMatcher {
+                        member: parse_quote! { score },
+                        matcher: ComplexTestCase::Ord(crate::complex_expr::Ord {
+                            token: OrderingToken::Lt,
+                            expected_value: Box::new(parse_quote! { 10 }),
+                        }),
+                    },
+                    FieldMatcher {
+                        member: parse_quote! { 0 },
+                        matcher: ComplexTestCase::Ord(crate::complex_expr::Ord {
+                            token: OrderingToken::Eq,
+                            expected_value: Box::new(parse_quote! { "x" }),
+                        }),
+                    },
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn parses_debug_eq_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { debug_eq "Point { x: 1, y: 2 }" };
+        assert_eq!(
+            actual,
+            ComplexTestCase::DebugEq(DebugEq {
+                expected_value: Box::new(parse_quote! { "Point { x: 1, y: 2 }" })
+            })
+        );
+    }
+
+    #[test]
+    fn parses_bytes_eq_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { bytes_eq [0xca, 0xfe] };
+        assert_eq!(
+            actual,
+            ComplexTestCase::BytesEq(BytesEq {
+                expected_value: Box::new(parse_quote! { [0xca, 0xfe] })
+            })
+        );
+    }
+
+    #[test]
+    fn parses_all_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { all is_positive };
+        assert_eq!(
+            actual,
+            ComplexTestCase::All(All {
+                predicate: Box::new(parse_quote! { is_positive })
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { all |x| *x > 0 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::All(All {
+                predicate: Box::new(parse_quote! { |x| *x > 0 })
+            })
+        )
+    }
+
+    #[test]
+    fn parses_any_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { any |e| e.is_timeout() };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Any(Any {
+                predicate: Box::new(parse_quote! { |e| e.is_timeout() })
+            })
+        )
+    }
+
+    #[test]
+    fn parses_str_eq_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { eq_ignore_case "HELLO" };
+        assert_eq!(
+            actual,
+            ComplexTestCase::StrEq(StrEq {
+                token: StrEqToken::IgnoreAsciiCase,
+                expected_value: Box::new(parse_quote! { "HELLO" })
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { eq_ignore_case unicode "ŻÓŁĆ" };
+        assert_eq!(
+            actual,
+            ComplexTestCase::StrEq(StrEq {
+                token: StrEqToken::IgnoreCase,
+                expected_value: Box::new(parse_quote! { "ŻÓŁĆ" })
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { eq_trimmed "a b" };
+        assert_eq!(
+            actual,
+            ComplexTestCase::StrEq(StrEq {
+                token: StrEqToken::Trimmed,
+                expected_value: Box::new(parse_quote! { "a b" })
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { eq_normalized_ws "a b c" };
+        assert_eq!(
+            actual,
+            ComplexTestCase::StrEq(StrEq {
+                token: StrEqToken::NormalizedWs,
+                expected_value: Box::new(parse_quote! { "a b c" })
+            })
+        );
+    }
+
+    #[test]
+    fn parses_affix_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { starts_with "abc" };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Affix(Affix {
+                token: AffixToken::Prefix,
+                expected_affix: Box::new(parse_quote! { "abc" })
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { ends_with "xyz" };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Affix(Affix {
+                token: AffixToken::Suffix,
+                expected_affix: Box::new(parse_quote! { "xyz" })
+            })
+        );
+    }
+
     #[test]
     fn parses_len_token_stream() {
         let actual1: ComplexTestCase = parse_quote! { len 10 };
         let actual2: ComplexTestCase = parse_quote! { has_length 11 };
+        let actual3: ComplexTestCase = parse_quote! { has_length gt 2 };
         assert_eq!(
             actual1,
             ComplexTestCase::Len(Len {
+                token: OrderingToken::Eq,
                 expected_len: Box::new(parse_quote! { 10 })
             })
         );
@@ -660,8 +2426,17 @@ mod tests {
         assert_eq!(
             actual2,
             ComplexTestCase::Len(Len {
+                token: OrderingToken::Eq,
                 expected_len: Box::new(parse_quote! { 11 })
             })
+        );
+
+        assert_eq!(
+            actual3,
+            ComplexTestCase::Len(Len {
+                token: OrderingToken::Gt,
+                expected_len: Box::new(parse_quote! { 2 })
+            })
         )
     }
 
@@ -669,17 +2444,27 @@ mod tests {
     fn parses_count_token_stream() {
         let actual1: ComplexTestCase = parse_quote! { count 10 };
         let actual2: ComplexTestCase = parse_quote! { has_count 11 };
+        let actual3: ComplexTestCase = parse_quote! { count leq 3 };
         assert_eq!(
             actual1,
             ComplexTestCase::Count(Count {
+                token: OrderingToken::Eq,
                 expected_len: Box::new(parse_quote! { 10 })
             })
         );
         assert_eq!(
             actual2,
             ComplexTestCase::Count(Count {
+                token: OrderingToken::Eq,
                 expected_len: Box::new(parse_quote! { 11 })
             })
+        );
+        assert_eq!(
+            actual3,
+            ComplexTestCase::Count(Count {
+                token: OrderingToken::Leq,
+                expected_len: Box::new(parse_quote! { 3 })
+            })
         )
     }
 
@@ -689,6 +2474,112 @@ mod tests {
         assert_eq!(actual, ComplexTestCase::Empty,)
     }
 
+    #[test]
+    #[cfg(feature = "with-approx")]
+    fn parses_approx_token_stream() {
+        use crate::complex_expr::{Approx, ApproxToken};
+
+        let actual: ComplexTestCase = parse_quote! { abs_diff_eq 1.0 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Approx(Approx {
+                token: ApproxToken::AbsDiff,
+                expected_value: Box::new(parse_quote! { 1.0 }),
+                epsilon: None,
+                max_relative: None,
+                max_ulps: None,
+            })
+        );
+        let actual: ComplexTestCase =
+            parse_quote! { relative_eq 1.0 epsilon 0.1 max_relative 0.01 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Approx(Approx {
+                token: ApproxToken::Relative,
+                expected_value: Box::new(parse_quote! { 1.0 }),
+                epsilon: Some(Box::new(parse_quote! { 0.1 })),
+                max_relative: Some(Box::new(parse_quote! { 0.01 })),
+                max_ulps: None,
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { ulps_eq 1.0 max_ulps 4 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Approx(Approx {
+                token: ApproxToken::Ulps,
+                expected_value: Box::new(parse_quote! { 1.0 }),
+                epsilon: None,
+                max_relative: None,
+                max_ulps: Some(Box::new(parse_quote! { 4 })),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_via_token_stream() {
+        let expected = ComplexTestCase::Via(Via {
+            projection: Box::new(parse_quote! { |r| r.len() }),
+            inner: Box::new(ComplexTestCase::Ord(crate::complex_expr::Ord {
+                token: OrderingToken::Eq,
+                expected_value: Box::new(parse_quote! { 3 }),
+            })),
+        });
+        let actual: ComplexTestCase = parse_quote! { via |r| r.len() eq 3 };
+        assert_eq!(actual, expected);
+        let actual: ComplexTestCase = parse_quote! { map |r| r.len() => eq 3 };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parses_wrapped_token_stream() {
+        let actual: ComplexTestCase = parse_quote! { some eq 42 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Wrapped(Wrapped {
+                token: WrappedToken::Some,
+                inner: Box::new(parse_quote! { eq 42 }),
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { some (gt 3 and lt 5) };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Wrapped(Wrapped {
+                token: WrappedToken::Some,
+                inner: Box::new(parse_quote! { gt 3 and lt 5 }),
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { ok eq 7 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Wrapped(Wrapped {
+                token: WrappedToken::Ok,
+                inner: Box::new(parse_quote! { eq 7 }),
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { err matches MyError::Timeout { .. } };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Wrapped(Wrapped {
+                token: WrappedToken::Err,
+                inner: Box::new(ComplexTestCase::Matches(Matches {
+                    pattern: Box::new(parse_quote! { MyError::Timeout { .. } }),
+                    guard: None,
+                })),
+            })
+        );
+        let actual: ComplexTestCase = parse_quote! { ok matches n if n > 10 };
+        assert_eq!(
+            actual,
+            ComplexTestCase::Wrapped(Wrapped {
+                token: WrappedToken::Ok,
+                inner: Box::new(ComplexTestCase::Matches(Matches {
+                    pattern: Box::new(parse_quote! { n }),
+                    guard: Some(Box::new(parse_quote! { n > 10 })),
+                })),
+            })
+        );
+    }
+
     #[test]
     fn parses_negation() {
         let actual: ComplexTestCase = parse_quote! { not eq 1.0 };
@@ -741,5 +2632,21 @@ mod tests {
             ComplexTestCase::Or(v) if v.len() == 2 => {}
             _ => panic!("test failed"),
         }
+        let actual: ComplexTestCase = parse_quote! { eq 2.0 or lt 1.0 and gt 0.0 };
+        match actual {
+            ComplexTestCase::Or(v)
+                if v.len() == 2 && matches!(&v[1], ComplexTestCase::And(a) if a.len() == 2) => {}
+            _ => panic!("test failed"),
+        }
+        let actual: ComplexTestCase = parse_quote! { lt 1.0 and gt 0.0 or eq 2.0 and eq 3.0 };
+        match actual {
+            ComplexTestCase::Or(v) if v.len() == 2 => {}
+            _ => panic!("test failed"),
+        }
+        let actual: ComplexTestCase = parse_quote! { not eq 1.0 and gt 0.0 };
+        match actual {
+            ComplexTestCase::And(v) if matches!(v[0], ComplexTestCase::Not(_)) => {}
+            _ => panic!("test failed"),
+        }
     }
 }
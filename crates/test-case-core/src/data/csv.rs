@@ -0,0 +1,205 @@
+use super::{arg, cases, error, is_expected_verbatim, params, read, value, Entry};
+use crate::TestCase;
+use proc_macro2::TokenStream as TokenStream2;
+use std::mem;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Signature};
+
+/// Arguments of `#[test_cases_from_csv("path")]`.
+///
+/// First record of the file names parameters of the function in order, following records are
+/// arguments of cases. Additional last column is the expected value, cases with this column empty
+/// don't have one.
+#[derive(Debug)]
+pub struct CsvCases {
+    path: LitStr,
+}
+
+impl Parse for CsvCases {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            path: input.parse()?,
+        })
+    }
+}
+
+impl CsvCases {
+    /// Cases read from the file for function with signature `sig`.
+    pub fn cases(&self, sig: &Signature) -> syn::Result<Vec<TestCase>> {
+        let (full_path, contents) = read(&self.path)?;
+        let mut records =
+            records(&contents).map_err(|(line, message)| error(&self.path, line, message))?;
+        if records.is_empty() {
+            return Err(error(
+                &self.path,
+                1,
+                "expected header naming parameters".to_string(),
+            ));
+        }
+        let (_, header) = records.remove(0);
+        let params = params(sig);
+        if header.len() != params.len() && header.len() != params.len() + 1 {
+            return Err(error(
+                &self.path,
+                1,
+                format!(
+                    "expected {} columns named after parameters and optional expected value, found {}",
+                    params.len(),
+                    header.len()
+                ),
+            ));
+        }
+        for (column, (param, _)) in header.iter().zip(&params) {
+            if column != param {
+                return Err(error(
+                    &self.path,
+                    1,
+                    format!("column `{column}` doesn't match parameter `{param}`"),
+                ));
+            }
+        }
+
+        let entries = records
+            .into_iter()
+            .map(|(line, mut record)| {
+                if record.len() != header.len() {
+                    return Err(error(
+                        &self.path,
+                        line,
+                        format!("expected {} columns, found {}", header.len(), record.len()),
+                    ));
+                }
+                let expected = if header.len() > params.len() {
+                    record.pop().filter(|expected| !expected.is_empty())
+                } else {
+                    None
+                };
+                let args = record
+                    .iter()
+                    .zip(&params)
+                    .map(|(text, (_, ty))| arg(text, ty, &self.path, line))
+                    .collect::<syn::Result<_>>()?;
+                let expected = expected
+                    .map(|expected| value(&expected, is_expected_verbatim(sig), &self.path, line))
+                    .transpose()?;
+                Ok(Entry {
+                    args,
+                    modifiers: TokenStream2::new(),
+                    expected,
+                    description: None,
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        cases(entries, &full_path)
+    }
+}
+
+/// Fields of a record with the line the record starts at.
+type Record = (usize, Vec<String>);
+
+/// Non-empty records of `text` with lines they start at. Fields can be quoted with `"`, which is
+/// written as `""` inside of them, whitespace around unquoted fields is trimmed.
+fn records(text: &str) -> Result<Vec<Record>, (usize, String)> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut was_quoted = false;
+    let mut line = 1;
+    let mut start = 1;
+
+    let push_field = |record: &mut Vec<String>, field: &mut String, was_quoted: &mut bool| {
+        let value = mem::take(field);
+        record.push(if *was_quoted {
+            value
+        } else {
+            value.trim().to_string()
+        });
+        *was_quoted = false;
+    };
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if field.trim().is_empty() && !was_quoted => {
+                field.clear();
+                quoted = true;
+                was_quoted = true;
+            }
+            ',' if !quoted => push_field(&mut record, &mut field, &mut was_quoted),
+            '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+            '\n' if !quoted => {
+                push_field(&mut record, &mut field, &mut was_quoted);
+                if record.len() > 1 || !record[0].is_empty() {
+                    records.push((start, mem::take(&mut record)));
+                } else {
+                    record.clear();
+                }
+                line += 1;
+                start = line;
+            }
+            c => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    if quoted {
+        return Err((start, "unterminated quoted field".to_string()));
+    }
+    if !record.is_empty() || !field.trim().is_empty() || was_quoted {
+        push_field(&mut record, &mut field, &mut was_quoted);
+        records.push((start, record));
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{records, Record};
+
+    fn fields(records: &[Record]) -> Vec<(usize, Vec<&str>)> {
+        records
+            .iter()
+            .map(|(line, record)| (*line, record.iter().map(String::as_str).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn reads_records() {
+        let records = records("a, b\r\n\n1,2\n").unwrap();
+        assert_eq!(
+            fields(&records),
+            vec![(1, vec!["a", "b"]), (3, vec!["1", "2"])]
+        );
+    }
+
+    #[test]
+    fn reads_quoted_fields() {
+        let records = records("\" a, \"\"b\"\"\",\"multi\nline\"\nlast,").unwrap();
+        assert_eq!(
+            fields(&records),
+            vec![(1, vec![" a, \"b\"", "multi\nline"]), (3, vec!["last", ""])]
+        );
+    }
+
+    #[test]
+    fn rejects_unterminated_quotes() {
+        assert_eq!(
+            records("a\n\"b,c\n").unwrap_err(),
+            (2, "unterminated quoted field".to_string())
+        );
+    }
+}
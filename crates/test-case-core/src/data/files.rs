@@ -0,0 +1,269 @@
+use super::{is_str, manifest_dir, params};
+use crate::comment::TestCaseComment;
+use crate::expr::TestCaseExpression;
+use crate::utils::tokens_between;
+use crate::TestCase;
+use proc_macro2::{Delimiter, TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use std::path::Path;
+use syn::parse::{Parse, ParseStream};
+use syn::{parenthesized, Error, Expr, Ident, LitStr, Signature, Type};
+
+/// Arguments of `#[test_case(files("tests/fixtures/*.txt"))]`.
+///
+/// Generates a case per file matching the pattern, relative to the crate root, which passes the
+/// path of the file and optionally its contents to the function. Patterns support `*` and `?`
+/// within a name and `**` matching any number of directories. Expected result and description
+/// apply to every case.
+#[derive(Debug)]
+pub struct FileCases {
+    pattern: LitStr,
+    expression: Option<TestCaseExpression>,
+    comment: Option<TestCaseComment>,
+    source: TokenStream2,
+}
+
+impl FileCases {
+    /// Whether `#[test_case(...)]` with `args` generates cases from files, i.e. starts with
+    /// `files("...")`.
+    pub fn is_files(args: &TokenStream2) -> bool {
+        let mut tokens = args.clone().into_iter();
+        match (tokens.next(), tokens.next()) {
+            (Some(TokenTree::Ident(ident)), Some(TokenTree::Group(group))) => {
+                ident == "files"
+                    && group.delimiter() == Delimiter::Parenthesis
+                    && syn::parse2::<LitStr>(group.stream()).is_ok()
+            }
+            _ => false,
+        }
+    }
+
+    /// Cases for files matching the pattern, for function with signature `sig`.
+    pub fn cases(&self, sig: &Signature) -> syn::Result<Vec<TestCase>> {
+        let params = params(sig);
+        if params.is_empty() || params.len() > 2 {
+            return Err(Error::new_spanned(
+                &self.pattern,
+                "`files(...)` requires function taking path and optionally contents of the file",
+            ));
+        }
+
+        let pattern = self.pattern.value();
+        let root = manifest_dir();
+        let files = glob(&root, &pattern).map_err(|err| {
+            Error::new_spanned(&self.pattern, format!("cannot list files: {err}"))
+        })?;
+        if files.is_empty() {
+            return Err(Error::new_spanned(
+                &self.pattern,
+                format!("no files match `{pattern}`"),
+            ));
+        }
+
+        // Cases are named after the part of the path matched by wildcards
+        let base = pattern
+            .split('/')
+            .take_while(|segment| !is_wildcard(segment))
+            .map(|segment| format!("{segment}/"))
+            .collect::<String>();
+        files
+            .into_iter()
+            .map(|file| {
+                let path = LitStr::new(&file, self.pattern.span());
+                let full_path = root.join(&file).to_string_lossy().into_owned();
+                let mut args = vec![path_arg(&path, params[0].1)];
+                if let Some((_, ty)) = params.get(1) {
+                    args.push(contents_arg(&full_path, ty));
+                }
+                let name = file.strip_prefix(base.as_str()).unwrap_or(&file);
+                let description = match &self.comment {
+                    Some(comment) => format!("{} {name}", comment.comment.value()),
+                    None => name.to_string(),
+                };
+                let comment = syn::parse2::<TestCaseComment>(quote! { ; #description })?;
+                Ok(TestCase::new(args, self.expression.clone(), Some(comment))
+                    .with_source(self.source.clone()))
+            })
+            .collect()
+    }
+}
+
+impl Parse for FileCases {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let begin = input.cursor();
+        let ident: Ident = input.parse()?;
+        if ident != "files" {
+            return Err(Error::new(ident.span(), "expected `files(\"...\")`"));
+        }
+        let content;
+        parenthesized!(content in input);
+        let pattern = content.parse()?;
+        let expression = (!input.is_empty()).then(|| input.parse()).transpose()?;
+        let comment = (!input.is_empty()).then(|| input.parse()).transpose()?;
+
+        Ok(Self {
+            pattern,
+            expression,
+            comment,
+            source: tokens_between(begin, input.cursor()),
+        })
+    }
+}
+
+/// Argument of `ty` holding `path`: `&str`, other references, e.g. `&Path`, with `AsRef` and
+/// owned values, e.g. `PathBuf`, with `From`.
+fn path_arg(path: &LitStr, ty: &Type) -> Expr {
+    match ty {
+        _ if is_str(ty) => syn::parse_quote! { #path },
+        Type::Reference(_) => syn::parse_quote! { ::std::convert::AsRef::as_ref(#path) },
+        _ => syn::parse_quote! { ::std::convert::From::from(#path) },
+    }
+}
+
+/// Argument of `ty` holding contents of file at `full_path`: `&[u8]` are bytes, `&str` or owned
+/// values, e.g. `String`, are text.
+fn contents_arg(full_path: &str, ty: &Type) -> Expr {
+    match ty {
+        Type::Reference(reference) if matches!(reference.elem.as_ref(), Type::Slice(_)) => {
+            syn::parse_quote! { include_bytes!(#full_path) }
+        }
+        _ if is_str(ty) => syn::parse_quote! { include_str!(#full_path) },
+        _ => syn::parse_quote! { ::std::convert::From::from(include_str!(#full_path)) },
+    }
+}
+
+fn is_wildcard(segment: &str) -> bool {
+    segment.contains(['*', '?'])
+}
+
+/// Paths of files under `root` matching `pattern`, relative to `root`, in order.
+fn glob(root: &Path, pattern: &str) -> std::io::Result<Vec<String>> {
+    let mut segments: Vec<&str> = pattern
+        .split('/')
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .collect();
+    if segments.last() == Some(&"**") {
+        segments.push("*");
+    }
+    let mut files = vec![];
+    walk(root, "", &segments, &mut files)?;
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Collects files under `dir`, at `relative` path, matching `segments` of pattern.
+fn walk(
+    dir: &Path,
+    relative: &str,
+    segments: &[&str],
+    files: &mut Vec<String>,
+) -> std::io::Result<()> {
+    let (segment, rest) = match segments.split_first() {
+        Some(split) => split,
+        None => return Ok(()),
+    };
+    let join = |name: &str| match relative {
+        "" => name.to_string(),
+        _ => format!("{relative}/{name}"),
+    };
+
+    if !is_wildcard(segment) {
+        let path = dir.join(segment);
+        if rest.is_empty() && path.is_file() {
+            files.push(join(segment));
+        } else if path.is_dir() {
+            walk(&path, &join(segment), rest, files)?;
+        }
+        return Ok(());
+    }
+    if *segment == "**" {
+        walk(dir, relative, rest, files)?;
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        // Hidden files match only patterns which name them explicitly
+        if name.starts_with('.') && !segment.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        if *segment == "**" {
+            if path.is_dir() {
+                walk(&path, &join(&name), segments, files)?;
+            }
+        } else if matches(segment, &name) {
+            if rest.is_empty() && path.is_file() {
+                files.push(join(&name));
+            } else if path.is_dir() {
+                walk(&path, &join(&name), rest, files)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether `name` matches `pattern` with `*` (any characters) and `?` (single character).
+fn matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` and of the name it was tried at, to backtrack to
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{glob, matches, FileCases};
+    use quote::quote;
+
+    #[test]
+    fn matches_wildcards() {
+        assert!(matches("*.txt", "a.txt"));
+        assert!(matches("a?c*", "abc"));
+        assert!(matches("*b*d", "abcbd"));
+        assert!(!matches("*.txt", "a.txt.bak"));
+        assert!(!matches("a?", "a"));
+    }
+
+    #[test]
+    fn globs_files() {
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        let files = glob(root, "src/data/*.rs").unwrap();
+        assert!(files.contains(&"src/data/files.rs".to_string()));
+        assert!(files.windows(2).all(|pair| pair[0] < pair[1]));
+        let files = glob(root, "src/**/mod.rs").unwrap();
+        assert!(files.contains(&"src/data/mod.rs".to_string()));
+        assert!(files.contains(&"src/test_matrix/mod.rs".to_string()));
+    }
+
+    #[test]
+    fn recognizes_files_arguments() {
+        assert!(FileCases::is_files(&quote! { files("*.txt") }));
+        assert!(FileCases::is_files(&quote! { files("*.txt") => ignore }));
+        assert!(!FileCases::is_files(&quote! { files(name) }));
+        assert!(!FileCases::is_files(&quote! { files("a", "b") }));
+        assert!(!FileCases::is_files(&quote! { "files" }));
+    }
+}
@@ -0,0 +1,115 @@
+use super::manifest_dir;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, Error, Expr, Ident, Lit, LitStr, Token};
+
+/// Arguments of `#[test_cases_from(include = concat!(env!("OUT_DIR"), "/cases.rs"))]`.
+///
+/// The included file, e.g. generated by build script, holds attributes generating cases, e.g.
+/// `#[test_case(1, 2 => 3)]`. Path is a string literal, `concat!(...)` or `env!(...)` of them,
+/// relative paths are relative to the crate root.
+#[derive(Debug)]
+pub struct IncludedCases {
+    include: Expr,
+}
+
+impl Parse for IncludedCases {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        if key != "include" {
+            return Err(Error::new(key.span(), "expected `include = ...`"));
+        }
+        let _: Token![=] = input.parse()?;
+        Ok(Self {
+            include: input.parse()?,
+        })
+    }
+}
+
+impl IncludedCases {
+    /// Full path of the included file and attributes it holds.
+    pub fn attrs(&self) -> syn::Result<(String, Vec<Attribute>)> {
+        let path = manifest_dir().join(evaluate(&self.include)?);
+        let full_path = path.to_string_lossy().into_owned();
+        let contents = std::fs::read_to_string(&path).map_err(|err| {
+            Error::new(
+                self.include.span(),
+                format!("cannot read `{full_path}`: {err}"),
+            )
+        })?;
+        let attrs = syn::parse_str::<Attrs>(&contents)
+            .map_err(|err| Error::new(self.include.span(), format!("`{full_path}`: {err}")))?;
+        Ok((full_path, attrs.0))
+    }
+}
+
+/// Outer attributes filling the whole file.
+struct Attrs(Vec<Attribute>);
+
+impl Parse for Attrs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        if !input.is_empty() {
+            return Err(input.error("expected attributes, e.g. `#[test_case(...)]`"));
+        }
+        Ok(Self(attrs))
+    }
+}
+
+/// Value of string `expr`, evaluating `concat!` and `env!` like the compiler does.
+fn evaluate(expr: &Expr) -> syn::Result<String> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(lit) => Ok(lit.value()),
+            _ => Err(Error::new(lit.span(), "expected string literal")),
+        },
+        Expr::Macro(mac) if mac.mac.path.is_ident("concat") => {
+            let parts = mac
+                .mac
+                .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+            parts.iter().map(evaluate).collect()
+        }
+        Expr::Macro(mac) if mac.mac.path.is_ident("env") => {
+            let name: LitStr = mac.mac.parse_body()?;
+            std::env::var(name.value()).map_err(|_| {
+                Error::new(
+                    name.span(),
+                    format!("environment variable `{}` not defined", name.value()),
+                )
+            })
+        }
+        _ => Err(Error::new(
+            expr.span(),
+            "expected string literal, `concat!(...)` or `env!(...)`",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{evaluate, IncludedCases};
+    use syn::parse_quote;
+
+    #[test]
+    fn evaluates_path() {
+        let path = evaluate(&parse_quote! {
+            concat!(env!("CARGO_PKG_NAME"), "/", "cases.rs")
+        })
+        .unwrap();
+        assert_eq!(path, "test-case-core/cases.rs");
+
+        let error = evaluate(&parse_quote! { env!("TEST_CASE_UNDEFINED") }).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "environment variable `TEST_CASE_UNDEFINED` not defined"
+        );
+    }
+
+    #[test]
+    fn reports_missing_file() {
+        let included: IncludedCases = parse_quote! { include = "missing.rs" };
+        let error = included.attrs().unwrap_err().to_string();
+        assert!(error.starts_with("cannot read `"), "{error}");
+    }
+}
@@ -0,0 +1,259 @@
+use super::value::{entries, Collections, Fields, Value};
+use super::{cases, error, read};
+use crate::TestCase;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Signature};
+
+/// Arguments of `#[test_cases_from_json("path")]`.
+///
+/// The file holds an array of objects, each with values of parameters under their names, optional
+/// `expected` value, `description` and `ignore` (`true` or reason). Strings are taken verbatim for
+/// `&str` parameters (and `&str` or `String` results) and are Rust expressions otherwise, e.g.
+/// `"Some(1)"`. Numbers, booleans and `null` (`None`) are literals, arrays and objects are
+/// deserialized with `serde_json`.
+#[derive(Debug)]
+pub struct JsonCases {
+    path: LitStr,
+}
+
+impl Parse for JsonCases {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            path: input.parse()?,
+        })
+    }
+}
+
+impl JsonCases {
+    /// Cases read from the file for function with signature `sig`.
+    pub fn cases(&self, sig: &Signature) -> syn::Result<Vec<TestCase>> {
+        let (full_path, contents) = read(&self.path)?;
+        let mut parser = Parser::new(&contents);
+        let objects = parser
+            .entries()
+            .map_err(|message| error(&self.path, parser.line, message))?;
+        cases(
+            entries(objects, sig, &self.path, Collections::Deserialized)?,
+            &full_path,
+        )
+    }
+}
+
+/// Parser of JSON documents, which keeps track of the current line for error messages.
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            chars: text.chars().peekable(),
+            line: 1,
+        }
+    }
+
+    /// Objects of the top level array with lines they start at.
+    fn entries(&mut self) -> Result<Vec<(usize, Fields)>, String> {
+        let mut entries = vec![];
+        self.expect('[')?;
+        if self.peek() == Some(']') {
+            self.next();
+        } else {
+            loop {
+                self.skip_whitespace();
+                let line = self.line;
+                match self.value()? {
+                    Value::Object(fields) => entries.push((line, fields)),
+                    _ => return Err("expected object describing case".to_string()),
+                }
+                match self.peek() {
+                    Some(',') => self.next(),
+                    Some(']') => break self.next(),
+                    _ => return Err("expected `,` or `]`".to_string()),
+                };
+            }
+        }
+        match self.peek() {
+            None => Ok(entries),
+            Some(_) => Err("unexpected characters after array of cases".to_string()),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match self.peek() {
+            Some('{') => {
+                self.next();
+                let mut fields = vec![];
+                if self.peek() == Some('}') {
+                    self.next();
+                    return Ok(Value::Object(fields));
+                }
+                loop {
+                    self.skip_whitespace();
+                    let name = self.string()?;
+                    self.expect(':')?;
+                    fields.push((name, self.value()?));
+                    match self.peek() {
+                        Some(',') => self.next(),
+                        Some('}') => break self.next(),
+                        _ => return Err("expected `,` or `}`".to_string()),
+                    };
+                }
+                Ok(Value::Object(fields))
+            }
+            Some('[') => {
+                self.next();
+                let mut values = vec![];
+                if self.peek() == Some(']') {
+                    self.next();
+                    return Ok(Value::Array(values));
+                }
+                loop {
+                    values.push(self.value()?);
+                    match self.peek() {
+                        Some(',') => self.next(),
+                        Some(']') => break self.next(),
+                        _ => return Err("expected `,` or `]`".to_string()),
+                    };
+                }
+                Ok(Value::Array(values))
+            }
+            Some('"') => self.string().map(Value::String),
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                let mut number = String::new();
+                while let Some(c) = self
+                    .chars
+                    .next_if(|c| c.is_ascii_digit() || "+-.eE".contains(*c))
+                {
+                    number.push(c);
+                }
+                Ok(Value::Number(number))
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                let mut word = String::new();
+                while let Some(c) = self.chars.next_if(char::is_ascii_alphabetic) {
+                    word.push(c);
+                }
+                match word.as_str() {
+                    "null" => Ok(Value::Null),
+                    "true" => Ok(Value::Bool(true)),
+                    "false" => Ok(Value::Bool(false)),
+                    _ => Err(format!("unexpected `{word}`")),
+                }
+            }
+            Some(c) => Err(format!("unexpected `{c}`")),
+            None => Err("unexpected end of file".to_string()),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut text = String::new();
+        loop {
+            match self.chars.next() {
+                Some('"') => return Ok(text),
+                Some('\\') => match self.chars.next() {
+                    Some('n') => text.push('\n'),
+                    Some('t') => text.push('\t'),
+                    Some('r') => text.push('\r'),
+                    Some('b') => text.push('\u{8}'),
+                    Some('f') => text.push('\u{c}'),
+                    Some('u') => {
+                        let mut code = self.hex()?;
+                        if (0xd800..0xdc00).contains(&code) {
+                            self.expect('\\')?;
+                            self.expect('u')?;
+                            let low = self.hex()?.wrapping_sub(0xdc00);
+                            code = 0x10000 + ((code - 0xd800) << 10) + low;
+                        }
+                        text.push(char::from_u32(code).ok_or("invalid unicode escape")?);
+                    }
+                    Some(c @ ('"' | '\\' | '/')) => text.push(c),
+                    _ => return Err("invalid escape sequence".to_string()),
+                },
+                Some('\n') | None => return Err("unterminated string".to_string()),
+                Some(c) => text.push(c),
+            }
+        }
+    }
+
+    fn hex(&mut self) -> Result<u32, String> {
+        let digits: String = (0..4).filter_map(|_| self.chars.next()).collect();
+        u32::from_str_radix(&digits, 16).map_err(|_| "invalid unicode escape".to_string())
+    }
+
+    /// Next significant character, without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.peek().copied()
+    }
+
+    fn next(&mut self) {
+        self.chars.next();
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.next();
+                Ok(())
+            }
+            _ => Err(format!("expected `{expected}`")),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.chars.next_if(|c| c.is_whitespace()) {
+            if c == '\n' {
+                self.line += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Parser, Value};
+
+    #[test]
+    fn parses_entries() {
+        let mut parser = Parser::new(
+            "[\n  {\"a\": 1, \"b\": \"x\\\"\\u00e9\"},\n  {\"c\": [true, null, -1.5e3], \"d\": {}}\n]",
+        );
+        let entries = parser.entries().unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                (
+                    2,
+                    vec![
+                        ("a".to_string(), Value::Number("1".to_string())),
+                        ("b".to_string(), Value::String("x\"é".to_string()))
+                    ]
+                ),
+                (
+                    3,
+                    vec![
+                        (
+                            "c".to_string(),
+                            Value::Array(vec![
+                                Value::Bool(true),
+                                Value::Null,
+                                Value::Number("-1.5e3".to_string())
+                            ])
+                        ),
+                        ("d".to_string(), Value::Object(vec![]))
+                    ]
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_line_of_error() {
+        let mut parser = Parser::new("[\n  {\"a\": 1},\n  {\"a\" 2}\n]");
+        assert_eq!(parser.entries().unwrap_err(), "expected `:`");
+        assert_eq!(parser.line, 3);
+    }
+}
@@ -0,0 +1,151 @@
+//! Cases read from data files at expansion time, e.g. `#[test_cases_from_csv("cases.csv")]`.
+
+use crate::comment::TestCaseComment;
+use crate::expr::TestCaseExpression;
+use crate::TestCase;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use std::fmt::Display;
+use std::path::PathBuf;
+use syn::{Error, Expr, FnArg, LitStr, Pat, PatType, ReturnType, Signature, Type};
+
+mod csv;
+mod files;
+mod include;
+mod json;
+mod toml;
+mod value;
+mod yaml;
+
+pub use self::csv::CsvCases;
+pub use self::files::FileCases;
+pub use self::include::IncludedCases;
+pub use self::json::JsonCases;
+pub use self::toml::TomlCases;
+pub use self::yaml::YamlCases;
+
+/// Case read from a data file.
+struct Entry {
+    args: Vec<Expr>,
+    /// Modifiers preceding expected value, e.g. `ignore["reason"]`.
+    modifiers: TokenStream2,
+    expected: Option<Expr>,
+    description: Option<String>,
+}
+
+/// Error at `line` of data file at `path`.
+fn error(path: &LitStr, line: usize, message: impl Display) -> Error {
+    Error::new_spanned(path, format!("`{}` line {line}: {message}", path.value()))
+}
+
+/// Reads `path` relative to the directory of the manifest of crate being compiled, returns
+/// absolute path and contents of the file.
+fn read(path: &LitStr) -> syn::Result<(String, String)> {
+    let full_path = manifest_dir().join(path.value());
+    let contents = std::fs::read_to_string(&full_path).map_err(|err| {
+        Error::new_spanned(path, format!("cannot read `{}`: {err}", path.value()))
+    })?;
+    Ok((full_path.to_string_lossy().into_owned(), contents))
+}
+
+/// Root of the crate being compiled, which paths of data files are relative to.
+fn manifest_dir() -> PathBuf {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+}
+
+/// Names and types of parameters of `sig`, patterns are named after their position.
+fn params(sig: &Signature) -> Vec<(String, &Type)> {
+    sig.inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(PatType { pat, ty, .. }) => Some((pat, ty.as_ref())),
+            FnArg::Receiver(_) => None,
+        })
+        .enumerate()
+        .map(|(idx, (pat, ty))| match pat.as_ref() {
+            Pat::Ident(pat) => (pat.ident.to_string(), ty),
+            _ => (format!("arg{idx}"), ty),
+        })
+        .collect()
+}
+
+/// Whether `ty` is `&str`.
+fn is_str(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => {
+            matches!(reference.elem.as_ref(), Type::Path(path) if path.path.is_ident("str"))
+        }
+        _ => false,
+    }
+}
+
+fn is_string(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.path.is_ident("String"))
+}
+
+/// Whether values of `ty` are written verbatim in data files, i.e. `ty` is `&str` or `String`.
+fn is_string_like(ty: &Type) -> bool {
+    is_str(ty) || is_string(ty)
+}
+
+/// Whether expected value of function with signature `sig` is written verbatim in data files.
+fn is_expected_verbatim(sig: &Signature) -> bool {
+    match &sig.output {
+        ReturnType::Type(_, ty) => is_string_like(ty),
+        ReturnType::Default => false,
+    }
+}
+
+/// Value written as `text` in the data file: strings are taken `verbatim`, other values are Rust
+/// expressions, e.g. `42` or `Some(1)`.
+fn value(text: &str, verbatim: bool, path: &LitStr, line: usize) -> syn::Result<Expr> {
+    if verbatim {
+        let lit = LitStr::new(text, path.span());
+        return Ok(syn::parse_quote! { #lit });
+    }
+    syn::parse_str(text)
+        .map_err(|err| error(path, line, format!("`{text}` is not an expression: {err}")))
+}
+
+/// Argument of `ty` written as `text` in the data file: `&str` and `String` arguments are taken
+/// verbatim, other values are Rust expressions.
+fn arg(text: &str, ty: &Type, path: &LitStr, line: usize) -> syn::Result<Expr> {
+    if is_string(ty) {
+        let lit = LitStr::new(text, path.span());
+        return Ok(syn::parse_quote! { #lit.to_string() });
+    }
+    value(text, is_str(ty), path, line)
+}
+
+/// Cases read from `entries` of data file at `full_path`.
+fn cases(entries: Vec<Entry>, full_path: &str) -> syn::Result<Vec<TestCase>> {
+    entries
+        .into_iter()
+        .map(|entry| {
+            let Entry {
+                args,
+                modifiers,
+                expected,
+                description,
+            } = entry;
+            let expression = (expected.is_some() || !modifiers.is_empty())
+                .then(|| quote! { => #modifiers #expected });
+            let comment = description.map(|description| quote! { ; #description });
+            let source = quote! { #(#args),* #expression #comment };
+            let expression = expression
+                .map(syn::parse2::<TestCaseExpression>)
+                .transpose()?;
+            let comment = comment.map(syn::parse2::<TestCaseComment>).transpose()?;
+            Ok(TestCase::new(args, expression, comment)
+                .with_source(source)
+                .with_data_file(full_path))
+        })
+        .collect()
+}
+
+/// Reference to data file, so that cases are regenerated when it changes.
+pub(crate) fn track(full_path: &str) -> TokenStream2 {
+    quote! { const _: &[u8] = include_bytes!(#full_path); }
+}
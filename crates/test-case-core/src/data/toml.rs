@@ -0,0 +1,470 @@
+use super::value::{entries, Collections, Fields, Value};
+use super::{cases, error, read};
+use crate::TestCase;
+use std::iter::Peekable;
+use std::str::Chars;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Signature};
+
+/// Arguments of `#[test_cases_from_toml("path")]`.
+///
+/// The file holds `[[case]]` tables, each with values of parameters under their names, optional
+/// `expected` value, `description` and `ignore` (`true` or reason). Tables, e.g. `[case.point]`,
+/// are struct literals of the parameter type and arrays are converted with `Into`.
+#[derive(Debug)]
+pub struct TomlCases {
+    path: LitStr,
+}
+
+impl Parse for TomlCases {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            path: input.parse()?,
+        })
+    }
+}
+
+impl TomlCases {
+    /// Cases read from the file for function with signature `sig`.
+    pub fn cases(&self, sig: &Signature) -> syn::Result<Vec<TestCase>> {
+        let (full_path, contents) = read(&self.path)?;
+        let mut parser = Parser::new(&contents);
+        let tables = parser
+            .entries()
+            .map_err(|message| error(&self.path, parser.line, message))?;
+        cases(
+            entries(tables, sig, &self.path, Collections::Literals)?,
+            &full_path,
+        )
+    }
+}
+
+/// Name of tables describing cases.
+const CASE: &str = "case";
+
+/// Parser of TOML documents, which keeps track of the current line for error messages.
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            chars: text.chars().peekable(),
+            line: 1,
+        }
+    }
+
+    /// `[[case]]` tables with lines they start at.
+    fn entries(&mut self) -> Result<Vec<(usize, Fields)>, String> {
+        let mut root: Fields = vec![];
+        let mut lines = vec![];
+        let mut current: Vec<String> = vec![];
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                None => break,
+                Some('\n') => {}
+                Some('[') => {
+                    self.chars.next();
+                    let array = self.chars.next_if_eq(&'[').is_some();
+                    let keys = self.keys()?;
+                    self.expect(']')?;
+                    if array {
+                        self.expect(']')?;
+                    }
+                    if keys[0] != CASE || (!array && keys.len() == 1) {
+                        return Err(format!("expected `[[{CASE}]]` tables"));
+                    }
+                    if array {
+                        let (last, parent) = keys.split_last().unwrap();
+                        match entry(table(&mut root, parent)?, last) {
+                            Value::Array(tables) => tables.push(Value::Object(vec![])),
+                            _ => return Err(format!("`{last}` is not an array of tables")),
+                        }
+                        if keys.len() == 1 {
+                            lines.push(self.line);
+                        }
+                    } else {
+                        table(&mut root, &keys)?;
+                    }
+                    current = keys;
+                }
+                Some(_) => {
+                    if current.is_empty() {
+                        return Err(format!("expected `[[{CASE}]]` tables"));
+                    }
+                    let keys = self.keys()?;
+                    self.expect('=')?;
+                    let value = self.value()?;
+                    insert(table(&mut root, &current)?, &keys, value)?;
+                }
+            }
+            self.skip_whitespace();
+            match self.chars.next() {
+                Some('\n') | None => self.line += 1,
+                Some(c) => return Err(format!("unexpected `{c}`")),
+            }
+        }
+
+        let cases = match root.pop() {
+            Some((_, Value::Array(cases))) => cases,
+            _ => vec![],
+        };
+        Ok(lines
+            .into_iter()
+            .zip(cases)
+            .map(|(line, case)| match case {
+                Value::Object(fields) => (line, fields),
+                _ => unreachable!("cases are tables"),
+            })
+            .collect())
+    }
+
+    /// Dotted keys, e.g. `point.x`.
+    fn keys(&mut self) -> Result<Vec<String>, String> {
+        let mut keys = vec![];
+        loop {
+            self.skip_whitespace();
+            let key = match self.chars.peek() {
+                Some('"' | '\'') => self.string()?,
+                _ => {
+                    let mut key = String::new();
+                    while let Some(c) = self
+                        .chars
+                        .next_if(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+                    {
+                        key.push(c);
+                    }
+                    if key.is_empty() {
+                        return Err("expected key".to_string());
+                    }
+                    key
+                }
+            };
+            keys.push(key);
+            self.skip_whitespace();
+            if self.chars.next_if_eq(&'.').is_none() {
+                return Ok(keys);
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some('"' | '\'') => self.string().map(Value::String),
+            Some('[') => {
+                self.chars.next();
+                let mut values = vec![];
+                loop {
+                    self.skip_blank();
+                    if self.chars.next_if_eq(&']').is_some() {
+                        return Ok(Value::Array(values));
+                    }
+                    values.push(self.value()?);
+                    self.skip_blank();
+                    match self.chars.next() {
+                        Some(',') => {}
+                        Some(']') => return Ok(Value::Array(values)),
+                        _ => return Err("expected `,` or `]`".to_string()),
+                    }
+                }
+            }
+            Some('{') => {
+                self.chars.next();
+                let mut fields = vec![];
+                self.skip_whitespace();
+                if self.chars.next_if_eq(&'}').is_some() {
+                    return Ok(Value::Object(fields));
+                }
+                loop {
+                    let keys = self.keys()?;
+                    self.expect('=')?;
+                    let value = self.value()?;
+                    insert(&mut fields, &keys, value)?;
+                    self.skip_whitespace();
+                    match self.chars.next() {
+                        Some(',') => {}
+                        Some('}') => return Ok(Value::Object(fields)),
+                        _ => return Err("expected `,` or `}`".to_string()),
+                    }
+                }
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(c) = self.chars.next_if(|c| !" \t\r\n,]}#".contains(*c)) {
+                    word.push(c);
+                }
+                match word.as_str() {
+                    "true" => Ok(Value::Bool(true)),
+                    "false" => Ok(Value::Bool(false)),
+                    "" => Err("expected value".to_string()),
+                    _ => number(&word).ok_or_else(|| format!("unsupported value `{word}`")),
+                }
+            }
+        }
+    }
+
+    /// Basic (`"`) or literal (`'`) string, both also in multi-line form.
+    fn string(&mut self) -> Result<String, String> {
+        let quote = self.chars.next().unwrap();
+        let mut text = String::new();
+        let multiline = {
+            let mut chars = self.chars.clone();
+            chars.next() == Some(quote) && chars.next() == Some(quote)
+        };
+        if multiline {
+            self.chars.nth(1);
+            // Newline following opening delimiter is trimmed
+            self.chars.next_if_eq(&'\r');
+            if self.chars.next_if_eq(&'\n').is_some() {
+                self.line += 1;
+            }
+        } else if self.chars.next_if_eq(&quote).is_some() {
+            return Ok(text);
+        }
+        loop {
+            match self.chars.next() {
+                Some(c) if c == quote && !multiline => return Ok(text),
+                Some(c) if c == quote && self.chars.peek() == Some(&quote) => {
+                    let mut chars = self.chars.clone();
+                    chars.next();
+                    if chars.next() == Some(quote) {
+                        self.chars.nth(1);
+                        // Up to two quotes can precede closing delimiter
+                        for _ in 0..2 {
+                            if self.chars.next_if_eq(&quote).is_some() {
+                                text.push(quote);
+                            }
+                        }
+                        return Ok(text);
+                    }
+                    text.push(c);
+                }
+                Some('\\') if quote == '"' => match self.chars.next() {
+                    Some('n') => text.push('\n'),
+                    Some('t') => text.push('\t'),
+                    Some('r') => text.push('\r'),
+                    Some('b') => text.push('\u{8}'),
+                    Some('f') => text.push('\u{c}'),
+                    Some('e') => text.push('\u{1b}'),
+                    Some('u') => text.push(self.code(4)?),
+                    Some('U') => text.push(self.code(8)?),
+                    Some(c @ ('"' | '\\')) => text.push(c),
+                    // Line ending backslash trims following whitespace
+                    Some(mut c) if multiline && c.is_whitespace() => loop {
+                        if c == '\n' {
+                            self.line += 1;
+                        }
+                        match self.chars.next_if(|c| c.is_whitespace()) {
+                            Some(next) => c = next,
+                            None => break,
+                        }
+                    },
+                    _ => return Err("invalid escape sequence".to_string()),
+                },
+                Some('\n') if multiline => {
+                    self.line += 1;
+                    text.push('\n');
+                }
+                Some('\n') | None => return Err("unterminated string".to_string()),
+                Some(c) => text.push(c),
+            }
+        }
+    }
+
+    fn code(&mut self, digits: usize) -> Result<char, String> {
+        let digits: String = (0..digits).filter_map(|_| self.chars.next()).collect();
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| "invalid unicode escape".to_string())
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.chars.next_if_eq(&expected) {
+            Some(_) => Ok(()),
+            None => Err(format!("expected `{expected}`")),
+        }
+    }
+
+    /// Skips spaces and comment till the end of line.
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .next_if(|c| *c == ' ' || *c == '\t' || *c == '\r')
+            .is_some()
+        {}
+        if self.chars.peek() == Some(&'#') {
+            while self.chars.next_if(|c| *c != '\n').is_some() {}
+        }
+    }
+
+    /// Skips whitespace, comments and newlines, as allowed inside of arrays.
+    fn skip_blank(&mut self) {
+        loop {
+            self.skip_whitespace();
+            if self.chars.next_if_eq(&'\n').is_none() {
+                return;
+            }
+            self.line += 1;
+        }
+    }
+}
+
+/// Number written as `word`, in decimal notation understood by both Rust and JSON.
+fn number(word: &str) -> Option<Value> {
+    let digits = word.replace('_', "");
+    let digits = digits.trim_start_matches('+');
+    let radix = match digits.get(..2) {
+        Some("0x") => 16,
+        Some("0o") => 8,
+        Some("0b") => 2,
+        _ => 10,
+    };
+    if radix != 10 {
+        let number = u128::from_str_radix(&digits[2..], radix).ok()?;
+        return Some(Value::Number(number.to_string()));
+    }
+    let valid = digits
+        .trim_start_matches('-')
+        .starts_with(|c: char| c.is_ascii_digit())
+        && digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || "+-.eE".contains(c))
+        && digits.parse::<f64>().is_ok();
+    if valid {
+        Some(Value::Number(digits.to_string()))
+    } else {
+        None
+    }
+}
+
+/// Value under `key` of table, empty array of tables when missing.
+fn entry<'t>(fields: &'t mut Fields, key: &str) -> &'t mut Value {
+    let idx = match fields.iter().position(|(name, _)| name == key) {
+        Some(idx) => idx,
+        None => {
+            fields.push((key.to_string(), Value::Array(vec![])));
+            fields.len() - 1
+        }
+    };
+    &mut fields[idx].1
+}
+
+/// Table at `keys` of `root`, created when missing. Arrays of tables lead to the last table.
+fn table<'t>(root: &'t mut Fields, keys: &[String]) -> Result<&'t mut Fields, String> {
+    let mut fields = root;
+    for key in keys {
+        let idx = match fields.iter().position(|(name, _)| name == key) {
+            Some(idx) => idx,
+            None => {
+                fields.push((key.clone(), Value::Object(vec![])));
+                fields.len() - 1
+            }
+        };
+        fields = match &mut fields[idx].1 {
+            Value::Object(fields) => fields,
+            Value::Array(tables) => match tables.last_mut() {
+                Some(Value::Object(fields)) => fields,
+                _ => return Err(format!("`{key}` is not a table")),
+            },
+            _ => return Err(format!("`{key}` is not a table")),
+        };
+    }
+    Ok(fields)
+}
+
+/// Inserts `value` under dotted `keys` of table.
+fn insert(fields: &mut Fields, keys: &[String], value: Value) -> Result<(), String> {
+    let (last, parent) = keys.split_last().unwrap();
+    let fields = table(fields, parent)?;
+    if fields.iter().any(|(name, _)| name == last) {
+        return Err(format!("duplicated key `{last}`"));
+    }
+    fields.push((last.clone(), value));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Parser, Value};
+
+    fn number(text: &str) -> Value {
+        Value::Number(text.to_string())
+    }
+
+    fn string(text: &str) -> Value {
+        Value::String(text.to_string())
+    }
+
+    #[test]
+    fn parses_case_tables() {
+        let mut parser = Parser::new(
+            "# cases\n[[case]]\na = 1_000 # comment\nb = 'C:\\\\x'\n[case.point]\nx = 0x10\ny = -2.5\n\n[[case]]\n\"a\" = [\n  true, # first\n  \"\\u00e9\",\n]\nb.c = { d = 1 }\n",
+        );
+        let entries = parser.entries().unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                (
+                    2,
+                    vec![
+                        ("a".to_string(), number("1000")),
+                        ("b".to_string(), string("C:\\\\x")),
+                        (
+                            "point".to_string(),
+                            Value::Object(vec![
+                                ("x".to_string(), number("16")),
+                                ("y".to_string(), number("-2.5")),
+                            ])
+                        ),
+                    ]
+                ),
+                (
+                    9,
+                    vec![
+                        (
+                            "a".to_string(),
+                            Value::Array(vec![Value::Bool(true), string("é")])
+                        ),
+                        (
+                            "b".to_string(),
+                            Value::Object(vec![(
+                                "c".to_string(),
+                                Value::Object(vec![("d".to_string(), number("1"))])
+                            )])
+                        ),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_multiline_strings() {
+        let mut parser =
+            Parser::new("[[case]]\na = \"\"\"\nline \\\n   1\n\"\"\"\nb = '''\n\\raw\"'''\n");
+        let entries = parser.entries().unwrap();
+        assert_eq!(
+            entries[0].1,
+            vec![
+                ("a".to_string(), string("line 1\n")),
+                ("b".to_string(), string("\\raw\"")),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_line_of_error() {
+        let mut parser = Parser::new("[[case]]\na = 1\na = 2\n");
+        assert_eq!(parser.entries().unwrap_err(), "duplicated key `a`");
+        assert_eq!(parser.line, 3);
+
+        let mut parser = Parser::new("a = 1\n");
+        assert_eq!(parser.entries().unwrap_err(), "expected `[[case]]` tables");
+    }
+}
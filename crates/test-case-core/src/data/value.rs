@@ -0,0 +1,289 @@
+use super::{arg, error, is_string_like, params, value, Entry};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use std::fmt::{self, Display, Formatter};
+use syn::{Expr, LitStr, ReturnType, Signature, Type};
+
+/// Value of structured data file, numbers are kept as written. Displayed as JSON.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Value {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Fields),
+}
+
+/// Fields of an object in order of declaration.
+pub(super) type Fields = Vec<(String, Value)>;
+
+/// How arrays and objects of data file become arguments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Collections {
+    /// Deserialized with `serde_json` into the parameter type.
+    Deserialized,
+    /// Written as Rust literals, objects are struct literals of the parameter type.
+    Literals,
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Number(number) => write!(f, "{number}"),
+            Value::String(text) => write_string(f, text),
+            Value::Array(values) => {
+                write!(f, "[")?;
+                for (idx, value) in values.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(fields) => {
+                write!(f, "{{")?;
+                for (idx, (name, value)) in fields.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, name)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut Formatter<'_>, text: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in text.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+/// Entries of function with signature `sig` described by `objects` of data file at `path`, with
+/// lines they start at. Objects hold values of parameters under their names, optional `expected`
+/// value, `description` and `ignore` (`true` or reason).
+pub(super) fn entries(
+    objects: Vec<(usize, Fields)>,
+    sig: &Signature,
+    path: &LitStr,
+    collections: Collections,
+) -> syn::Result<Vec<Entry>> {
+    let params = params(sig);
+    let output = match &sig.output {
+        ReturnType::Type(_, ty) => Some(ty.as_ref()),
+        ReturnType::Default => None,
+    };
+
+    objects
+        .into_iter()
+        .map(|(line, mut fields)| {
+            let mut take = |key: &str| {
+                fields
+                    .iter()
+                    .position(|(name, _)| name == key)
+                    .map(|idx| fields.remove(idx).1)
+            };
+            let args = params
+                .iter()
+                .map(|(name, ty)| match take(name) {
+                    Some(arg) => expr(arg, Some(ty), false, collections, path, line),
+                    None => Err(error(path, line, format!("missing value of `{name}`"))),
+                })
+                .collect::<syn::Result<_>>()?;
+            let expected = take("expected")
+                .map(|expected| expr(expected, output, true, collections, path, line))
+                .transpose()?;
+            let description = match take("description") {
+                Some(Value::String(description)) => Some(description),
+                Some(_) => return Err(error(path, line, "description has to be string")),
+                None => None,
+            };
+            let modifiers = match take("ignore") {
+                Some(Value::Bool(true)) => quote! { ignore },
+                Some(Value::String(reason)) => quote! { ignore[#reason] },
+                Some(Value::Bool(false)) | Some(Value::Null) | None => TokenStream2::new(),
+                Some(_) => {
+                    return Err(error(
+                        path,
+                        line,
+                        "ignore has to be boolean or string with reason",
+                    ))
+                }
+            };
+            if let Some((name, _)) = fields.first() {
+                return Err(error(
+                    path,
+                    line,
+                    format!(
+                        "unknown key `{name}`, expected parameter name, `expected`, \
+                         `description` or `ignore`"
+                    ),
+                ));
+            }
+            Ok(Entry {
+                args,
+                modifiers,
+                expected,
+                description,
+            })
+        })
+        .collect()
+}
+
+/// Expression of `value` of type `ty`. Strings are taken verbatim for `&str` and `String` types,
+/// e.g. `"Ferris"`, and are Rust expressions otherwise, `null` is `None`, arrays and objects are
+/// turned into arguments according to `collections`.
+fn expr(
+    value: Value,
+    ty: Option<&Type>,
+    expected: bool,
+    collections: Collections,
+    path: &LitStr,
+    line: usize,
+) -> syn::Result<Expr> {
+    match value {
+        // Expected values are compared with string literals, arguments have to be of the type
+        Value::String(text) => match ty {
+            Some(ty) if expected => self::value(&text, is_string_like(ty), path, line),
+            Some(ty) => arg(&text, ty, path, line),
+            None => self::value(&text, false, path, line),
+        },
+        Value::Null => Ok(syn::parse_quote! { None }),
+        Value::Bool(_) | Value::Number(_) => self::value(&value.to_string(), false, path, line),
+        Value::Array(_) | Value::Object(_) if collections == Collections::Literals => {
+            literal(value, ty, path, line)
+        }
+        Value::Array(_) | Value::Object(_) => deserialized(value, ty, path, line),
+    }
+}
+
+/// Literal of `value` of type `ty`, when known. Arrays are converted with `Into`, e.g. into
+/// `Vec`, objects are struct literals of `ty`. Strings nested in them are string literals
+/// converted with `Into` as well, e.g. into `String`.
+fn literal(value: Value, ty: Option<&Type>, path: &LitStr, line: usize) -> syn::Result<Expr> {
+    let (reference, ty) = match ty {
+        Some(Type::Reference(reference)) => (quote! { & }, Some(reference.elem.as_ref())),
+        ty => (TokenStream2::new(), ty),
+    };
+    match value {
+        Value::Array(values) => {
+            let values = values
+                .into_iter()
+                .map(|value| literal(value, None, path, line))
+                .collect::<syn::Result<Vec<_>>>()?;
+            if reference.is_empty() {
+                Ok(syn::parse_quote! { [#(#values),*].into() })
+            } else {
+                Ok(syn::parse_quote! { &[#(#values),*] })
+            }
+        }
+        Value::Object(fields) => {
+            let mut ty = match ty {
+                Some(Type::Path(ty)) if ty.qself.is_none() => ty.path.clone(),
+                _ => {
+                    return Err(error(
+                        path,
+                        line,
+                        "tables are supported only for parameters of struct types",
+                    ))
+                }
+            };
+            // Generic arguments of paths in expressions require turbofish
+            for segment in &mut ty.segments {
+                if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    args.colon2_token = Some(Default::default());
+                }
+            }
+            let fields = fields
+                .into_iter()
+                .map(|(name, value)| {
+                    let name = syn::parse_str::<syn::Ident>(&name)
+                        .map_err(|_| error(path, line, format!("invalid field name `{name}`")))?;
+                    let value = match value {
+                        Value::Object(_) => {
+                            return Err(error(
+                                path,
+                                line,
+                                format!("type of table `{name}` nested in struct is unknown"),
+                            ))
+                        }
+                        value => literal(value, None, path, line)?,
+                    };
+                    Ok(quote! { #name: #value })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            Ok(syn::parse_quote! { #reference #ty { #(#fields),* } })
+        }
+        Value::String(text) => {
+            let lit = LitStr::new(&text, path.span());
+            Ok(syn::parse_quote! { #lit.into() })
+        }
+        Value::Null => Ok(syn::parse_quote! { None }),
+        Value::Bool(_) | Value::Number(_) => self::value(&value.to_string(), false, path, line),
+    }
+}
+
+#[cfg(feature = "with-json")]
+fn deserialized(value: Value, ty: Option<&Type>, path: &LitStr, line: usize) -> syn::Result<Expr> {
+    let text = value.to_string();
+    match ty {
+        Some(Type::Reference(reference)) => {
+            let ty = &reference.elem;
+            Ok(syn::parse_quote! {
+                &::test_case::serde_json::from_str::<#ty>(#text).unwrap()
+            })
+        }
+        Some(ty) => Ok(syn::parse_quote! {
+            ::test_case::serde_json::from_str::<#ty>(#text).unwrap()
+        }),
+        None => Err(error(
+            path,
+            line,
+            "expected value requires function to return a value",
+        )),
+    }
+}
+
+#[cfg(not(feature = "with-json"))]
+fn deserialized(
+    _value: Value,
+    _ty: Option<&Type>,
+    path: &LitStr,
+    line: usize,
+) -> syn::Result<Expr> {
+    Err(error(
+        path,
+        line,
+        "'with-json' feature is required to deserialize arrays and objects",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+
+    #[test]
+    fn writes_json() {
+        let value = Value::Object(vec![
+            (
+                "x".to_string(),
+                Value::Array(vec![Value::Null, Value::Bool(false)]),
+            ),
+            ("y".to_string(), Value::String("a\"b".to_string())),
+        ]);
+        assert_eq!(value.to_string(), "{\"x\":[null,false],\"y\":\"a\\\"b\"}");
+    }
+}
@@ -0,0 +1,573 @@
+use super::value::{entries, Collections, Fields, Value};
+use super::{cases, error, read};
+use crate::TestCase;
+use std::iter::Peekable;
+use std::str::Chars;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Signature};
+
+/// Arguments of `#[test_cases_from_yaml("path")]`.
+///
+/// The file holds a sequence of mappings, each with values of parameters under their names,
+/// optional `expected` value, `description` and `ignore` (`true` or reason). Common subset of YAML
+/// is supported: block and flow collections, plain, quoted and block scalars, and comments.
+#[derive(Debug)]
+pub struct YamlCases {
+    path: LitStr,
+}
+
+impl Parse for YamlCases {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            path: input.parse()?,
+        })
+    }
+}
+
+impl YamlCases {
+    /// Cases read from the file for function with signature `sig`.
+    pub fn cases(&self, sig: &Signature) -> syn::Result<Vec<TestCase>> {
+        let (full_path, contents) = read(&self.path)?;
+        let objects = Parser::new(&contents)
+            .entries()
+            .map_err(|(line, message)| error(&self.path, line, message))?;
+        cases(
+            entries(objects, sig, &self.path, Collections::Deserialized)?,
+            &full_path,
+        )
+    }
+}
+
+/// Message of parsing error with the line it occurred at.
+type ParseError = (usize, String);
+
+/// Line of YAML document.
+#[derive(Clone, Copy)]
+struct Line<'a> {
+    number: usize,
+    raw: &'a str,
+    indent: usize,
+    /// Content following indentation, without comment.
+    text: &'a str,
+}
+
+/// Parser of YAML documents, working line by line for block collections.
+struct Parser<'a> {
+    lines: Vec<Line<'a>>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        let lines = text
+            .lines()
+            .enumerate()
+            .map(|(idx, raw)| {
+                let content = raw.trim_start_matches(' ');
+                Line {
+                    number: idx + 1,
+                    raw,
+                    indent: raw.len() - content.len(),
+                    text: strip_comment(content).trim_end(),
+                }
+            })
+            .collect();
+        Self { lines, pos: 0 }
+    }
+
+    /// Mappings of the top level sequence with lines they start at.
+    fn entries(mut self) -> Result<Vec<(usize, Fields)>, ParseError> {
+        if self.peek().map(|line| line.text) == Some("---") {
+            self.pos += 1;
+        }
+        let items = match self.peek() {
+            Some(line) if is_sequence_item(line.text) => self.sequence(line.indent)?,
+            Some(line) => return Err((line.number, "expected sequence of cases".to_string())),
+            None => vec![],
+        };
+        if let Some(line) = self.peek() {
+            return Err((line.number, "unexpected indentation".to_string()));
+        }
+        items
+            .into_iter()
+            .map(|(line, item)| match item {
+                Value::Object(fields) => Ok((line, fields)),
+                _ => Err((line, "expected mapping describing case".to_string())),
+            })
+            .collect()
+    }
+
+    /// Next line with content, skipping blank ones and comments.
+    fn peek(&mut self) -> Option<Line<'a>> {
+        while self.lines.get(self.pos)?.text.is_empty() {
+            self.pos += 1;
+        }
+        self.lines.get(self.pos).copied()
+    }
+
+    /// Value starting at the next line, nested deeper than `parent` indentation.
+    fn node(&mut self, parent: usize) -> Result<Value, ParseError> {
+        let (number, indent, text) = match self.peek() {
+            Some(line) if line.indent > parent => (line.number, line.indent, line.text),
+            _ => return Ok(Value::Null),
+        };
+        if is_sequence_item(text) {
+            let items = self.sequence(indent)?;
+            Ok(Value::Array(
+                items.into_iter().map(|(_, item)| item).collect(),
+            ))
+        } else if split_key(text).is_some() {
+            self.mapping(indent).map(Value::Object)
+        } else {
+            self.pos += 1;
+            self.scalar(text, number, parent)
+        }
+    }
+
+    /// Items of block sequence at `indent` with lines they start at.
+    fn sequence(&mut self, indent: usize) -> Result<Vec<(usize, Value)>, ParseError> {
+        let mut items = vec![];
+        while let Some(line) = self.peek() {
+            if line.indent != indent || !is_sequence_item(line.text) {
+                break;
+            }
+            let number = line.number;
+            let rest = line.text[1..].trim_start_matches(' ');
+            if rest.is_empty() {
+                self.pos += 1;
+                items.push((number, self.node(indent)?));
+            } else {
+                // Content following `- ` is nested in the item, as if it was on its own line
+                let offset = line.text.len() - rest.len();
+                let line = &mut self.lines[self.pos];
+                line.indent += offset;
+                line.text = rest;
+                items.push((number, self.node(indent)?));
+            }
+        }
+        Ok(items)
+    }
+
+    /// Entries of block mapping at `indent`.
+    fn mapping(&mut self, indent: usize) -> Result<Fields, ParseError> {
+        let mut fields: Fields = vec![];
+        while let Some(line) = self.peek() {
+            if line.indent != indent || is_sequence_item(line.text) {
+                break;
+            }
+            let number = line.number;
+            let (key, rest) = split_key(line.text)
+                .ok_or_else(|| (number, "expected `key: value`".to_string()))??;
+            self.pos += 1;
+            let value = if rest.is_empty() {
+                match self.peek() {
+                    Some(next) if next.indent == indent && is_sequence_item(next.text) => {
+                        let items = self.sequence(indent)?;
+                        Value::Array(items.into_iter().map(|(_, item)| item).collect())
+                    }
+                    _ => self.node(indent)?,
+                }
+            } else {
+                self.scalar(rest, number, indent)?
+            };
+            if fields.iter().any(|(name, _)| *name == key) {
+                return Err((number, format!("duplicated key `{key}`")));
+            }
+            fields.push((key, value));
+        }
+        Ok(fields)
+    }
+
+    /// Value written in line `number` as `text`, block scalars continue on lines nested deeper
+    /// than `parent` indentation.
+    fn scalar(&mut self, text: &str, number: usize, parent: usize) -> Result<Value, ParseError> {
+        match text.strip_prefix(['|', '>']) {
+            Some(chomping) if ["", "-", "+"].contains(&chomping) => {
+                Ok(Value::String(self.block_scalar(text, parent)))
+            }
+            _ => {
+                let mut flow = Flow {
+                    chars: text.chars().peekable(),
+                };
+                let value = flow.value(false).map_err(|message| (number, message))?;
+                match flow.chars.find(|c| *c != ' ') {
+                    Some(c) => Err((number, format!("unexpected `{c}`"))),
+                    None => Ok(value),
+                }
+            }
+        }
+    }
+
+    /// Contents of block scalar with `header`, e.g. `|-`, which is nested deeper than `parent`.
+    fn block_scalar(&mut self, header: &str, parent: usize) -> String {
+        let mut lines = vec![];
+        while let Some(line) = self.lines.get(self.pos) {
+            let blank = line.raw.trim().is_empty();
+            if !blank && line.indent <= parent {
+                break;
+            }
+            lines.push(line.raw);
+            self.pos += 1;
+        }
+        let indent = lines
+            .iter()
+            .find(|line| !line.trim().is_empty())
+            .map_or(0, |line| line.len() - line.trim_start_matches(' ').len());
+        let mut lines: Vec<&str> = lines
+            .into_iter()
+            .map(|line| line.get(indent..).unwrap_or_default())
+            .collect();
+        let mut trailing = 0;
+        while let Some(line) = lines.last() {
+            if !line.trim().is_empty() {
+                break;
+            }
+            lines.pop();
+            trailing += 1;
+        }
+
+        let mut text = if header.starts_with('|') {
+            lines.join("\n")
+        } else {
+            let mut text = String::new();
+            for (idx, line) in lines.iter().enumerate() {
+                if line.is_empty() {
+                    text.push('\n');
+                } else {
+                    if idx > 0 && !lines[idx - 1].is_empty() {
+                        text.push(' ');
+                    }
+                    text.push_str(line);
+                }
+            }
+            text
+        };
+        if header.ends_with('-') || lines.is_empty() {
+            return text;
+        }
+        text.push('\n');
+        if header.ends_with('+') {
+            text.push_str(&"\n".repeat(trailing));
+        }
+        text
+    }
+}
+
+/// Parser of values written in a single line, including flow collections, e.g. `[1, 2]`.
+struct Flow<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl Flow<'_> {
+    fn value(&mut self, in_collection: bool) -> Result<Value, String> {
+        self.skip_spaces();
+        match self.chars.peek() {
+            Some('[') => {
+                self.chars.next();
+                let mut values = vec![];
+                loop {
+                    self.skip_spaces();
+                    if self.chars.next_if_eq(&']').is_some() {
+                        return Ok(Value::Array(values));
+                    }
+                    values.push(self.value(true)?);
+                    self.skip_spaces();
+                    match self.chars.next() {
+                        Some(',') => {}
+                        Some(']') => return Ok(Value::Array(values)),
+                        _ => return Err("expected `,` or `]`".to_string()),
+                    }
+                }
+            }
+            Some('{') => {
+                self.chars.next();
+                let mut fields = vec![];
+                loop {
+                    self.skip_spaces();
+                    if self.chars.next_if_eq(&'}').is_some() {
+                        return Ok(Value::Object(fields));
+                    }
+                    let key = match self.value(true)? {
+                        Value::String(key) => key,
+                        key => key.to_string(),
+                    };
+                    self.skip_spaces();
+                    if self.chars.next_if_eq(&':').is_none() {
+                        return Err("expected `:`".to_string());
+                    }
+                    fields.push((key, self.value(true)?));
+                    self.skip_spaces();
+                    match self.chars.next() {
+                        Some(',') => {}
+                        Some('}') => return Ok(Value::Object(fields)),
+                        _ => return Err("expected `,` or `}`".to_string()),
+                    }
+                }
+            }
+            Some('"') => {
+                self.chars.next();
+                let mut text = String::new();
+                loop {
+                    match self.chars.next() {
+                        Some('"') => return Ok(Value::String(text)),
+                        Some('\\') => text.push(self.escape()?),
+                        Some(c) => text.push(c),
+                        None => return Err("unterminated string".to_string()),
+                    }
+                }
+            }
+            Some('\'') => {
+                self.chars.next();
+                let mut text = String::new();
+                loop {
+                    match self.chars.next() {
+                        Some('\'') if self.chars.next_if_eq(&'\'').is_some() => text.push('\''),
+                        Some('\'') => return Ok(Value::String(text)),
+                        Some(c) => text.push(c),
+                        None => return Err("unterminated string".to_string()),
+                    }
+                }
+            }
+            _ => {
+                let mut text = String::new();
+                while let Some(&c) = self.chars.peek() {
+                    if in_collection && (",]}".contains(c) || c == ':' && self.ends_key()) {
+                        break;
+                    }
+                    text.push(c);
+                    self.chars.next();
+                }
+                Ok(plain(text.trim()))
+            }
+        }
+    }
+
+    /// Whether `:` at the current position ends key of flow mapping.
+    fn ends_key(&self) -> bool {
+        let mut chars = self.chars.clone();
+        chars.next();
+        matches!(chars.next(), None | Some(' ' | ',' | ']' | '}'))
+    }
+
+    fn escape(&mut self) -> Result<char, String> {
+        let c = match self.chars.next() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('0') => '\0',
+            Some('x') => self.code(2)?,
+            Some('u') => self.code(4)?,
+            Some('U') => self.code(8)?,
+            Some(c @ ('"' | '\\' | '/' | ' ')) => c,
+            _ => return Err("invalid escape sequence".to_string()),
+        };
+        Ok(c)
+    }
+
+    fn code(&mut self, digits: usize) -> Result<char, String> {
+        let digits: String = (0..digits).filter_map(|_| self.chars.next()).collect();
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| "invalid escape sequence".to_string())
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.chars.next_if_eq(&' ').is_some() {}
+    }
+}
+
+/// Value of plain (unquoted) scalar.
+fn plain(text: &str) -> Value {
+    match text {
+        "" | "~" | "null" | "Null" | "NULL" => Value::Null,
+        "true" | "True" | "TRUE" => Value::Bool(true),
+        "false" | "False" | "FALSE" => Value::Bool(false),
+        _ if is_number(text) => Value::Number(text.trim_start_matches('+').to_string()),
+        _ => match radix(text) {
+            Some(number) => Value::Number(number.to_string()),
+            None => Value::String(text.to_string()),
+        },
+    }
+}
+
+/// Value of hexadecimal (`0x`) or octal (`0o`) integer, which JSON doesn't support.
+fn radix(text: &str) -> Option<u128> {
+    let (digits, radix) = match text.get(..2)? {
+        "0x" => (&text[2..], 16),
+        "0o" => (&text[2..], 8),
+        _ => return None,
+    };
+    u128::from_str_radix(digits, radix).ok()
+}
+
+fn is_number(text: &str) -> bool {
+    let digits = text.trim_start_matches(['+', '-']);
+    digits.starts_with(|c: char| c.is_ascii_digit())
+        && digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || "._eE+-".contains(c))
+        && text.replace('_', "").parse::<f64>().is_ok()
+}
+
+fn is_sequence_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// Key of mapping entry written as `text` and the rest of the line, if it is an entry.
+fn split_key(text: &str) -> Option<Result<(String, &str), ParseError>> {
+    if text.starts_with(['"', '\'']) {
+        let mut flow = Flow {
+            chars: text.chars().peekable(),
+        };
+        let key = match flow.value(true) {
+            Ok(Value::String(key)) => key,
+            _ => return None,
+        };
+        let rest: String = flow.chars.collect();
+        let rest = rest.strip_prefix(':')?;
+        if !(rest.is_empty() || rest.starts_with(' ')) {
+            return None;
+        }
+        let rest = text[text.len() - rest.len()..].trim_start();
+        return Some(Ok((key, rest)));
+    }
+    if text.starts_with(['[', '{']) {
+        return None;
+    }
+    text.char_indices()
+        .find(|&(idx, c)| c == ':' && matches!(text[idx + 1..].chars().next(), None | Some(' ')))
+        .map(|(idx, _)| {
+            Ok((
+                text[..idx].trim_end().to_string(),
+                text[idx + 1..].trim_start(),
+            ))
+        })
+}
+
+/// `text` without trailing comment, i.e. `#` at the start or after whitespace, outside of quotes.
+fn strip_comment(text: &str) -> &str {
+    let mut quote = None;
+    let mut previous: Option<char> = None;
+    let mut chars = text.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        match (quote, c) {
+            (Some('"'), '\\') => {
+                chars.next();
+            }
+            (Some(q), c) if q == c => quote = None,
+            (Some(_), _) => {}
+            (None, '#') if !matches!(previous, Some(c) if !c.is_whitespace()) => {
+                return &text[..idx]
+            }
+            // Quotes start scalars only, apostrophes inside of plain scalars are kept
+            (None, '"' | '\'')
+                if matches!(
+                    text[..idx].trim_end().chars().last(),
+                    None | Some(':' | ',' | '[' | '{' | '-')
+                ) =>
+            {
+                quote = Some(c)
+            }
+            (None, _) => {}
+        }
+        previous = Some(c);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Parser, Value};
+
+    fn string(text: &str) -> Value {
+        Value::String(text.to_string())
+    }
+
+    fn number(text: &str) -> Value {
+        Value::Number(text.to_string())
+    }
+
+    #[test]
+    fn parses_block_collections() {
+        let entries = Parser::new(
+            "---\n# cases\n- a: 1\n  b: two # comment\n  c:\n    - x\n    - 'y''s'\n-\n  a: -2.5\n  b: \"#3\"\n  c: []\n",
+        )
+        .entries()
+        .unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                (
+                    3,
+                    vec![
+                        ("a".to_string(), number("1")),
+                        ("b".to_string(), string("two")),
+                        (
+                            "c".to_string(),
+                            Value::Array(vec![string("x"), string("y's")])
+                        ),
+                    ]
+                ),
+                (
+                    8,
+                    vec![
+                        ("a".to_string(), number("-2.5")),
+                        ("b".to_string(), string("#3")),
+                        ("c".to_string(), Value::Array(vec![])),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_flow_collections_and_scalars() {
+        let entries = Parser::new(
+            "- point: {x: 0x1f, y: [true, ~]}\n  text: it's\n  quoted: \"a\\tb\"\n  key:\n  - 1\n",
+        )
+        .entries()
+        .unwrap();
+        assert_eq!(
+            entries[0].1,
+            vec![
+                (
+                    "point".to_string(),
+                    Value::Object(vec![
+                        ("x".to_string(), number("31")),
+                        (
+                            "y".to_string(),
+                            Value::Array(vec![Value::Bool(true), Value::Null])
+                        ),
+                    ])
+                ),
+                ("text".to_string(), string("it's")),
+                ("quoted".to_string(), string("a\tb")),
+                ("key".to_string(), Value::Array(vec![number("1")])),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_block_scalars() {
+        let entries = Parser::new(
+            "- literal: |\n    line 1\n     line 2\n\n  folded: >-\n    word\n    word\n  next: x\n",
+        )
+        .entries()
+        .unwrap();
+        assert_eq!(
+            entries[0].1,
+            vec![
+                ("literal".to_string(), string("line 1\n line 2\n")),
+                ("folded".to_string(), string("word word")),
+                ("next".to_string(), string("x")),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_line_of_error() {
+        let error = Parser::new("- a: 1\n- a: [1, 2\n").entries().unwrap_err();
+        assert_eq!(error, (2, "expected `,` or `]`".to_string()));
+    }
+}
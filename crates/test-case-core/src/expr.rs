@@ -1,13 +1,14 @@
-use crate::complex_expr::ComplexTestCase;
-use crate::modifier::{parse_kws, Modifier};
-use crate::utils::fmt_syn;
+use crate::complex_expr::{self, ComplexTestCase};
+use crate::modifier::{self, parse_kws, Modifier};
+use crate::utils::{any_ident, fmt_syn};
 use crate::TokenStream2;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
+use syn::parse::discouraged::Speculative;
 use syn::parse::{Parse, ParseStream};
 use syn::token::If;
-use syn::{parse_quote, Attribute, Expr, Pat, Token};
+use syn::{parse_quote, Attribute, Expr, LitStr, Pat, Token};
 
 pub mod kw {
     syn::custom_keyword!(matches);
@@ -16,6 +17,12 @@ pub mod kw {
     syn::custom_keyword!(it);
     syn::custom_keyword!(is);
     syn::custom_keyword!(panics);
+    syn::custom_keyword!(exits);
+    syn::custom_keyword!(aborts);
+    syn::custom_keyword!(does_not_panic);
+    syn::custom_keyword!(oracle);
+    syn::custom_keyword!(gtest);
+    syn::custom_keyword!(compile_fail);
 }
 
 #[derive(Clone, Debug)]
@@ -35,18 +42,40 @@ pub enum TestCaseResult {
     Matching(Pat, Option<Box<Expr>>),
     // test_case(a, b, c => panics "abcd")
     Panicking(Option<Expr>),
+    // test_case(a, b, c => panics matches_regex r"index \d+ out of bounds")
+    PanickingMessage(ComplexTestCase),
+    // test_case(a, b, c => panics with MyPanicPayload { code: 42, .. })
+    PanickingWith(Pat, Option<Box<Expr>>),
+    // test_case(a, b, c => does_not_panic)
+    NotPanicking,
+    // test_case(a, b, c => exits 2)
+    Exiting(Expr),
+    // test_case(a, b, c => aborts)
+    Aborting,
+    // test_case(a, b, c => compile_fail "mismatched types")
+    CompileFail(Option<LitStr>),
     // test_case(a, b, c => with |v: T| assert!(v.is_nan()))
     With(Expr),
     // test_case(a, b, c => using assert_nan)
     UseFn(Expr),
+    // test_case(a, b, c => oracle naive::sum)
+    Oracle(Expr),
+    // test_case(a, b, c => gtest contains_substring("x"))
+    #[cfg(feature = "with-googletest")]
+    Gtest(Expr),
     // test_case(a, b, c => is close to 4 precision 0.1)
+    // test_case(a, b, c => matches_regex r"^\d+$")
+    // test_case(a, b, c => not contains "error")
+    // test_case(a, b, c => in 0..100)
+    // test_case(a, b, c => cmp same_name, "expected")
+    // test_case(a, b, c => (eq 1, gt 0.5, matches Some(_)))
     Complex(ComplexTestCase),
 }
 
 impl Parse for TestCaseExpression {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let token: Token![=>] = input.parse()?;
-        let extra_keywords = parse_kws(input);
+        let extra_keywords = parse_kws(input)?;
 
         if input.parse::<kw::matches>().is_ok() {
             let pattern = Pat::parse_single(input)?;
@@ -63,13 +92,86 @@ impl Parse for TestCaseExpression {
                 extra_keywords,
                 result: TestCaseResult::Matching(pattern, guard),
             })
-        } else if input.parse::<kw::it>().is_ok() || input.parse::<kw::is>().is_ok() {
+        } else if input.parse::<kw::it>().is_ok()
+            || input.parse::<kw::is>().is_ok()
+            || input.peek(complex_expr::kw::matches_regex)
+            || input.peek(complex_expr::kw::matching_regex)
+            || input.peek(complex_expr::kw::not)
+            || input.peek(Token![in])
+            || input.peek(complex_expr::kw::cmp)
+            || input.peek(complex_expr::kw::has_fields)
+            || input.peek(complex_expr::kw::debug_eq)
+            || input.peek(complex_expr::kw::bytes_eq)
+            || input.peek(complex_expr::kw::via)
+            || input.peek(complex_expr::kw::within)
+            || (input.peek(complex_expr::kw::map)
+                && input.fork().parse::<ComplexTestCase>().is_ok())
+            || input.peek(complex_expr::kw::serde_eq)
+            || (input.peek(syn::token::Paren) && input.fork().parse::<Expr>().is_err())
+            || (cfg!(feature = "with-json")
+                && input.peek(complex_expr::kw::json)
+                && input.peek2(Token![!]))
+        {
             parse_with_keyword::<_, _>(input, token, extra_keywords, TestCaseResult::Complex)
         } else if input.parse::<kw::using>().is_ok() {
             parse_with_keyword::<_, _>(input, token, extra_keywords, TestCaseResult::UseFn)
         } else if input.parse::<kw::with>().is_ok() {
             parse_with_keyword::<_, _>(input, token, extra_keywords, TestCaseResult::With)
+        } else if input.parse::<kw::oracle>().is_ok() {
+            parse_with_keyword::<_, _>(input, token, extra_keywords, TestCaseResult::Oracle)
+        } else if input.parse::<kw::gtest>().is_ok() {
+            cfg_if::cfg_if! {
+                if #[cfg(feature = "with-googletest")] {
+                    parse_with_keyword::<_, _>(input, token, extra_keywords, TestCaseResult::Gtest)
+                } else {
+                    Err(input.error("'with-googletest' feature is required to use 'gtest' keyword"))
+                }
+            }
+        } else if input.parse::<kw::exits>().is_ok() {
+            parse_with_keyword::<_, _>(input, token, extra_keywords, TestCaseResult::Exiting)
+        } else if input.parse::<kw::does_not_panic>().is_ok() {
+            Ok(TestCaseExpression {
+                _token: token,
+                extra_keywords,
+                result: TestCaseResult::NotPanicking,
+            })
+        } else if input.parse::<kw::compile_fail>().is_ok() {
+            let message = input.peek(LitStr).then(|| input.parse()).transpose()?;
+            Ok(TestCaseExpression {
+                _token: token,
+                extra_keywords,
+                result: TestCaseResult::CompileFail(message),
+            })
+        } else if input.parse::<kw::aborts>().is_ok() {
+            Ok(TestCaseExpression {
+                _token: token,
+                extra_keywords,
+                result: TestCaseResult::Aborting,
+            })
         } else if input.parse::<kw::panics>().is_ok() {
+            if input.parse::<kw::with>().is_ok() {
+                let pattern = Pat::parse_single(input)?;
+                let guard = if input.peek(If) {
+                    let _if_kw: If = input.parse()?;
+                    let guard: Box<Expr> = input.parse()?;
+                    Some(guard)
+                } else {
+                    None
+                };
+
+                return Ok(TestCaseExpression {
+                    _token: token,
+                    extra_keywords,
+                    result: TestCaseResult::PanickingWith(pattern, guard),
+                });
+            }
+            if let Some(matcher) = parse_panic_message_matcher(input)? {
+                return Ok(TestCaseExpression {
+                    _token: token,
+                    extra_keywords,
+                    result: TestCaseResult::PanickingMessage(matcher),
+                });
+            }
             parse_with_keyword_ok::<_, _>(input, token, extra_keywords, TestCaseResult::Panicking)
         } else {
             let result = match input.parse::<Expr>() {
@@ -89,7 +191,7 @@ impl Parse for TestCaseExpression {
 
 impl Display for TestCaseExpression {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for kw in &self.extra_keywords {
+        for kw in modifier::ordered(&self.extra_keywords) {
             write!(f, "{kw:?}")?;
         }
         write!(f, "{}", self.result)
@@ -108,18 +210,63 @@ impl Display for TestCaseResult {
                 "panicking {:?}",
                 expr.as_ref().map(|inner| fmt_syn(&inner))
             ),
+            TestCaseResult::PanickingMessage(matcher) => write!(f, "panicking {matcher}"),
+            TestCaseResult::PanickingWith(pat, expr) => {
+                write!(f, "panicking with {} {}", fmt_syn(pat), fmt_syn(expr))
+            }
+            TestCaseResult::NotPanicking => write!(f, "not panicking"),
+            TestCaseResult::Exiting(expr) => write!(f, "exiting {}", fmt_syn(expr)),
+            TestCaseResult::Aborting => write!(f, "aborting"),
+            TestCaseResult::CompileFail(None) => write!(f, "compile_fail"),
+            TestCaseResult::CompileFail(Some(message)) => {
+                write!(f, "compile_fail {}", message.value())
+            }
             TestCaseResult::With(expr) => write!(f, "with {}", fmt_syn(expr)),
             TestCaseResult::UseFn(expr) => write!(f, "use {}", fmt_syn(expr)),
+            TestCaseResult::Oracle(expr) => write!(f, "oracle {}", fmt_syn(expr)),
+            #[cfg(feature = "with-googletest")]
+            TestCaseResult::Gtest(expr) => write!(f, "gtest {}", fmt_syn(expr)),
             TestCaseResult::Complex(complex) => write!(f, "complex {complex}"),
             TestCaseResult::Empty => write!(f, "empty"),
         }
     }
 }
 
+impl TestCaseResult {
+    /// Expectation phrased for names of cases, e.g. `returns 42` or `panics`.
+    pub fn short_description(&self) -> String {
+        match self {
+            TestCaseResult::Simple(expr) => format!("returns {}", fmt_syn(expr)),
+            TestCaseResult::Matching(pat, _) => format!("matches {}", fmt_syn(pat)),
+            TestCaseResult::Panicking(None) => "panics".to_string(),
+            TestCaseResult::Panicking(Some(expr)) => format!("panics {}", fmt_syn(expr)),
+            TestCaseResult::PanickingMessage(matcher) => format!("panics {matcher}"),
+            TestCaseResult::PanickingWith(pat, _) => format!("panics with {}", fmt_syn(pat)),
+            TestCaseResult::NotPanicking => "does not panic".to_string(),
+            TestCaseResult::Exiting(expr) => format!("exits {}", fmt_syn(expr)),
+            TestCaseResult::Aborting => "aborts".to_string(),
+            TestCaseResult::CompileFail(_) => "fails to compile".to_string(),
+            TestCaseResult::Complex(complex) => format!("is {complex}"),
+            TestCaseResult::Empty => String::new(),
+            result => result.to_string(),
+        }
+    }
+}
+
 impl TestCaseExpression {
+    /// Like `Display`, but with expectation phrased by [`TestCaseResult::short_description`].
+    pub fn short_description(&self) -> String {
+        let mut desc = String::new();
+        for kw in modifier::ordered(&self.extra_keywords) {
+            desc.push_str(&format!("{kw:?} "));
+        }
+        desc.push_str(&self.result.short_description());
+        desc
+    }
+
     pub fn assertion(&self) -> TokenStream2 {
         match &self.result {
-            TestCaseResult::Simple(expr) => parse_quote! { assert_eq!(_result, #expr) },
+            TestCaseResult::Simple(expr) => test_eq_assertion(expr, None),
             TestCaseResult::Matching(pat, guard) => {
                 let pat_str = pat.to_token_stream().to_string();
 
@@ -142,18 +289,115 @@ impl TestCaseExpression {
                 }
             }
             TestCaseResult::Panicking(_) => TokenStream2::new(),
-            TestCaseResult::With(expr) => parse_quote! { let fun = #expr; fun(_result) },
+            TestCaseResult::PanickingMessage(matcher) => {
+                let assertion = matcher.assertion();
+                let message = panic_message();
+                parse_quote! {
+                    let _result = match _result {
+                        ::std::result::Result::Ok(_) => {
+                            panic!("expected test case to panic, but it returned normally")
+                        }
+                        ::std::result::Result::Err(payload) => #message,
+                    };
+                    #assertion
+                }
+            }
+            TestCaseResult::NotPanicking => not_panicking_assertion("test case"),
+            // Needs case arguments, rendered by `TestCase::render` via `oracle_assertion`
+            TestCaseResult::Oracle(_) => TokenStream2::new(),
+            #[cfg(feature = "with-googletest")]
+            TestCaseResult::Gtest(matcher) => parse_quote! {
+                {
+                    use ::test_case::googletest::prelude::*;
+                    ::test_case::googletest::verify_that!(_result, #matcher)
+                }
+            },
+            TestCaseResult::PanickingWith(pat, guard) => {
+                let pat_str = pat.to_token_stream().to_string();
+                let guard = guard
+                    .as_ref()
+                    .map(|guard| quote! { if #guard })
+                    .unwrap_or_default();
+
+                parse_quote! {
+                    match _result {
+                        ::std::result::Result::Ok(_) => {
+                            panic!("expected test case to panic, but it returned normally")
+                        }
+                        ::std::result::Result::Err(payload) => {
+                            fn _tc_downcast<T: 'static, F: FnOnce(&T) -> bool>(
+                                payload: &(dyn ::std::any::Any + Send),
+                                matches: F,
+                            ) -> ::std::option::Option<bool> {
+                                payload.downcast_ref::<T>().map(matches)
+                            }
+
+                            match _tc_downcast(&*payload, |payload| match payload {
+                                #pat #guard => true,
+                                _ => false,
+                            }) {
+                                ::std::option::Option::Some(true) => (),
+                                ::std::option::Option::Some(false) => {
+                                    panic!("Expected panic payload `{}` found other value", #pat_str)
+                                }
+                                ::std::option::Option::None => {
+                                    panic!("Expected panic payload `{}` found payload of other type", #pat_str)
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            TestCaseResult::Exiting(code) => parse_quote! {
+                assert!(
+                    _result.status.code() == ::std::option::Option::Some(#code),
+                    "expected test case to exit with code {}, but subprocess finished with {}\n{}",
+                    #code,
+                    _result.status,
+                    ::std::string::String::from_utf8_lossy(&_result.stderr)
+                )
+            },
+            TestCaseResult::Aborting => parse_quote! {
+                {
+                    #[cfg(unix)]
+                    let _tc_aborted = ::std::os::unix::process::ExitStatusExt::signal(&_result.status)
+                        == ::std::option::Option::Some(6);
+                    #[cfg(not(unix))]
+                    let _tc_aborted = !_result.status.success()
+                        && _result.status.code() != ::std::option::Option::Some(101);
+                    assert!(
+                        _tc_aborted,
+                        "expected test case to abort, but subprocess finished with {}\n{}",
+                        _result.status,
+                        ::std::string::String::from_utf8_lossy(&_result.stderr)
+                    )
+                }
+            },
+            TestCaseResult::CompileFail(None) => TokenStream2::new(),
+            TestCaseResult::CompileFail(Some(message)) => parse_quote! {
+                assert!(
+                    _result.contains(#message),
+                    "expected compile error containing {:?}, but rustc reported:\n{}",
+                    #message,
+                    _result
+                )
+            },
+            TestCaseResult::With(expr) => parse_quote! {
+                fn _tc_with<T, F: FnOnce(T)>(value: T, fun: F) {
+                    fun(value)
+                }
+                _tc_with(_result, #expr)
+            },
             TestCaseResult::UseFn(path) => parse_quote! { #path(_result) },
             TestCaseResult::Complex(complex) => complex.assertion(),
             TestCaseResult::Empty => TokenStream2::new(),
         }
     }
 
-    pub fn attributes(&self) -> Vec<Attribute> {
-        let mut attrs: Vec<Attribute> = self
-            .extra_keywords
-            .iter()
-            .map(|modifier| modifier.attribute())
+    pub fn attributes(&self, placeholders: &[(String, String)]) -> Vec<Attribute> {
+        let mut attrs: Vec<Attribute> = modifier::ordered(&self.extra_keywords)
+            .into_iter()
+            .flat_map(|modifier| modifier.attributes(placeholders))
             .collect();
         if let TestCaseResult::Panicking(opt) = &self.result {
             if let Some(expr) = opt {
@@ -164,6 +408,225 @@ impl TestCaseExpression {
         }
         attrs
     }
+
+    /// Applies modifiers wrapping the whole test body, e.g. `timeout`.
+    pub fn wrap_body(&self, block: TokenStream2, is_async: bool) -> TokenStream2 {
+        modifier::ordered(&self.extra_keywords)
+            .into_iter()
+            .fold(block, |block, kw| kw.wrap(block, is_async))
+    }
+
+    /// Whether the expectation may refer to case arguments by the names of function parameters.
+    pub fn binds_arguments(&self) -> bool {
+        matches!(
+            self.result,
+            TestCaseResult::Simple(_)
+                | TestCaseResult::Matching(_, _)
+                | TestCaseResult::Complex(_)
+                | TestCaseResult::With(_)
+                | TestCaseResult::UseFn(_)
+        )
+    }
+
+    /// Return type of generated test function, `assertion()` evaluates to its value.
+    pub fn return_type(&self) -> Option<TokenStream2> {
+        match self.result {
+            #[cfg(feature = "with-googletest")]
+            TestCaseResult::Gtest(_) => Some(quote! { ::test_case::googletest::Result<()> }),
+            _ => None,
+        }
+    }
+
+    /// Date and reason of `ignore_until` modifier, if any.
+    pub fn ignored_until(&self) -> Option<(&LitStr, Option<&LitStr>)> {
+        self.extra_keywords.iter().find_map(|kw| match kw {
+            Modifier::IgnoreUntil(date, reason) => Some((date, reason.as_ref())),
+            _ => None,
+        })
+    }
+
+    /// Reason of `inconclusive` (or `ignore`) modifier, empty when not given.
+    pub fn inconclusive_reason(&self) -> Option<String> {
+        self.extra_keywords.iter().find_map(|kw| match kw {
+            Modifier::Inconclusive => Some(String::new()),
+            Modifier::InconclusiveWithReason(reason) => Some(reason.value()),
+            _ => None,
+        })
+    }
+
+    /// Duration expression of `warn_if_slower_than` threshold, if any.
+    pub fn slow_warning_threshold(&self) -> Option<TokenStream2> {
+        self.extra_keywords.iter().find_map(|kw| match kw {
+            Modifier::WarnIfSlowerThan(duration) => Some(modifier::duration(duration)),
+            _ => None,
+        })
+    }
+
+    /// Position requested with `order = N`, if any.
+    pub fn order(&self) -> Option<u32> {
+        self.extra_keywords.iter().find_map(|kw| match kw {
+            Modifier::Order(order) => Some(*order),
+            _ => None,
+        })
+    }
+
+    /// Async function declared with `async_setup = path`, if any.
+    pub fn async_setup(&self) -> Option<&Expr> {
+        self.extra_keywords.iter().find_map(|kw| match kw {
+            Modifier::AsyncSetup(setup) => Some(setup),
+            _ => None,
+        })
+    }
+
+    /// Arguments of generated `#[tokio::test(...)]` declared with `rt(...)`, if any.
+    pub fn runtime_args(&self) -> Option<TokenStream2> {
+        self.extra_keywords.iter().find_map(|kw| match kw {
+            #[cfg(feature = "with-tokio")]
+            Modifier::Runtime(rt) => Some(rt.tokens.clone()),
+            _ => None,
+        })
+    }
+
+    /// Whether the case is compiled only under some `cfg`, e.g. with `only(unix)`.
+    pub fn is_conditional(&self) -> bool {
+        self.extra_keywords.iter().any(|kw| match kw {
+            Modifier::Only(_) | Modifier::RequiresFeature(_) => true,
+            Modifier::Attributes(attrs) => attrs.iter().any(|attr| attr.path().is_ident("cfg")),
+            _ => false,
+        })
+    }
+
+    pub fn expects_failure(&self) -> bool {
+        self.extra_keywords
+            .iter()
+            .any(|kw| matches!(kw, Modifier::ExpectedFailure(_)))
+    }
+
+    /// Keyword of modifier which is not supported for async functions, if any.
+    pub fn sync_only_modifier(&self) -> Option<&'static str> {
+        modifier::ordered(&self.extra_keywords)
+            .into_iter()
+            .find_map(Modifier::sync_only_keyword)
+    }
+
+    /// Keyword of modifier which is only supported for async functions, if any.
+    pub fn async_only_modifier(&self) -> Option<&'static str> {
+        modifier::ordered(&self.extra_keywords)
+            .into_iter()
+            .find_map(Modifier::async_only_keyword)
+    }
+
+    /// Whether the test body has to be run in a child process so the assertion can inspect its
+    /// exit status.
+    pub fn runs_in_subprocess(&self) -> bool {
+        matches!(
+            self.result,
+            TestCaseResult::Exiting(_) | TestCaseResult::Aborting
+        )
+    }
+
+    /// Whether the expectation awaits a future, which requires an async test function.
+    pub fn awaits(&self) -> bool {
+        any_ident(self.assertion(), &|ident| ident == "await")
+    }
+
+    /// Whether the case is checked by compiling the function and the call standalone with `rustc`
+    /// instead of running it, the assertion inspects compiler errors.
+    pub fn expects_compile_error(&self) -> bool {
+        matches!(self.result, TestCaseResult::CompileFail(_))
+    }
+
+    /// Whether the test body has to be run inside `catch_unwind` so the assertion can inspect the
+    /// panic payload.
+    pub fn catches_unwind(&self) -> bool {
+        matches!(
+            self.result,
+            TestCaseResult::PanickingMessage(_)
+                | TestCaseResult::PanickingWith(_, _)
+                | TestCaseResult::NotPanicking
+        )
+    }
+}
+
+/// Converts `payload: Box<dyn Any + Send>` caught by `catch_unwind` into panic message.
+fn panic_message() -> TokenStream2 {
+    quote! {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            ::std::string::String::from(*message)
+        } else if let Some(message) = payload.downcast_ref::<::std::string::String>() {
+            message.clone()
+        } else {
+            ::std::string::String::from("Box<dyn Any>")
+        }
+    }
+}
+
+pub(crate) fn not_panicking_assertion(case: &str) -> TokenStream2 {
+    let message = panic_message();
+    quote! {
+        if let ::std::result::Result::Err(payload) = _result {
+            panic!("{} panicked with: {}", #case, #message)
+        }
+    }
+}
+
+pub(crate) fn oracle_assertion<'a>(
+    oracle: &Expr,
+    args: impl Iterator<Item = &'a Expr>,
+) -> TokenStream2 {
+    let expected = quote! { (#oracle)(#(#args),*) };
+    let message = format!("result differs from oracle `{}`", fmt_syn(oracle));
+    test_eq_assertion(expected, Some(&message))
+}
+
+/// Same as `assert_eq!(_result, expected)` but compares via `test_case::TestEq`.
+fn test_eq_assertion(expected: impl ToTokens, message: Option<&str>) -> TokenStream2 {
+    let message = message.map(|m| format!(": {m}")).unwrap_or_default();
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "with-pretty-assertions")] {
+            let message = format!("assertion failed: `(left == right)`{message}\n\n{{}}\n");
+            let failure = quote! {
+                panic!(#message, ::test_case::pretty_assertions::Comparison::new(left, right))
+            };
+        } else {
+            let message =
+                format!("assertion `left == right` failed{message}\n  left: {{:?}}\n right: {{:?}}");
+            let failure = quote! { panic!(#message, left, right) };
+        }
+    }
+    quote! {
+        match (&_result, &(#expected)) {
+            (left, right) => {
+                if !::test_case::TestEq::test_eq(left, right) {
+                    #failure
+                }
+            }
+        }
+    }
+}
+
+/// `panics` accepts either an expected message (handled by `#[should_panic]`) or a complex
+/// expression matched against the panic message.
+fn parse_panic_message_matcher(input: ParseStream) -> syn::Result<Option<ComplexTestCase>> {
+    if input.is_empty() || input.peek(syn::Lit) || input.peek(Token![;]) {
+        return Ok(None);
+    }
+
+    let fork = input.fork();
+    match fork.parse::<ComplexTestCase>() {
+        Ok(matcher) => {
+            input.advance_to(&fork);
+            Ok(Some(matcher))
+        }
+        Err(e) => {
+            let fork = input.fork();
+            if fork.parse::<Expr>().is_ok() && (fork.is_empty() || fork.peek(Token![;])) {
+                Ok(None)
+            } else {
+                Err(e)
+            }
+        }
+    }
 }
 
 fn parse_with_keyword<Inner, Mapping>(
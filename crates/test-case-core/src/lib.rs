@@ -1,12 +1,21 @@
 use proc_macro2::TokenStream as TokenStream2;
 
+mod case_set;
+mod cases_from;
 mod comment;
 mod complex_expr;
+mod data;
 mod expr;
 mod modifier;
+mod naming;
+mod standalone;
 mod test_case;
 mod test_matrix;
 mod utils;
 
+pub use case_set::CaseSets;
+pub use cases_from::TestCasesFrom;
+pub use data::{CsvCases, FileCases, IncludedCases, JsonCases, TomlCases, YamlCases};
+pub use naming::Naming;
 pub use test_case::TestCase;
 pub use test_matrix::TestMatrix;
@@ -1,18 +1,69 @@
+use crate::utils::{fmt_syn, interpolate, parse_date};
+use proc_macro2::{Span as Span2, TokenStream as TokenStream2};
+use quote::quote;
 use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use syn::parse::{Parse, ParseStream};
-use syn::token::Bracket;
-use syn::{bracketed, parse_quote, Attribute, LitStr};
+use syn::punctuated::Punctuated;
+use syn::token::{Bracket, Paren};
+use syn::{
+    bracketed, parenthesized, parse_quote, Attribute, Expr, LitInt, LitStr, Meta, MetaList, Token,
+};
 
 mod kw {
     syn::custom_keyword!(inconclusive);
     syn::custom_keyword!(ignore);
+    syn::custom_keyword!(ignore_if);
+    syn::custom_keyword!(timeout);
+    syn::custom_keyword!(retries);
+    syn::custom_keyword!(serial);
+    syn::custom_keyword!(skip_if_env);
+    syn::custom_keyword!(only);
+    syn::custom_keyword!(requires_feature);
+    syn::custom_keyword!(with);
+    syn::custom_keyword!(tags);
+    syn::custom_keyword!(xfail);
+    syn::custom_keyword!(order);
+    syn::custom_keyword!(before);
+    syn::custom_keyword!(after);
+    syn::custom_keyword!(warn_if_slower_than);
+    syn::custom_keyword!(skip_under_miri);
+    syn::custom_keyword!(skip_under_sanitizer);
+    syn::custom_keyword!(max_alloc);
+    syn::custom_keyword!(ignore_until);
+    syn::custom_keyword!(local_set);
+    syn::custom_keyword!(async_setup);
+    syn::custom_keyword!(rt);
 }
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Modifier {
     Inconclusive,
     InconclusiveWithReason(LitStr),
+    IgnoreIf(MetaList, Option<LitStr>),
+    IgnoreUntil(LitStr, Option<LitStr>),
+    SkipUnderMiri(Option<LitStr>),
+    SkipUnderSanitizer(LitStr, Option<LitStr>),
+    Timeout(LitInt),
+    Retries(LitInt),
+    Serial(Option<LitStr>),
+    SkipIfEnv(LitStr),
+    Only(Meta),
+    RequiresFeature(LitStr),
+    Attributes(Vec<Attribute>),
+    Tags(Vec<LitStr>),
+    ExpectedFailure(Option<LitStr>),
+    Order(u32),
+    Before(Expr),
+    After(Expr),
+    AsyncSetup(Expr),
+    WarnIfSlowerThan(LitInt),
+    #[cfg(feature = "with-max-alloc")]
+    MaxAlloc(LitInt),
+    #[cfg(feature = "with-tokio")]
+    LocalSet,
+    #[cfg(feature = "with-tokio")]
+    Runtime(MetaList),
 }
 
 impl Debug for Modifier {
@@ -21,6 +72,48 @@ impl Debug for Modifier {
             Modifier::Inconclusive | Modifier::InconclusiveWithReason(_) => {
                 write!(f, "inconclusive")
             }
+            Modifier::IgnoreIf(predicate, _) => write!(f, "ignore_if {} ", fmt_syn(predicate)),
+            Modifier::IgnoreUntil(date, _) => write!(f, "ignore_until {} ", date.value()),
+            Modifier::SkipUnderMiri(_) => write!(f, "skip_under_miri "),
+            Modifier::SkipUnderSanitizer(sanitizer, _) => {
+                write!(f, "skip_under_sanitizer {} ", sanitizer.value())
+            }
+            Modifier::Timeout(duration) => write!(f, "timeout {duration} "),
+            Modifier::WarnIfSlowerThan(duration) => write!(f, "warn_if_slower_than {duration} "),
+            #[cfg(feature = "with-max-alloc")]
+            Modifier::MaxAlloc(size) => write!(f, "max_alloc {size} "),
+            #[cfg(feature = "with-tokio")]
+            Modifier::LocalSet => write!(f, "local_set "),
+            #[cfg(feature = "with-tokio")]
+            Modifier::Runtime(rt) => write!(f, "rt {} ", fmt_syn(&rt.tokens)),
+            Modifier::Retries(retries) => write!(f, "retries {retries} "),
+            Modifier::Serial(None) => write!(f, "serial "),
+            Modifier::Serial(Some(group)) => write!(f, "serial {} ", group.value()),
+            Modifier::SkipIfEnv(var) => write!(f, "skip_if_env {} ", var.value()),
+            Modifier::Only(predicate) => write!(f, "only {} ", fmt_syn(predicate)),
+            Modifier::RequiresFeature(feature) => {
+                write!(f, "requires_feature {} ", feature.value())
+            }
+            Modifier::ExpectedFailure(_) => write!(f, "xfail "),
+            // order is expressed by the prefix of test name
+            Modifier::Order(_) => Ok(()),
+            Modifier::Before(hook) => write!(f, "before {} ", fmt_syn(hook)),
+            Modifier::After(hook) => write!(f, "after {} ", fmt_syn(hook)),
+            Modifier::AsyncSetup(setup) => write!(f, "async_setup {} ", fmt_syn(setup)),
+            Modifier::Tags(tags) => {
+                write!(f, "tags")?;
+                for tag in tags {
+                    write!(f, " {}", tag.value())?;
+                }
+                write!(f, " ")
+            }
+            Modifier::Attributes(attrs) => {
+                write!(f, "with")?;
+                for attr in attrs {
+                    write!(f, " {}", fmt_syn(attr))?;
+                }
+                write!(f, " ")
+            }
         }
     }
 }
@@ -33,6 +126,107 @@ impl Parse for Modifier {
         } else if input.peek(kw::ignore) {
             let _: kw::ignore = input.parse()?;
             Self::parse_inconclusive(input)
+        } else if input.peek(kw::ignore_if) {
+            let _: kw::ignore_if = input.parse()?;
+            Self::parse_ignore_if(input)
+        } else if input.peek(kw::ignore_until) {
+            let _: kw::ignore_until = input.parse()?;
+            Self::parse_ignore_until(input)
+        } else if input.peek(kw::timeout) {
+            let _: kw::timeout = input.parse()?;
+            Self::parse_timeout(input)
+        } else if input.peek(kw::max_alloc) {
+            let _: kw::max_alloc = input.parse()?;
+            let _: Option<Token![=]> = input.parse()?;
+            Self::parse_max_alloc(input)
+        } else if input.peek(kw::local_set) {
+            let _: kw::local_set = input.parse()?;
+            Self::parse_local_set(input)
+        } else if input.peek(kw::rt) {
+            Self::parse_runtime(input)
+        } else if input.peek(kw::skip_under_miri) {
+            let _: kw::skip_under_miri = input.parse()?;
+            let reason = match Self::parse_inconclusive(input)? {
+                Self::InconclusiveWithReason(reason) => Some(reason),
+                _ => None,
+            };
+            Ok(Self::SkipUnderMiri(reason))
+        } else if input.peek(kw::skip_under_sanitizer) {
+            let _: kw::skip_under_sanitizer = input.parse()?;
+            let content;
+            let _: Paren = parenthesized!(content in input);
+            let sanitizer: LitStr = content.parse()?;
+            let reason = match Self::parse_inconclusive(input)? {
+                Self::InconclusiveWithReason(reason) => Some(reason),
+                _ => None,
+            };
+            Ok(Self::SkipUnderSanitizer(sanitizer, reason))
+        } else if input.peek(kw::warn_if_slower_than) {
+            let _: kw::warn_if_slower_than = input.parse()?;
+            let _: Option<Token![=]> = input.parse()?;
+            let duration = parse_duration(input, "warn_if_slower_than", "= 500ms")?;
+            Ok(Self::WarnIfSlowerThan(duration))
+        } else if input.peek(kw::retries) {
+            let _: kw::retries = input.parse()?;
+            let _: Option<Token![=]> = input.parse()?;
+            Ok(Self::Retries(input.parse()?))
+        } else if input.peek(kw::serial) {
+            let _: kw::serial = input.parse()?;
+            Self::parse_serial(input)
+        } else if input.peek(kw::skip_if_env) {
+            let _: kw::skip_if_env = input.parse()?;
+            let content;
+            let _: Paren = parenthesized!(content in input);
+            Ok(Self::SkipIfEnv(content.parse()?))
+        } else if input.peek(kw::only) {
+            let _: kw::only = input.parse()?;
+            let content;
+            let _: Paren = parenthesized!(content in input);
+            Ok(Self::Only(content.parse()?))
+        } else if input.peek(kw::requires_feature) {
+            let _: kw::requires_feature = input.parse()?;
+            if input.peek(Paren) {
+                let content;
+                let _: Paren = parenthesized!(content in input);
+                Ok(Self::RequiresFeature(content.parse()?))
+            } else {
+                let _: Token![=] = input.parse()?;
+                Ok(Self::RequiresFeature(input.parse()?))
+            }
+        } else if input.peek(kw::xfail) {
+            let _: kw::xfail = input.parse()?;
+            let reason = match Self::parse_inconclusive(input)? {
+                Self::InconclusiveWithReason(reason) => Some(reason),
+                _ => None,
+            };
+            Ok(Self::ExpectedFailure(reason))
+        } else if input.peek(kw::order) {
+            let _: kw::order = input.parse()?;
+            let _: Option<Token![=]> = input.parse()?;
+            let order: LitInt = input.parse()?;
+            Ok(Self::Order(order.base10_parse()?))
+        } else if input.peek(kw::before) {
+            let _: kw::before = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            Ok(Self::Before(input.parse()?))
+        } else if input.peek(kw::after) {
+            let _: kw::after = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            Ok(Self::After(input.parse()?))
+        } else if input.peek(kw::async_setup) {
+            let _: kw::async_setup = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            Ok(Self::AsyncSetup(input.parse()?))
+        } else if input.peek(kw::tags) {
+            let _: kw::tags = input.parse()?;
+            let _: Option<Token![=]> = input.parse()?;
+            let content;
+            let _: Bracket = bracketed!(content in input);
+            let tags = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+            Ok(Self::Tags(tags.into_iter().collect()))
+        } else if Self::peek_attributes(input) {
+            let _: kw::with = input.parse()?;
+            Self::parse_attributes(input)
         } else {
             Err(syn::Error::new(input.span(), "unknown modifier keyword"))
         }
@@ -40,6 +234,58 @@ impl Parse for Modifier {
 }
 
 impl Modifier {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(kw::inconclusive)
+            || input.peek(kw::ignore)
+            || (input.peek(kw::ignore_if) && input.peek2(Paren))
+            || (input.peek(kw::ignore_until) && input.peek2(Paren))
+            || input.peek(kw::skip_under_miri)
+            || input.peek(kw::local_set)
+            || (input.peek(kw::rt) && input.peek2(Paren))
+            || (input.peek(kw::max_alloc) && (input.peek2(LitInt) || input.peek2(Token![=])))
+            || (input.peek(kw::skip_under_sanitizer) && input.peek2(Paren))
+            || (input.peek(kw::timeout) && (input.peek2(LitInt) || input.peek2(Token![=])))
+            || (input.peek(kw::warn_if_slower_than)
+                && (input.peek2(LitInt) || input.peek2(Token![=])))
+            || (input.peek(kw::retries) && (input.peek2(LitInt) || input.peek2(Token![=])))
+            || input.peek(kw::serial)
+            || input.peek(kw::xfail)
+            || (input.peek(kw::order) && (input.peek2(LitInt) || input.peek2(Token![=])))
+            || (input.peek(kw::before) && input.peek2(Token![=]))
+            || (input.peek(kw::after) && input.peek2(Token![=]))
+            || (input.peek(kw::async_setup) && input.peek2(Token![=]))
+            || (input.peek(kw::skip_if_env) && input.peek2(Paren))
+            || (input.peek(kw::only) && input.peek2(Paren))
+            || (input.peek(kw::requires_feature) && (input.peek2(Paren) || input.peek2(Token![=])))
+            || (input.peek(kw::tags) && (input.peek2(Bracket) || input.peek2(Token![=])))
+            || Self::peek_attributes(input)
+    }
+
+    /// `with(#[attr], ..)`, as opposed to `with` expectation taking a closure.
+    fn peek_attributes(input: ParseStream) -> bool {
+        let fork = input.fork();
+        let peek = || -> syn::Result<bool> {
+            let _: kw::with = fork.parse()?;
+            let content;
+            let _: Paren = parenthesized!(content in fork);
+            Ok(content.peek(Token![#]))
+        };
+        peek().unwrap_or_default()
+    }
+
+    pub fn parse_attributes(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let _: Paren = parenthesized!(content in input);
+        let mut attrs = vec![];
+        while !content.is_empty() {
+            attrs.extend(content.call(Attribute::parse_outer)?);
+            if !content.is_empty() {
+                let _: Token![,] = content.parse()?;
+            }
+        }
+        Ok(Self::Attributes(attrs))
+    }
+
     pub fn parse_inconclusive(input: ParseStream) -> syn::Result<Self> {
         if input.peek(Bracket) {
             let content;
@@ -51,18 +297,463 @@ impl Modifier {
         }
     }
 
-    pub fn attribute(&self) -> Attribute {
+    pub fn parse_ignore_if(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let _: Paren = parenthesized!(content in input);
+        let predicate: MetaList = content.parse()?;
+        if !predicate.path.is_ident("cfg") {
+            return Err(syn::Error::new_spanned(
+                predicate.path,
+                "expected `cfg(...)` predicate, e.g. `ignore_if(cfg(miri))`",
+            ));
+        }
+        let reason = match Self::parse_inconclusive(input)? {
+            Self::InconclusiveWithReason(reason) => Some(reason),
+            _ => None,
+        };
+        Ok(Self::IgnoreIf(predicate, reason))
+    }
+
+    /// `ignore_until("2025-12-01", "reason")` ignores the case, its companion test fails once the
+    /// date has passed.
+    pub fn parse_ignore_until(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let _: Paren = parenthesized!(content in input);
+        let date: LitStr = content.parse()?;
+        let reason = if content.is_empty() {
+            None
+        } else {
+            let _: Token![,] = content.parse()?;
+            Some(content.parse::<LitStr>()?)
+        };
+        if parse_date(&date.value()).is_none() {
+            return Err(syn::Error::new(
+                date.span(),
+                "expected date in `YYYY-MM-DD` format",
+            ));
+        }
+        Ok(Self::IgnoreUntil(date, reason))
+    }
+
+    pub fn parse_serial(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        if input.peek(Paren) {
+            let _: Paren = parenthesized!(content in input);
+        } else if input.peek(Bracket) {
+            let _: Bracket = bracketed!(content in input);
+        } else {
+            return Ok(Self::Serial(None));
+        }
+        Ok(Self::Serial(Some(content.parse()?)))
+    }
+
+    #[cfg(feature = "with-max-alloc")]
+    pub fn parse_max_alloc(input: ParseStream) -> syn::Result<Self> {
+        let size: LitInt = input.parse()?;
+        alloc_budget(&size)?;
+        Ok(Self::MaxAlloc(size))
+    }
+
+    #[cfg(not(feature = "with-max-alloc"))]
+    pub fn parse_max_alloc(input: ParseStream) -> syn::Result<Self> {
+        Err(syn::Error::new(
+            input.span(),
+            "'with-max-alloc' feature is required to use 'max_alloc' keyword",
+        ))
+    }
+
+    #[cfg(feature = "with-tokio")]
+    pub fn parse_local_set(_input: ParseStream) -> syn::Result<Self> {
+        Ok(Self::LocalSet)
+    }
+
+    #[cfg(not(feature = "with-tokio"))]
+    pub fn parse_local_set(input: ParseStream) -> syn::Result<Self> {
+        Err(syn::Error::new(
+            input.span(),
+            "'with-tokio' feature is required to use 'local_set' keyword",
+        ))
+    }
+
+    #[cfg(feature = "with-tokio")]
+    pub fn parse_runtime(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self::Runtime(input.parse()?))
+    }
+
+    #[cfg(not(feature = "with-tokio"))]
+    pub fn parse_runtime(input: ParseStream) -> syn::Result<Self> {
+        Err(syn::Error::new(
+            input.span(),
+            "'with-tokio' feature is required to use 'rt' keyword",
+        ))
+    }
+
+    pub fn parse_timeout(input: ParseStream) -> syn::Result<Self> {
+        let _: Option<Token![=]> = input.parse()?;
+        parse_duration(input, "timeout", "2s").map(Self::Timeout)
+    }
+
+    /// `placeholders` are substituted in the ignore reason, e.g. `{name}` with an argument value.
+    pub fn attributes(&self, placeholders: &[(String, String)]) -> Vec<Attribute> {
+        match self {
+            Modifier::Attributes(attrs) => attrs.clone(),
+            modifier => modifier.attribute(placeholders).into_iter().collect(),
+        }
+    }
+
+    fn attribute(&self, placeholders: &[(String, String)]) -> Option<Attribute> {
+        match self {
+            Modifier::Inconclusive => Some(parse_quote! { #[ignore] }),
+            Modifier::InconclusiveWithReason(r) => {
+                let r = LitStr::new(&interpolate(&r.value(), placeholders), r.span());
+                Some(parse_quote! { #[ignore = #r] })
+            }
+            Modifier::IgnoreIf(predicate, reason) => {
+                let condition = &predicate.tokens;
+                Some(match reason {
+                    Some(r) => {
+                        let r = LitStr::new(&interpolate(&r.value(), placeholders), r.span());
+                        parse_quote! { #[cfg_attr(#condition, ignore = #r)] }
+                    }
+                    None => parse_quote! { #[cfg_attr(#condition, ignore)] },
+                })
+            }
+            Modifier::IgnoreUntil(date, reason) => {
+                let reason = match reason {
+                    Some(r) => format!(
+                        "{} (until {})",
+                        interpolate(&r.value(), placeholders),
+                        date.value()
+                    ),
+                    None => format!("ignored until {}", date.value()),
+                };
+                let reason = LitStr::new(&reason, date.span());
+                Some(parse_quote! { #[ignore = #reason] })
+            }
+            Modifier::SkipUnderMiri(reason) => {
+                let reason = reason.as_ref().map_or_else(
+                    || LitStr::new("not supported under miri", Span2::call_site()),
+                    |r| LitStr::new(&interpolate(&r.value(), placeholders), r.span()),
+                );
+                Some(parse_quote! { #[cfg_attr(miri, ignore = #reason)] })
+            }
+            // `cfg(sanitize)` is unstable: crates using it need nightly and `#![feature(cfg_sanitize)]`,
+            // it is a hard error on stable
+            Modifier::SkipUnderSanitizer(sanitizer, reason) => {
+                let reason = reason.as_ref().map_or_else(
+                    || {
+                        let reason = format!("not supported under {} sanitizer", sanitizer.value());
+                        LitStr::new(&reason, sanitizer.span())
+                    },
+                    |r| LitStr::new(&interpolate(&r.value(), placeholders), r.span()),
+                );
+                Some(parse_quote! { #[cfg_attr(sanitize = #sanitizer, ignore = #reason)] })
+            }
+            Modifier::Only(predicate) => Some(parse_quote! { #[cfg(#predicate)] }),
+            Modifier::RequiresFeature(feature) => Some(parse_quote! { #[cfg(feature = #feature)] }),
+            Modifier::Timeout(_)
+            | Modifier::Retries(_)
+            | Modifier::Serial(_)
+            | Modifier::SkipIfEnv(_)
+            | Modifier::Tags(_)
+            | Modifier::ExpectedFailure(_)
+            | Modifier::Order(_)
+            | Modifier::Before(_)
+            | Modifier::After(_)
+            | Modifier::AsyncSetup(_)
+            | Modifier::WarnIfSlowerThan(_)
+            | Modifier::Attributes(_) => None,
+            #[cfg(feature = "with-max-alloc")]
+            Modifier::MaxAlloc(_) => None,
+            #[cfg(feature = "with-tokio")]
+            Modifier::LocalSet | Modifier::Runtime(_) => None,
+        }
+    }
+
+    /// Wraps body of generated test, `block` evaluates to the value returned by the test.
+    /// `is_async` tells whether `block` is a body of async test.
+    pub fn wrap(&self, block: TokenStream2, is_async: bool) -> TokenStream2 {
+        match self {
+            Modifier::Inconclusive
+            | Modifier::InconclusiveWithReason(_)
+            | Modifier::IgnoreIf(..)
+            | Modifier::IgnoreUntil(..)
+            | Modifier::SkipUnderMiri(_)
+            | Modifier::SkipUnderSanitizer(..)
+            | Modifier::Only(_)
+            | Modifier::RequiresFeature(_)
+            | Modifier::Order(_)
+            | Modifier::WarnIfSlowerThan(_)
+            | Modifier::AsyncSetup(_)
+            | Modifier::Attributes(_) => block,
+            #[cfg(feature = "with-tokio")]
+            Modifier::Runtime(_) => block,
+            #[cfg(feature = "with-max-alloc")]
+            Modifier::MaxAlloc(size) => {
+                let budget = alloc_budget(size).expect("validated while parsing");
+                let budget = LitInt::new(&format!("{budget}usize"), size.span());
+                let limit = size.to_string();
+                quote! {
+                    let _tc_alloc_budget = ::test_case::__private::AllocBudget::new(#budget, #limit);
+                    let _tc_value = { #block };
+                    _tc_alloc_budget.check();
+                    _tc_value
+                }
+            }
+            #[cfg(feature = "with-tokio")]
+            Modifier::LocalSet => quote! {
+                ::test_case::tokio::task::LocalSet::new()
+                    .run_until(async move { #block })
+                    .await
+            },
+            Modifier::Before(hook) => quote! {
+                #hook;
+                #block
+            },
+            Modifier::After(hook) => quote! {
+                let _tc_after = ::test_case::__private::After::new(|| {
+                    #hook;
+                });
+                #block
+            },
+            Modifier::Timeout(duration) if is_async => async_timeout_wrapper(duration, block),
+            Modifier::Timeout(duration) => timeout_wrapper(duration, block),
+            Modifier::Retries(retries) => {
+                let retries = LitInt::new(retries.base10_digits(), retries.span());
+                quote! {
+                    ::test_case::__private::retry(#retries, || { #block })
+                }
+            }
+            Modifier::Serial(group) => {
+                let group = group.as_ref().map(LitStr::value).unwrap_or_default();
+                quote! {
+                    let _tc_serial_guard = ::test_case::__private::serial_lock(#group);
+                    #block
+                }
+            }
+            Modifier::SkipIfEnv(var) => quote! {
+                if ::std::env::var_os(#var).is_some() {
+                    ::std::eprintln!("skipped: environment variable `{}` is set", #var);
+                    return ::test_case::__private::Skipped::skipped();
+                }
+                #block
+            },
+            Modifier::ExpectedFailure(reason) => {
+                let reason = reason.as_ref().map(LitStr::value).unwrap_or_default();
+                quote! {
+                    ::test_case::__private::expect_failure(#reason, || { #block })
+                }
+            }
+            Modifier::Tags(tags) => quote! {
+                if !::test_case::__private::tags_selected(&[#(#tags),*]) {
+                    ::std::eprintln!("skipped: tags not selected by `TEST_CASE_TAGS`");
+                    return ::test_case::__private::Skipped::skipped();
+                }
+                #block
+            },
+        }
+    }
+
+    /// Keyword of modifier which can't wrap body of async test, if any.
+    pub fn sync_only_keyword(&self) -> Option<&'static str> {
         match self {
-            Modifier::Inconclusive => parse_quote! { #[ignore] },
-            Modifier::InconclusiveWithReason(r) => parse_quote! { #[ignore = #r] },
+            Modifier::Inconclusive
+            | Modifier::InconclusiveWithReason(_)
+            | Modifier::IgnoreIf(..)
+            | Modifier::IgnoreUntil(..)
+            | Modifier::SkipUnderMiri(_)
+            | Modifier::SkipUnderSanitizer(..)
+            | Modifier::Only(_)
+            | Modifier::RequiresFeature(_)
+            | Modifier::Attributes(_)
+            | Modifier::Tags(_)
+            | Modifier::Serial(_)
+            | Modifier::Order(_)
+            | Modifier::Before(_)
+            | Modifier::After(_)
+            | Modifier::AsyncSetup(_)
+            | Modifier::WarnIfSlowerThan(_)
+            | Modifier::SkipIfEnv(_) => None,
+            #[cfg(any(feature = "with-tokio", feature = "with-async-std"))]
+            Modifier::Timeout(_) => None,
+            #[cfg(not(any(feature = "with-tokio", feature = "with-async-std")))]
+            Modifier::Timeout(_) => Some("timeout"),
+            Modifier::Retries(_) => Some("retries"),
+            Modifier::ExpectedFailure(_) => Some("xfail"),
+            // allocations are counted per thread, while async tasks may move between threads
+            #[cfg(feature = "with-max-alloc")]
+            Modifier::MaxAlloc(_) => Some("max_alloc"),
+            #[cfg(feature = "with-tokio")]
+            Modifier::LocalSet | Modifier::Runtime(_) => None,
         }
     }
+
+    /// Keyword of modifier which can only wrap body of async test, if any.
+    pub fn async_only_keyword(&self) -> Option<&'static str> {
+        match self {
+            #[cfg(feature = "with-tokio")]
+            Modifier::LocalSet => Some("local_set"),
+            #[cfg(feature = "with-tokio")]
+            Modifier::Runtime(_) => Some("rt"),
+            Modifier::AsyncSetup(_) => Some("async_setup"),
+            _ => None,
+        }
+    }
+
+    /// Wrappers with lower order are applied first, i.e. end up innermost.
+    fn wrapping_order(&self) -> u8 {
+        match self {
+            Modifier::Inconclusive
+            | Modifier::InconclusiveWithReason(_)
+            | Modifier::IgnoreIf(..)
+            | Modifier::IgnoreUntil(..)
+            | Modifier::SkipUnderMiri(_)
+            | Modifier::SkipUnderSanitizer(..)
+            | Modifier::Only(_)
+            | Modifier::RequiresFeature(_)
+            | Modifier::Order(_)
+            | Modifier::WarnIfSlowerThan(_)
+            | Modifier::AsyncSetup(_)
+            | Modifier::Attributes(_) => 0,
+            // `after` is applied first, so its guard is created once `before` finished
+            Modifier::After(_) => 0,
+            #[cfg(feature = "with-max-alloc")]
+            Modifier::MaxAlloc(_) => 0,
+            #[cfg(feature = "with-tokio")]
+            Modifier::LocalSet | Modifier::Runtime(_) => 0,
+            Modifier::Before(_) => 1,
+            Modifier::Timeout(_) => 2,
+            Modifier::Retries(_) => 3,
+            Modifier::ExpectedFailure(_) => 4,
+            Modifier::Serial(_) => 5,
+            Modifier::SkipIfEnv(_) | Modifier::Tags(_) => 6,
+        }
+    }
+}
+
+/// Parses integer with `ms` or `s` suffix, `example` follows `keyword` in the error message.
+fn parse_duration(input: ParseStream, keyword: &str, example: &str) -> syn::Result<LitInt> {
+    let duration: LitInt = input.parse()?;
+    match duration.suffix() {
+        "ms" | "s" => Ok(duration),
+        _ => Err(syn::Error::new(
+            duration.span(),
+            format!("{keyword} requires `ms` or `s` suffix, e.g. `{keyword} {example}`"),
+        )),
+    }
 }
 
-pub fn parse_kws(input: ParseStream) -> HashSet<Modifier> {
+/// Number of bytes in size with `B`, `KB`, `MB`, `GB` or `KiB`, `MiB`, `GiB` suffix.
+#[cfg(feature = "with-max-alloc")]
+fn alloc_budget(size: &LitInt) -> syn::Result<u64> {
+    let unit: u64 = match size.suffix() {
+        "B" => 1,
+        "KB" => 1_000,
+        "MB" => 1_000_000,
+        "GB" => 1_000_000_000,
+        "KiB" => 1 << 10,
+        "MiB" => 1 << 20,
+        "GiB" => 1 << 30,
+        _ => {
+            return Err(syn::Error::new(
+                size.span(),
+                "max_alloc requires `B`, `KB`, `MB`, `GB`, `KiB`, `MiB` or `GiB` suffix, e.g. `max_alloc = 10MB`",
+            ))
+        }
+    };
+    size.base10_parse::<u64>()?
+        .checked_mul(unit)
+        .ok_or_else(|| syn::Error::new(size.span(), "max_alloc is too large"))
+}
+
+/// `std::time::Duration` expression of duration parsed by [`parse_duration`].
+pub fn duration(duration: &LitInt) -> TokenStream2 {
+    let value = LitInt::new(duration.base10_digits(), duration.span());
+    match duration.suffix() {
+        "ms" => quote! { ::std::time::Duration::from_millis(#value) },
+        _ => quote! { ::std::time::Duration::from_secs(#value) },
+    }
+}
+
+fn timeout_wrapper(duration: &LitInt, block: TokenStream2) -> TokenStream2 {
+    let duration = self::duration(duration);
+    quote! {
+        let _tc_timeout = #duration;
+        let (_tc_sender, _tc_receiver) = ::std::sync::mpsc::channel();
+        let _tc_thread = ::std::thread::current();
+        let _tc_handle = ::std::thread::Builder::new()
+            .name(_tc_thread.name().unwrap_or_default().to_string())
+            .spawn(move || {
+                let _ = _tc_sender.send({ #block });
+            })
+            .expect("failed to spawn test case thread");
+        match _tc_receiver.recv_timeout(_tc_timeout) {
+            Ok(_tc_value) => _tc_value,
+            Err(::std::sync::mpsc::RecvTimeoutError::Timeout) => {
+                panic!("test case timed out after {:?}", _tc_timeout)
+            }
+            Err(::std::sync::mpsc::RecvTimeoutError::Disconnected) => match _tc_handle.join() {
+                Err(payload) => ::std::panic::resume_unwind(payload),
+                Ok(()) => unreachable!("test case thread finished without result"),
+            },
+        }
+    }
+}
+
+/// Async counterpart of [`timeout_wrapper`], uses timer of async runtime so it respects paused time.
+/// Only reachable with `with-tokio` or `with-async-std` feature.
+fn async_timeout_wrapper(duration: &LitInt, block: TokenStream2) -> TokenStream2 {
+    let duration = self::duration(duration);
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "with-tokio")] {
+            let timeout = quote! { ::test_case::tokio::time::timeout };
+        } else {
+            let timeout = quote! { ::test_case::async_std::future::timeout };
+        }
+    }
+    quote! {
+        let _tc_timeout = #duration;
+        match #timeout(_tc_timeout, async { #block }).await {
+            ::std::result::Result::Ok(_tc_value) => _tc_value,
+            ::std::result::Result::Err(_) => panic!("test case timed out after {:?}", _tc_timeout),
+        }
+    }
+}
+
+pub fn parse_kws(input: ParseStream) -> syn::Result<HashSet<Modifier>> {
     let mut kws = HashSet::new();
-    while let Ok(kw) = Modifier::parse(input) {
-        kws.insert(kw);
+    while Modifier::peek(input) {
+        kws.insert(input.parse()?);
     }
+    Ok(kws)
+}
+
+/// Modifiers in the order their wrappers are applied.
+pub fn ordered(kws: &HashSet<Modifier>) -> Vec<&Modifier> {
+    let mut kws: Vec<_> = kws.iter().collect();
+    kws.sort_by_key(|kw| (kw.wrapping_order(), format!("{kw:?}")));
     kws
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Modifier;
+    use syn::{parse_quote, Attribute};
+
+    #[test]
+    fn skip_under_sanitizer() {
+        let modifier: Modifier = parse_quote! { skip_under_sanitizer("address") };
+        let expected: Attribute = parse_quote! {
+            #[cfg_attr(sanitize = "address", ignore = "not supported under address sanitizer")]
+        };
+        assert_eq!(modifier.attributes(&[]), vec![expected]);
+        assert_eq!(format!("{modifier:?}"), "skip_under_sanitizer address ");
+
+        let modifier: Modifier = parse_quote! { skip_under_sanitizer("thread")["racy for {0}"] };
+        let expected: Attribute = parse_quote! {
+            #[cfg_attr(sanitize = "thread", ignore = "racy for 1")]
+        };
+        let placeholders = [("0".to_string(), "1".to_string())];
+        assert_eq!(modifier.attributes(&placeholders), vec![expected]);
+    }
+}
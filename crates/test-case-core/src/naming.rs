@@ -0,0 +1,612 @@
+use crate::utils::{is_keyword, keyword_safe_ident, sanitize_test_name};
+use proc_macro2::{Ident, Span as Span2, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Error, Expr, ExprLit, Lit, LitInt, LitStr, Meta, MetaNameValue, Token, UseTree,
+    Visibility,
+};
+
+/// Options of `#[test_case::naming(...)]` controlling names of generated cases.
+#[derive(Clone, Debug, Default)]
+pub struct Naming {
+    /// `name = "{fn}_{arg0}"`: template of names of cases without description.
+    pub(crate) template: Option<LitStr>,
+    /// `unicode = "transliterate"`: how non-ASCII characters end up in names.
+    unicode: Unicode,
+    /// `casing = "snake_case"`: how uppercase letters end up in names.
+    casing: Casing,
+    /// `expected = "short"`: expectation in names without description is phrased briefly.
+    pub(crate) short_expected: bool,
+    /// `max_len = 64`: longer names are truncated and suffixed with hash of the whole name.
+    max_len: Option<usize>,
+    /// `nextest_safe = true`: names consist of ASCII letters, digits and single inner `_`, so they
+    /// can be used in filters of `cargo nextest` and shell without quoting.
+    nextest_safe: bool,
+    /// `module = "parser_cases"`: name of module holding generated cases instead of function name.
+    module: Option<Ident>,
+    /// `flat = true`: cases are siblings of the function, named `{fn}_{case}`, instead of being
+    /// put into a module.
+    pub(crate) flat: bool,
+    /// `nested = true`: cases are grouped into modules named after values of all arguments but
+    /// the last one, e.g. `width_4::height_2`.
+    pub(crate) nested: bool,
+    /// `groups = true`: description `"parsing :: empty input"` generates case `empty_input` in
+    /// module `parsing`.
+    pub(crate) groups: bool,
+    /// `gate_original = true`: the function itself is compiled under the same `cfg` as its cases.
+    gate_original: bool,
+    /// `keyword_prefix = "kw_"`: names which would be keywords are prefixed instead of being
+    /// raw identifiers, e.g. `kw_match` instead of `r#match`.
+    keyword_prefix: Option<String>,
+    /// `vis = "pub(crate)"`: visibility of generated modules and cases, private by default.
+    pub(crate) vis: Option<Visibility>,
+    /// `imports = ["super::parse", "std::fmt"]`: imports of the module holding generated cases
+    /// replacing `use super::*`.
+    imports: Option<Vec<UseTree>>,
+    /// `cfg = any(test, feature = "integration")`: predicate under which generated cases are
+    /// compiled instead of `test`.
+    cfg: Option<Meta>,
+}
+
+/// Casing of generated names.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Casing {
+    /// ASCII letters are lowercased, e.g. `MyType` becomes `mytype`.
+    #[default]
+    Lowercase,
+    /// Words of camel case are separated before lowercasing, e.g. `MyType` becomes `my_type`.
+    SnakeCase,
+    /// Letters are kept as they are, e.g. `MyType` stays `MyType`.
+    Preserve,
+}
+
+/// Strategy of representing non-ASCII characters of arguments and descriptions in names.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Unicode {
+    /// Letters are kept as they are, other characters become `_`.
+    #[default]
+    Keep,
+    /// Latin letters with diacritics lose them, e.g. `żółć` becomes `zolc`, other characters are
+    /// hex-escaped.
+    Transliterate,
+    /// Characters are replaced with their code points, e.g. `ż` becomes `u17c`.
+    Hex,
+}
+
+impl Parse for Naming {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut naming = Naming::default();
+        let options = Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?;
+        for option in options {
+            if option.path.is_ident("name") {
+                naming.template = Some(lit_str(option.value)?);
+            } else if option.path.is_ident("unicode") {
+                let strategy = lit_str(option.value)?;
+                naming.unicode = match strategy.value().as_str() {
+                    "keep" => Unicode::Keep,
+                    "transliterate" => Unicode::Transliterate,
+                    "hex" => Unicode::Hex,
+                    _ => {
+                        return Err(Error::new_spanned(
+                            strategy,
+                            "expected `\"keep\"`, `\"transliterate\"` or `\"hex\"`",
+                        ))
+                    }
+                };
+            } else if option.path.is_ident("expected") {
+                let expected = lit_str(option.value)?;
+                naming.short_expected = match expected.value().as_str() {
+                    "full" => false,
+                    "short" => true,
+                    _ => {
+                        return Err(Error::new_spanned(
+                            expected,
+                            "expected `\"full\"` or `\"short\"`",
+                        ))
+                    }
+                };
+            } else if option.path.is_ident("max_len") {
+                let max_len = lit_int(option.value)?;
+                let value: usize = max_len.base10_parse()?;
+                if value < MIN_LEN {
+                    return Err(Error::new_spanned(
+                        max_len,
+                        format!("max_len has to be at least {MIN_LEN}"),
+                    ));
+                }
+                naming.max_len = Some(value);
+            } else if option.path.is_ident("module") {
+                let module = lit_str(option.value)?;
+                naming.module = Some(module.parse().map_err(|_| {
+                    Error::new_spanned(&module, "module has to be a valid identifier")
+                })?);
+            } else if option.path.is_ident("cfg") {
+                let value = option.value.into_token_stream();
+                naming.cfg = Some(syn::parse2(value.clone()).map_err(|_| {
+                    Error::new_spanned(value, "expected cfg predicate, e.g. `any(test, miri)`")
+                })?);
+            } else if option.path.is_ident("imports") {
+                let imports = match option.value {
+                    Expr::Array(array) => array.elems,
+                    value => return Err(Error::new_spanned(value, "expected array of imports")),
+                };
+                naming.imports = Some(
+                    imports
+                        .into_iter()
+                        .map(|import| {
+                            let import = lit_str(import)?;
+                            import.parse().map_err(|_| {
+                                Error::new_spanned(
+                                    &import,
+                                    "expected import, e.g. `\"super::{parse, Token}\"`",
+                                )
+                            })
+                        })
+                        .collect::<syn::Result<_>>()?,
+                );
+            } else if option.path.is_ident("vis") {
+                let vis = lit_str(option.value)?;
+                naming.vis = Some(vis.parse().map_err(|_| {
+                    Error::new_spanned(&vis, "expected visibility, e.g. `\"pub(crate)\"`")
+                })?);
+            } else if option.path.is_ident("keyword_prefix") {
+                let prefix = lit_str(option.value)?;
+                if syn::parse_str::<Ident>(&format!("{}match", prefix.value())).is_err() {
+                    return Err(Error::new_spanned(
+                        prefix,
+                        "prefix has to start an identifier, e.g. `\"kw_\"`",
+                    ));
+                }
+                naming.keyword_prefix = Some(prefix.value());
+            } else if option.path.is_ident("gate_original") {
+                naming.gate_original = lit_bool(option.value)?;
+            } else if option.path.is_ident("groups") {
+                naming.groups = lit_bool(option.value)?;
+            } else if option.path.is_ident("nested") {
+                naming.nested = lit_bool(option.value)?;
+            } else if option.path.is_ident("flat") {
+                naming.flat = lit_bool(option.value)?;
+            } else if option.path.is_ident("nextest_safe") {
+                naming.nextest_safe = lit_bool(option.value)?;
+            } else if option.path.is_ident("casing") {
+                let casing = lit_str(option.value)?;
+                naming.casing = match casing.value().as_str() {
+                    "lowercase" => Casing::Lowercase,
+                    "snake_case" => Casing::SnakeCase,
+                    "preserve" => Casing::Preserve,
+                    _ => {
+                        return Err(Error::new_spanned(
+                            casing,
+                            "expected `\"lowercase\"`, `\"snake_case\"` or `\"preserve\"`",
+                        ))
+                    }
+                };
+            } else if option.path.is_ident("namer") {
+                // Names are identifiers, so they have to be known before user code can run
+                return Err(Error::new_spanned(
+                    &option.path,
+                    "functions can't be called while generating names, use template instead, \
+                     e.g. `name = \"given_{arg0}_when_{arg1}_then_{expected}\"`",
+                ));
+            } else {
+                return Err(Error::new_spanned(
+                    &option.path,
+                    "unknown naming option, expected `name`, `unicode`, `casing`, `max_len`, `expected`, `nextest_safe`, `module`, `flat`, `nested`, `vis`, `imports`, `cfg`, `groups`, `gate_original` or `keyword_prefix`",
+                ));
+            }
+        }
+        if naming.flat && naming.nested {
+            return Err(Error::new(
+                input.span(),
+                "`nested = true` can't be used together with `flat = true`",
+            ));
+        }
+        if naming.flat && naming.groups {
+            return Err(Error::new(
+                input.span(),
+                "`groups = true` can't be used together with `flat = true`",
+            ));
+        }
+        if naming.flat {
+            if let Some(module) = naming.module.as_ref() {
+                return Err(Error::new_spanned(
+                    module,
+                    "`module` can't be used together with `flat = true`",
+                ));
+            }
+            if naming.imports.is_some() {
+                return Err(Error::new(
+                    input.span(),
+                    "`imports` can't be used together with `flat = true`",
+                ));
+            }
+        }
+        Ok(naming)
+    }
+}
+
+impl Naming {
+    /// Removes `#[test_case::naming(..)]` from `attrs`, returns options declared by it.
+    pub fn take(attrs: &mut Vec<Attribute>) -> syn::Result<Self> {
+        let is_naming = |attr: &Attribute| {
+            let path = attr.path();
+            path.is_ident("naming")
+                || (path.segments.len() == 2
+                    && path.segments[0].ident == "test_case"
+                    && path.segments[1].ident == "naming")
+        };
+        let mut naming = None;
+        for attr in attrs.iter().filter(|attr| is_naming(attr)) {
+            if naming.is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    "only one `naming` can be declared",
+                ));
+            }
+            naming = Some(attr.parse_args()?);
+        }
+        attrs.retain(|attr| !is_naming(attr));
+        Ok(naming.unwrap_or_default())
+    }
+
+    /// Name of module holding generated cases of function `fn_name`.
+    pub fn module(&self, fn_name: &Ident) -> Ident {
+        self.module.clone().unwrap_or_else(|| fn_name.clone())
+    }
+
+    /// Imports of the module holding generated cases, `use super::*` unless declared otherwise.
+    pub fn imports(&self) -> TokenStream2 {
+        match self.imports.as_ref() {
+            Some(imports) => quote! { #(use #imports;)* },
+            None => quote! {
+                #[allow(unused_imports)]
+                use super::*;
+            },
+        }
+    }
+
+    /// Predicate under which generated cases are compiled, `test` unless declared otherwise.
+    pub fn cfg(&self) -> TokenStream2 {
+        match self.cfg.as_ref() {
+            Some(cfg) => cfg.to_token_stream(),
+            None => quote! { test },
+        }
+    }
+
+    /// Whether the function itself is compiled under the same `cfg` as its cases.
+    pub fn gates_original(&self) -> bool {
+        self.gate_original
+    }
+
+    /// Visibility of generated modules and cases.
+    pub fn visibility(&self) -> Option<&Visibility> {
+        self.vis.as_ref()
+    }
+
+    /// Whether cases are generated next to the function instead of in a module.
+    pub fn is_flat(&self) -> bool {
+        self.flat
+    }
+
+    /// Identifier of the case described by `desc`.
+    pub(crate) fn escape(&self, desc: String) -> Ident {
+        let hash = fnv1a(desc.as_bytes());
+        let unicode = match self.unicode {
+            Unicode::Keep if self.nextest_safe => Unicode::Transliterate,
+            unicode => unicode,
+        };
+        let desc = match unicode {
+            Unicode::Keep => desc,
+            Unicode::Transliterate => desc
+                .chars()
+                .flat_map(char::to_lowercase)
+                .map(|c| match transliterate(c) {
+                    Some(ascii) => ascii.to_string(),
+                    None if c.is_ascii() => c.to_string(),
+                    None => hex(c),
+                })
+                .collect(),
+            Unicode::Hex => desc
+                .chars()
+                .map(|c| if c.is_ascii() { c.to_string() } else { hex(c) })
+                .collect(),
+        };
+        let name = match self.casing {
+            Casing::Lowercase => sanitize_test_name(desc.to_ascii_lowercase()),
+            Casing::SnakeCase => sanitize_test_name(snake_case(&desc).to_ascii_lowercase()),
+            Casing::Preserve => sanitize_test_name(desc),
+        };
+        let name = if self.nextest_safe {
+            let name = name.trim_matches('_');
+            if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                name.to_string()
+            } else {
+                format!("case_{name}").trim_end_matches('_').to_string()
+            }
+        } else {
+            name
+        };
+        let name = match self.max_len {
+            Some(max_len) if name.len() > max_len => {
+                let suffix = format!("_{:08x}", hash as u32);
+                let mut end = max_len - suffix.len();
+                while !name.is_char_boundary(end) {
+                    end -= 1;
+                }
+                format!("{}{suffix}", name[..end].trim_end_matches('_'))
+            }
+            _ => name,
+        };
+        match self.keyword_prefix.as_ref() {
+            Some(prefix) if is_keyword(&name) => {
+                Ident::new(&format!("{prefix}{name}"), Span2::call_site())
+            }
+            _ => keyword_safe_ident(&name),
+        }
+    }
+}
+
+/// Shortest `max_len`, so that some part of the name is kept next to the hash suffix.
+const MIN_LEN: usize = 16;
+
+/// FNV-1a hash, stable across Rust versions unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Separates words of camel case with `_`, e.g. `NotFound` becomes `Not_Found`.
+fn snake_case(desc: &str) -> String {
+    let mut snake = String::with_capacity(desc.len());
+    let mut prev: Option<char> = None;
+    for c in desc.chars() {
+        if c.is_uppercase()
+            && matches!(prev, Some(prev) if prev.is_lowercase() || prev.is_ascii_digit())
+        {
+            snake.push('_');
+        }
+        snake.push(c);
+        prev = Some(c);
+    }
+    snake
+}
+
+fn hex(c: char) -> String {
+    format!("_u{:x}_", u32::from(c))
+}
+
+/// ASCII counterpart of lowercase Latin letter with diacritic.
+fn transliterate(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        'ß' => "ss",
+        'æ' => "ae",
+        'œ' => "oe",
+        'þ' => "th",
+        _ => return None,
+    })
+}
+
+fn lit_bool(value: Expr) -> syn::Result<bool> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Bool(lit),
+            ..
+        }) => Ok(lit.value),
+        value => Err(Error::new_spanned(value, "expected `true` or `false`")),
+    }
+}
+
+fn lit_int(value: Expr) -> syn::Result<LitInt> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => Ok(lit),
+        value => Err(Error::new_spanned(value, "expected integer literal")),
+    }
+}
+
+fn lit_str(value: Expr) -> syn::Result<LitStr> {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Ok(lit),
+        value => Err(Error::new_spanned(value, "expected string literal")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::naming::Naming;
+    use syn::{parse_quote, Visibility};
+
+    #[test]
+    fn takes_naming_attribute() {
+        let mut attrs = vec![
+            parse_quote! { #[test_case::naming(name = "{fn}_{arg0}")] },
+            parse_quote! { #[allow(dead_code)] },
+        ];
+        let naming = Naming::take(&mut attrs).unwrap();
+        assert_eq!(naming.template.unwrap().value(), "{fn}_{arg0}");
+        assert_eq!(attrs.len(), 1);
+    }
+
+    #[test]
+    fn suggests_template_instead_of_namer() {
+        let mut attrs = vec![parse_quote! { #[naming(namer = names::given_when_then)] }];
+        let err = Naming::take(&mut attrs).unwrap_err();
+        assert!(err.to_string().contains("use template instead"));
+    }
+
+    #[test]
+    fn transliterates_or_escapes_unicode() {
+        let naming = |unicode: &str| {
+            let mut attrs = vec![parse_quote! { #[naming(unicode = #unicode)] }];
+            Naming::take(&mut attrs).unwrap()
+        };
+        let desc = || "Żółć ⟶ zolc".to_string();
+        assert_eq!(naming("keep").escape(desc()), "_Żółć_zolc");
+        assert_eq!(naming("transliterate").escape(desc()), "zolc_u27f6_zolc");
+        assert_eq!(
+            naming("hex").escape(desc()),
+            "_u17b_uf3_u142_u107_u27f6_zolc"
+        );
+    }
+
+    #[test]
+    fn applies_casing() {
+        let naming = |casing: &str| {
+            let mut attrs = vec![parse_quote! { #[naming(casing = #casing)] }];
+            Naming::take(&mut attrs).unwrap()
+        };
+        let desc = || "HttpError::NotFound 404".to_string();
+        assert_eq!(naming("lowercase").escape(desc()), "httperror_notfound_404");
+        assert_eq!(
+            naming("snake_case").escape(desc()),
+            "http_error_not_found_404"
+        );
+        assert_eq!(naming("preserve").escape(desc()), "HttpError_NotFound_404");
+    }
+
+    #[test]
+    fn truncates_long_names() {
+        let mut attrs = vec![parse_quote! { #[naming(max_len = 20)] }];
+        let naming = Naming::take(&mut attrs).unwrap();
+        let short = naming.escape("short name".to_string());
+        assert_eq!(short, "short_name");
+
+        let long = naming.escape("a very long name of the case".to_string());
+        let other = naming.escape("a very long name of another case".to_string());
+        assert_eq!(long.to_string().len(), 20);
+        assert!(long.to_string().starts_with("a_very_long_"));
+        assert_ne!(long, other);
+    }
+
+    #[test]
+    fn rejects_too_short_max_len() {
+        let mut attrs = vec![parse_quote! { #[naming(max_len = 8)] }];
+        assert!(Naming::take(&mut attrs).is_err());
+    }
+
+    #[test]
+    fn produces_nextest_safe_names() {
+        let mut attrs = vec![parse_quote! { #[naming(nextest_safe = true)] }];
+        let naming = Naming::take(&mut attrs).unwrap();
+        assert_eq!(naming.escape(" parses \"ż\" ".to_string()), "parses_z");
+        assert_eq!(naming.escape("1 => panics".to_string()), "case_1_panics");
+        assert_eq!(naming.escape("→".to_string()), "u2192");
+        assert_eq!(naming.escape(String::new()), "empty");
+    }
+
+    #[test]
+    fn overrides_module_name() {
+        let mut attrs = vec![parse_quote! { #[naming(module = "parser_cases")] }];
+        let naming = Naming::take(&mut attrs).unwrap();
+        assert_eq!(naming.module(&parse_quote!(parse)), "parser_cases");
+        assert_eq!(Naming::default().module(&parse_quote!(parse)), "parse");
+
+        let mut attrs = vec![parse_quote! { #[naming(module = "parser cases")] }];
+        assert!(Naming::take(&mut attrs).is_err());
+    }
+
+    #[test]
+    fn parses_visibility() {
+        let mut attrs = vec![parse_quote! { #[naming(vis = "pub(crate)")] }];
+        let naming = Naming::take(&mut attrs).unwrap();
+        assert!(matches!(
+            naming.visibility(),
+            Some(Visibility::Restricted(_))
+        ));
+
+        let mut attrs = vec![parse_quote! { #[naming(vis = "crate")] }];
+        assert!(Naming::take(&mut attrs).is_err());
+    }
+
+    #[test]
+    fn replaces_glob_import() {
+        let naming = Naming::default();
+        assert_eq!(
+            naming.imports().to_string(),
+            "# [allow (unused_imports)] use super :: * ;"
+        );
+
+        let mut attrs = vec![parse_quote! { #[naming(imports = ["super::{a, b}", "std::fmt"])] }];
+        let naming = Naming::take(&mut attrs).unwrap();
+        assert_eq!(
+            naming.imports().to_string(),
+            "use super :: { a , b } ; use std :: fmt ;"
+        );
+
+        let mut attrs = vec![parse_quote! { #[naming(imports = [])] }];
+        let naming = Naming::take(&mut attrs).unwrap();
+        assert!(naming.imports().is_empty());
+
+        let mut attrs = vec![parse_quote! { #[naming(imports = ["super::"])] }];
+        assert!(Naming::take(&mut attrs).is_err());
+    }
+
+    #[test]
+    fn replaces_cfg_predicate() {
+        assert_eq!(Naming::default().cfg().to_string(), "test");
+
+        let mut attrs = vec![parse_quote! { #[naming(cfg = any(test, feature = "integration"))] }];
+        let naming = Naming::take(&mut attrs).unwrap();
+        assert_eq!(
+            naming.cfg().to_string(),
+            "any (test , feature = \"integration\")"
+        );
+
+        let mut attrs = vec![parse_quote! { #[naming(cfg = 1 + 2)] }];
+        assert!(Naming::take(&mut attrs).is_err());
+    }
+
+    #[test]
+    fn rejects_nested_flat_cases() {
+        let mut attrs = vec![parse_quote! { #[naming(nested = true, flat = true)] }];
+        assert!(Naming::take(&mut attrs).is_err());
+
+        let mut attrs = vec![parse_quote! { #[naming(groups = true, flat = true)] }];
+        assert!(Naming::take(&mut attrs).is_err());
+    }
+
+    #[test]
+    fn prefixes_keywords() {
+        assert_eq!(Naming::default().escape("match".to_string()), "r#match");
+
+        let mut attrs = vec![parse_quote! { #[naming(keyword_prefix = "kw_")] }];
+        let naming = Naming::take(&mut attrs).unwrap();
+        assert_eq!(naming.escape("match".to_string()), "kw_match");
+        assert_eq!(naming.escape("self".to_string()), "kw_self");
+        assert_eq!(naming.escape("matches".to_string()), "matches");
+
+        let mut attrs = vec![parse_quote! { #[naming(keyword_prefix = "1")] }];
+        assert!(Naming::take(&mut attrs).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_option() {
+        let mut attrs = vec![parse_quote! { #[naming(title = "x")] }];
+        assert!(Naming::take(&mut attrs).is_err());
+    }
+}
@@ -0,0 +1,346 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+use syn::visit::{self, Visit};
+use syn::{
+    Block, ExprPath, ExprStruct, Ident, ItemEnum, ItemStruct, ItemTrait, ItemType, ItemUnion,
+    ItemUse, PatStruct, PatTupleStruct, Path, Signature, TraitBound, TypeParam, TypePath, UseTree,
+};
+
+/// Crates available to a function compiled as a crate of its own.
+const STD_CRATES: &[&str] = &["std", "core", "alloc"];
+
+/// Primitive types and names of the `std` prelude.
+const PRELUDE: &[&str] = &[
+    "Self",
+    "bool",
+    "char",
+    "str",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "usize",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "isize",
+    "f32",
+    "f64",
+    "AsMut",
+    "AsRef",
+    "Box",
+    "Clone",
+    "Copy",
+    "Default",
+    "DoubleEndedIterator",
+    "Drop",
+    "Eq",
+    "Err",
+    "ExactSizeIterator",
+    "Extend",
+    "Fn",
+    "FnMut",
+    "FnOnce",
+    "From",
+    "FromIterator",
+    "Into",
+    "IntoIterator",
+    "Iterator",
+    "None",
+    "Ok",
+    "Option",
+    "Ord",
+    "PartialEq",
+    "PartialOrd",
+    "Result",
+    "Send",
+    "Sized",
+    "Some",
+    "String",
+    "Sync",
+    "ToOwned",
+    "ToString",
+    "TryFrom",
+    "TryInto",
+    "Unpin",
+    "Vec",
+];
+
+/// Edition cargo uses for crates which don't declare one.
+const DEFAULT_EDITION: &str = "2015";
+
+/// Edition of the crate being compiled, which `compile_fail` cases are compiled with. It is read
+/// from `Cargo.toml` of the crate, or of its workspace when the crate inherits it.
+pub fn crate_edition() -> String {
+    let dir = match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => return DEFAULT_EDITION.to_string(),
+    };
+    let manifest = std::fs::read_to_string(dir.join("Cargo.toml")).unwrap_or_default();
+    let edition = match manifest_entry(&manifest, "package", "edition") {
+        Some(entry) if entry.contains("workspace") => dir.ancestors().find_map(|dir| {
+            let manifest = std::fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+            manifest_entry(&manifest, "workspace.package", "edition").and_then(string_value)
+        }),
+        Some(entry) => string_value(entry),
+        None => None,
+    };
+    edition.unwrap_or_else(|| DEFAULT_EDITION.to_string())
+}
+
+/// Rest of the line declaring `key` in `[table]` of a manifest, e.g. `= "2021"` or
+/// `.workspace = true`.
+fn manifest_entry<'a>(manifest: &'a str, table: &str, key: &str) -> Option<&'a str> {
+    let mut current = "";
+    for line in manifest.lines().map(str::trim) {
+        if let Some(header) = line.strip_prefix('[') {
+            current = header.trim_end_matches(']').trim();
+        } else if current == table {
+            let rest = match line.strip_prefix(key) {
+                Some(rest) => rest.trim_start(),
+                None => continue,
+            };
+            if rest.starts_with('=') || rest.starts_with('.') {
+                return Some(rest);
+            }
+        }
+    }
+    None
+}
+
+/// String assigned by manifest entry, e.g. `2021` of `= "2021"`.
+fn string_value(entry: &str) -> Option<String> {
+    let value = entry.strip_prefix('=')?.trim();
+    let value = value.strip_prefix('"')?;
+    value.find('"').map(|end| value[..end].to_string())
+}
+
+/// First path to a type or trait used by function with `sig` and `block`, which is neither in
+/// `std` nor declared within the function, e.g. a type of the crate under test. Such function
+/// doesn't compile standalone, as `compile_fail` cases require.
+pub fn non_std_path(sig: &Signature, block: &Block) -> Option<Path> {
+    let mut declared = Declared::default();
+    declared.visit_signature(sig);
+    declared.visit_block(block);
+    if declared.std_glob {
+        return None;
+    }
+
+    let mut uses = Uses {
+        declared: declared.names,
+        found: None,
+    };
+    uses.visit_signature(sig);
+    uses.visit_block(block);
+    uses.found
+}
+
+/// Names of generic parameters, items declared and `std` items imported within the function.
+#[derive(Default)]
+struct Declared {
+    names: HashSet<Ident>,
+    /// Whether all items of a `std` module are imported, e.g. `use std::io::*;`
+    std_glob: bool,
+}
+
+impl Declared {
+    /// Imports `tree` of module `parent`.
+    fn import(&mut self, parent: &Ident, tree: &UseTree) {
+        match tree {
+            UseTree::Path(path) => self.import(&path.ident, &path.tree),
+            UseTree::Name(name) if name.ident == "self" => {
+                self.names.insert(parent.clone());
+            }
+            UseTree::Name(name) => {
+                self.names.insert(name.ident.clone());
+            }
+            UseTree::Rename(rename) => {
+                self.names.insert(rename.rename.clone());
+            }
+            UseTree::Glob(_) => self.std_glob = true,
+            UseTree::Group(group) => group
+                .items
+                .iter()
+                .for_each(|tree| self.import(parent, tree)),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Declared {
+    fn visit_type_param(&mut self, param: &'ast TypeParam) {
+        self.names.insert(param.ident.clone());
+        visit::visit_type_param(self, param);
+    }
+
+    fn visit_item_struct(&mut self, item: &'ast ItemStruct) {
+        self.names.insert(item.ident.clone());
+        visit::visit_item_struct(self, item);
+    }
+
+    fn visit_item_enum(&mut self, item: &'ast ItemEnum) {
+        self.names.insert(item.ident.clone());
+        visit::visit_item_enum(self, item);
+    }
+
+    fn visit_item_union(&mut self, item: &'ast ItemUnion) {
+        self.names.insert(item.ident.clone());
+        visit::visit_item_union(self, item);
+    }
+
+    fn visit_item_trait(&mut self, item: &'ast ItemTrait) {
+        self.names.insert(item.ident.clone());
+        visit::visit_item_trait(self, item);
+    }
+
+    fn visit_item_type(&mut self, item: &'ast ItemType) {
+        self.names.insert(item.ident.clone());
+        visit::visit_item_type(self, item);
+    }
+
+    fn visit_item_use(&mut self, item: &'ast ItemUse) {
+        // Items imported from other crates are reported where they are used
+        if let UseTree::Path(path) = &item.tree {
+            if STD_CRATES.iter().any(|name| path.ident == name) {
+                self.import(&path.ident, &path.tree);
+            }
+        }
+    }
+}
+
+/// Finds the first path to an item which is not available standalone.
+struct Uses {
+    declared: HashSet<Ident>,
+    found: Option<Path>,
+}
+
+impl Uses {
+    fn check(&mut self, path: &Path) {
+        if self.found.is_some() {
+            return;
+        }
+        let first = match path.segments.first() {
+            Some(segment) => &segment.ident,
+            None => return,
+        };
+        let is_std = if path.leading_colon.is_some() {
+            STD_CRATES.iter().any(|name| first == name)
+        } else {
+            STD_CRATES.iter().chain(PRELUDE).any(|name| first == name)
+                || self.declared.contains(first)
+        };
+        if !is_std {
+            self.found = Some(path.clone());
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Uses {
+    fn visit_type_path(&mut self, ty: &'ast TypePath) {
+        if ty.qself.is_none() {
+            self.check(&ty.path);
+        }
+        visit::visit_type_path(self, ty);
+    }
+
+    fn visit_trait_bound(&mut self, bound: &'ast TraitBound) {
+        self.check(&bound.path);
+        visit::visit_trait_bound(self, bound);
+    }
+
+    fn visit_expr_struct(&mut self, expr: &'ast ExprStruct) {
+        self.check(&expr.path);
+        visit::visit_expr_struct(self, expr);
+    }
+
+    /// Only paths through a type or module, e.g. `Local::new`, single names are values
+    fn visit_expr_path(&mut self, expr: &'ast ExprPath) {
+        if expr.qself.is_none() && expr.path.segments.len() > 1 {
+            self.check(&expr.path);
+        }
+        visit::visit_expr_path(self, expr);
+    }
+
+    fn visit_pat_struct(&mut self, pat: &'ast PatStruct) {
+        self.check(&pat.path);
+        visit::visit_pat_struct(self, pat);
+    }
+
+    fn visit_pat_tuple_struct(&mut self, pat: &'ast PatTupleStruct) {
+        self.check(&pat.path);
+        visit::visit_pat_tuple_struct(self, pat);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{manifest_entry, non_std_path, string_value};
+    use syn::{parse_quote, ItemFn};
+
+    fn non_std(item: ItemFn) -> Option<String> {
+        non_std_path(&item.sig, &item.block).map(|path| quote::quote!(#path).to_string())
+    }
+
+    #[test]
+    fn accepts_std_items() {
+        assert_eq!(
+            non_std(parse_quote! {
+                fn copy<T: Copy + std::fmt::Debug>(value: T) -> Option<T> {
+                    use std::collections::{self, HashMap};
+                    struct Local(u8);
+                    let _: HashMap<u8, Vec<T>> = collections::HashMap::new();
+                    let _ = Local(u8::MAX);
+                    let _: Box<dyn Fn(String) -> ::core::cmp::Ordering> = Box::new(|_| todo!());
+                    Some(value)
+                }
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn reports_items_of_other_crates() {
+        assert_eq!(
+            non_std(parse_quote! { fn local(_: Vec<Local>) {} }),
+            Some("Local".to_string())
+        );
+        assert_eq!(
+            non_std(parse_quote! { fn bound<T: crate::Trait>(_: T) {} }),
+            Some("crate :: Trait".to_string())
+        );
+        assert_eq!(
+            non_std(parse_quote! { fn call() -> u8 { other::answer() } }),
+            Some("other :: answer".to_string())
+        );
+    }
+
+    #[test]
+    fn reads_edition_of_manifest() {
+        let manifest = r#"
+            [package]
+            name          = "crate"
+            edition       = "2018" # comment
+
+            [workspace.package]
+            edition = "2021"
+        "#;
+        assert_eq!(
+            manifest_entry(manifest, "package", "edition").and_then(string_value),
+            Some("2018".to_string())
+        );
+        assert_eq!(
+            manifest_entry(manifest, "workspace.package", "edition").and_then(string_value),
+            Some("2021".to_string())
+        );
+        assert_eq!(
+            manifest_entry("[package]\nedition.workspace = true", "package", "edition"),
+            Some(".workspace = true")
+        );
+        assert_eq!(
+            manifest_entry("[dependencies]\nedition = \"1\"", "package", "edition"),
+            None
+        );
+    }
+}
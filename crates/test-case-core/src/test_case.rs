@@ -1,21 +1,41 @@
 use crate::comment::TestCaseComment;
-use crate::expr::{TestCaseExpression, TestCaseResult};
-use crate::utils::fmt_syn;
+use crate::expr::{not_panicking_assertion, oracle_assertion, TestCaseExpression, TestCaseResult};
+use crate::naming::Naming;
+use crate::standalone::{crate_edition, non_std_path};
+use crate::utils::{any_ident, escape_test_name, fmt_syn, interpolate, parse_date};
 use proc_macro2::{Span as Span2, TokenStream as TokenStream2};
-use quote::quote;
+use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{parse_quote, Error, Expr, Ident, ItemFn, ReturnType, Token};
+use syn::{
+    parse_quote, Attribute, Error, Expr, ExprLit, FnArg, GenericParam, Ident, ItemFn, Lit, LitStr,
+    Meta, Pat, PatType, ReturnType, Signature, Token, Visibility,
+};
 
 #[derive(Debug)]
 pub struct TestCase {
     args: Punctuated<Expr, Token![,]>,
     expression: Option<TestCaseExpression>,
     name: Ident,
+    /// Description the name comes from, instead of arguments.
+    description: Option<String>,
+    /// Whether description of `#[test_matrix]` prefixes the name generated from arguments.
+    prefixed: bool,
+    /// Whether case is generated next to the function rather than in a module.
+    flat: bool,
+    /// Modules nested in the module of the function the case is generated in.
+    modules: Vec<Ident>,
+    /// Visibility of the generated function.
+    vis: Option<Visibility>,
+    /// Arguments of `#[test_case(...)]` the case is generated from.
+    source: TokenStream2,
+    /// Data file the case is read from.
+    data_file: Option<String>,
 }
 
 impl Parse for TestCase {
     fn parse(input: ParseStream) -> Result<Self, Error> {
+        let source = input.cursor().token_stream();
         let args = Punctuated::parse_separated_nonempty_with(input, Expr::parse)?;
         let expression = (!input.is_empty()).then(|| input.parse()).transpose();
         let comment = (!input.is_empty()).then(|| input.parse()).transpose();
@@ -27,7 +47,7 @@ impl Parse for TestCase {
             (expression, comment) => (expression.ok().flatten(), comment.ok().flatten()),
         };
 
-        Ok(Self::new_from_parsed(args, expression, comment))
+        Ok(Self::new_from_parsed(args, expression, comment).with_source(source))
     }
 }
 impl TestCase {
@@ -45,29 +65,157 @@ impl TestCase {
         comment: Option<TestCaseComment>,
     ) -> Self {
         let name = Self::test_case_name_ident(args.iter(), expression.as_ref(), comment.as_ref());
+        let source = args.to_token_stream();
 
         Self {
             args,
             expression,
             name,
+            description: comment.map(|comment| comment.comment.value()),
+            prefixed: false,
+            flat: false,
+            modules: vec![],
+            vis: None,
+            source,
+            data_file: None,
         }
     }
 
+    /// Marks case as read from data file at `full_path`, so it is regenerated when file changes.
+    pub(crate) fn with_data_file(mut self, full_path: &str) -> Self {
+        self.data_file = Some(full_path.to_string());
+        self
+    }
+
+    /// Replaces arguments of `#[test_case(...)]` the case is documented as generated from.
+    pub(crate) fn with_source(mut self, source: TokenStream2) -> Self {
+        self.source = source;
+        self
+    }
+
     pub(crate) fn new_with_prefixed_name<I: IntoIterator<Item = Expr>>(
         args: I,
         expression: Option<TestCaseExpression>,
         prefix: &str,
     ) -> Self {
         let parsed_args = args.into_iter().collect::<Punctuated<Expr, Token![,]>>();
-        let name = Self::prefixed_test_case_name(parsed_args.iter(), expression.as_ref(), prefix);
+        let full_desc =
+            Self::prefixed_test_case_name(parsed_args.iter(), expression.as_ref(), prefix);
+        let name = escape_test_name(Self::ordered_name(full_desc, expression.as_ref()));
+        let source = parsed_args.to_token_stream();
 
         Self {
             args: parsed_args,
             expression,
             name,
+            description: Some(prefix.to_string()),
+            prefixed: true,
+            flat: false,
+            modules: vec![],
+            vis: None,
+            source,
+            data_file: None,
         }
     }
 
+    /// Renames case according to `naming` options of the function, template applies only to
+    /// cases without description. Description can use the same placeholders as template, matrix
+    /// description with placeholders replaces the name generated from arguments instead of
+    /// prefixing it.
+    pub fn apply_naming(&mut self, naming: &Naming, sig: &Signature, index: usize, count: usize) {
+        let desc = match (self.description.as_ref(), naming.template.as_ref()) {
+            (Some(description), _) => {
+                let interpolated = self.interpolate_name(description, sig, index, count);
+                if self.prefixed && interpolated == *description {
+                    Self::prefixed_test_case_name(
+                        self.args.iter(),
+                        self.expression.as_ref(),
+                        description,
+                    )
+                } else {
+                    interpolated
+                }
+            }
+            (None, Some(template)) => self.interpolate_name(&template.value(), sig, index, count),
+            (None, None) if naming.short_expected => self.short_name_string(),
+            (None, None) => {
+                Self::test_case_name_string(self.args.iter(), self.expression.as_ref(), None)
+            }
+        };
+        let mut groups: Vec<_> = if naming.groups {
+            desc.split("::")
+                .map(|part| part.trim().to_string())
+                .collect()
+        } else {
+            vec![desc]
+        };
+        let desc = groups.pop().unwrap_or_default();
+        let desc = Self::ordered_name(desc, self.expression.as_ref());
+        self.flat = naming.flat;
+        self.vis = naming.vis.clone();
+        self.name = if self.flat {
+            naming.escape(format!("{}_{desc}", sig.ident))
+        } else {
+            naming.escape(desc)
+        };
+        self.modules = groups
+            .into_iter()
+            .map(|group| naming.escape(group))
+            .collect();
+        if naming.nested {
+            let values = self.argument_values(&self.case_signature(sig));
+            self.modules.extend(
+                values
+                    .iter()
+                    .take(values.len().saturating_sub(1))
+                    .enumerate()
+                    .map(|(idx, (name, value))| match name {
+                        Some(name) => naming.escape(format!("{name} {value}")),
+                        None => naming.escape(format!("arg{idx} {value}")),
+                    }),
+            );
+        }
+    }
+
+    /// Modules nested in the module of the function the case is generated in, outermost first.
+    pub fn modules(&self) -> &[Ident] {
+        &self.modules
+    }
+
+    /// Template placeholders are `{fn}`, `{argN}`, `{expected}`, `{case}` and the ones of ignore
+    /// reasons. `{case}` is the zero-padded, 1-based `index` of the case among `count` cases.
+    fn interpolate_name(
+        &self,
+        template: &str,
+        sig: &Signature,
+        index: usize,
+        count: usize,
+    ) -> String {
+        let mut placeholders = self.placeholders(&self.case_signature(sig));
+        let indexed: Vec<_> = placeholders
+            .iter()
+            .filter(|(name, _)| name.parse::<usize>().is_ok())
+            .map(|(idx, value)| (format!("arg{idx}"), value.clone()))
+            .collect();
+        placeholders.extend(indexed);
+        placeholders.push(("fn".to_string(), sig.ident.to_string()));
+        if let Some(expr) = self.expression.as_ref() {
+            placeholders.push(("expected".to_string(), expr.result.to_string()));
+        }
+        let width = count.to_string().len().max(2);
+        placeholders.push(("case".to_string(), format!("{:0width$}", index + 1)));
+
+        interpolate(template, &placeholders)
+    }
+
+    /// Whether the case is compiled only under some `cfg`, so its name may repeat in other cases.
+    pub fn is_conditional(&self) -> bool {
+        self.expression
+            .as_ref()
+            .map(|expr| expr.is_conditional())
+            .unwrap_or_default()
+    }
+
     pub fn test_case_name(&self) -> Ident {
         // The clone is kind of annoying here, but because this is behind a reference, we must clone
         // to preserve the signature without a breaking change
@@ -77,45 +225,230 @@ impl TestCase {
 
     pub fn render(&self, mut item: ItemFn, origin_span: Span2) -> TokenStream2 {
         let item_name = item.sig.ident.clone();
-        let arg_values = self.args.iter();
+        let callee = if self.flat {
+            quote! { #item_name }
+        } else {
+            let supers = self.modules.iter().map(|_| quote! { super:: });
+            quote! { super:: #(#supers)* #item_name }
+        };
         let test_case_name = {
             let mut test_case_name = self.test_case_name();
             test_case_name.set_span(origin_span);
             test_case_name
         };
 
+        let runner = match take_runner(&mut item.attrs) {
+            Ok(runner) => runner,
+            Err(err) => return err.into_compile_error(),
+        };
+        let block_on = match runner {
+            Some(Runner::BlockOn(ref block_on)) => Some(block_on.clone()),
+            _ => None,
+        };
+        let is_async = item.sig.asyncness.is_some() && block_on.is_none();
+        let setup = self.expression.as_ref().and_then(|expr| expr.async_setup());
+        let case_sig = self.case_signature(&item.sig);
+        let placeholders = self.placeholders(&case_sig);
         let mut attrs = self
             .expression
             .as_ref()
-            .map(|expr| expr.attributes())
+            .map(|expr| expr.attributes(&placeholders))
             .unwrap_or_default();
 
+        let doc = format!(" `#[test_case({})]`", self.source);
+        attrs.push(parse_quote! { #[doc = #doc] });
         attrs.push(parse_quote! { #[allow(clippy::bool_assert_comparison)] });
         attrs.append(&mut item.attrs);
 
-        let (mut signature, body) = if item.sig.asyncness.is_some() {
+        let catches_unwind = self
+            .expression
+            .as_ref()
+            .map(|expr| expr.catches_unwind())
+            .unwrap_or_default();
+        let runs_in_subprocess = self
+            .expression
+            .as_ref()
+            .map(|expr| expr.runs_in_subprocess())
+            .unwrap_or_default();
+        let expects_compile_error = self
+            .expression
+            .as_ref()
+            .map(|expr| expr.expects_compile_error())
+            .unwrap_or_default();
+        let sync_only_modifier = self
+            .expression
+            .as_ref()
+            .and_then(|expr| expr.sync_only_modifier());
+        let async_only_modifier = self
+            .expression
+            .as_ref()
+            .and_then(|expr| expr.async_only_modifier());
+
+        let runtime_args = self
+            .expression
+            .as_ref()
+            .and_then(|expr| expr.runtime_args());
+        if runtime_args.is_some() && (runner.is_some() || has_test_attribute(&attrs)) {
+            return Error::new(
+                origin_span,
+                "`rt` configures generated `#[tokio::test]`, it can't be used with explicit test attribute or `runner`",
+            )
+            .into_compile_error();
+        }
+
+        let runners = match runner {
+            Some(Runner::Attribute(runner)) => vec![runner],
+            Some(Runner::BlockOn(_)) | None => default_runners(is_async, &attrs, runtime_args),
+        };
+        attrs.splice(0..0, runners);
+
+        let assertion = self.expression.as_ref().map(|expr| match expr.result {
+            TestCaseResult::Panicking(_) => TokenStream2::new(),
+            TestCaseResult::NotPanicking => {
+                not_panicking_assertion(&format!("case `{}`", self.test_case_name()))
+            }
+            TestCaseResult::Oracle(ref oracle) => oracle_assertion(oracle, self.args.iter()),
+            _ => expr.assertion(),
+        });
+        let (evaluated, arg_values, bindings) = match (self.expression.as_ref(), &assertion) {
+            (Some(expr), Some(assertion)) if expr.binds_arguments() => {
+                self.argument_bindings(&case_sig, assertion)
+            }
+            _ => (
+                TokenStream2::new(),
+                self.args.iter().map(ToTokens::to_token_stream).collect(),
+                TokenStream2::new(),
+            ),
+        };
+        let arg_values = &arg_values;
+
+        let (mut signature, body) = if is_async {
+            if catches_unwind {
+                return Error::new(
+                    origin_span,
+                    "matching panic message is not supported for async functions",
+                )
+                .into_compile_error();
+            }
+            if runs_in_subprocess {
+                return Error::new(
+                    origin_span,
+                    "`exits` and `aborts` are not supported for async functions",
+                )
+                .into_compile_error();
+            }
+            if expects_compile_error {
+                return Error::new(
+                    origin_span,
+                    "`compile_fail` is not supported for async functions",
+                )
+                .into_compile_error();
+            }
+            if let Some(keyword) = sync_only_modifier {
+                return Error::new(
+                    origin_span,
+                    format!("`{keyword}` is not supported for async functions"),
+                )
+                .into_compile_error();
+            }
             (
                 quote! { async },
-                quote! { let _result = super::#item_name(#(#arg_values),*).await; },
+                match setup {
+                    Some(setup) => quote! {
+                        let _result = #callee(#setup().await, #(#arg_values),*).await;
+                    },
+                    None => quote! { let _result = #callee(#(#arg_values),*).await; },
+                },
             )
         } else {
-            attrs.insert(0, parse_quote! { #[::core::prelude::v1::test] });
-            (
-                TokenStream2::new(),
-                quote! { let _result = super::#item_name(#(#arg_values),*); },
-            )
+            if self
+                .expression
+                .as_ref()
+                .map(|expr| expr.awaits())
+                .unwrap_or_default()
+            {
+                let message = if block_on.is_some() {
+                    "`.await` in expected value is not supported with `block_on` runner"
+                } else {
+                    "`.await` in expected value requires an async test function"
+                };
+                return Error::new(origin_span, message).into_compile_error();
+            }
+            if let Some(keyword) = async_only_modifier {
+                return Error::new(
+                    origin_span,
+                    format!("`{keyword}` is only supported for async functions"),
+                )
+                .into_compile_error();
+            }
+            let call = match block_on {
+                Some(ref block_on) => quote! {
+                    #block_on(async { #callee(#(#arg_values),*).await })
+                },
+                None => quote! { #callee(#(#arg_values),*) },
+            };
+            let body = if expects_compile_error {
+                let sig = &item.sig;
+                let block = &item.block;
+                if let Some(path) = non_std_path(sig, block) {
+                    return Error::new_spanned(
+                        &path,
+                        format!(
+                            "`compile_fail` compiles the function standalone, using only `std`, \
+                             so it cannot use `{}`",
+                            quote! { #path }.to_string().replace(' ', "")
+                        ),
+                    )
+                    .into_compile_error();
+                }
+                let function = quote! { #sig #block }.to_string();
+                let args = self.args.iter();
+                let call = quote! { #item_name(#(#args),*) }.to_string();
+                let edition = crate_edition();
+                quote! {
+                    let _result = ::test_case::__private::compile_fail(#function, #call, #edition);
+                }
+            } else if runs_in_subprocess {
+                quote! {
+                    let _tc_name = concat!(module_path!(), "::", stringify!(#test_case_name));
+                    let _tc_name = _tc_name.split_once("::").map_or(_tc_name, |(_, name)| name);
+                    if ::std::env::var("__TEST_CASE_SUBPROCESS").as_deref() == Ok(_tc_name) {
+                        let _ = #call;
+                        return;
+                    }
+                    let _result = ::std::process::Command::new(
+                        ::std::env::current_exe().expect("current test executable"),
+                    )
+                    .args([_tc_name, "--exact", "--nocapture", "--test-threads=1"])
+                    .env("__TEST_CASE_SUBPROCESS", _tc_name)
+                    .output()
+                    .expect("failed to spawn test subprocess");
+                }
+            } else if catches_unwind {
+                quote! {
+                    let _result = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+                        #call
+                    }));
+                }
+            } else {
+                quote! { let _result = #call; }
+            };
+            (TokenStream2::new(), body)
         };
+        let body = quote! { #evaluated #body };
 
         let expected = if let Some(expr) = self.expression.as_ref() {
-            attrs.extend(expr.attributes());
+            if expr.expects_failure() && matches!(expr.result, TestCaseResult::Panicking(_)) {
+                return Error::new(origin_span, "`xfail` can't be combined with `panics`")
+                    .into_compile_error();
+            }
 
-            signature.extend(quote! { fn #test_case_name() });
+            signature.extend(match expr.return_type() {
+                Some(typ) => quote! { fn #test_case_name() -> #typ },
+                None => quote! { fn #test_case_name() },
+            });
 
-            if let TestCaseResult::Panicking(_) = expr.result {
-                TokenStream2::new()
-            } else {
-                expr.assertion()
-            }
+            quote! { #bindings #assertion }
         } else {
             signature.extend(if let ReturnType::Type(_, typ) = item.sig.output {
                 quote! { fn #test_case_name() -> #typ }
@@ -126,15 +459,218 @@ impl TestCase {
             quote! { _result }
         };
 
+        let block = quote! {
+            #body
+            #expected
+        };
+        let block = match self.expression.as_ref() {
+            Some(expr) => {
+                let block = expr.wrap_body(block, is_async);
+                let block = match expr.slow_warning_threshold() {
+                    Some(threshold) => quote! {
+                        let _tc_slow_warning = ::test_case::__private::SlowWarning::new(
+                            concat!(module_path!(), "::", stringify!(#test_case_name)),
+                            #threshold,
+                        );
+                        #block
+                    },
+                    None => block,
+                };
+                match expr.inconclusive_reason() {
+                    Some(reason) => {
+                        let reason = interpolate(&reason, &placeholders);
+                        let args = self.args.iter().map(fmt_syn).collect::<Vec<_>>().join(", ");
+                        quote! {
+                            ::test_case::__private::report_inconclusive(
+                                concat!(module_path!(), "::", stringify!(#test_case_name)),
+                                #reason,
+                                #args,
+                            );
+                            #block
+                        }
+                    }
+                    None => block,
+                }
+            }
+            None => block,
+        };
+
+        let vis = &self.vis;
+        let data_file = self.data_file.as_deref().map(crate::data::track);
+        let ignore_until = self
+            .expression
+            .as_ref()
+            .and_then(|expr| expr.ignored_until())
+            .map(|(date, reason)| {
+                self.ignore_until_companion(&test_case_name, &attrs, date, reason, &placeholders)
+            });
         quote! {
             #(#attrs)*
-            #signature {
-                #body
-                #expected
+            #vis #signature {
+                #data_file
+                #block
             }
+            #ignore_until
         }
     }
 
+    /// Test which fails once the date of `ignore_until` has passed, as the case itself is ignored.
+    /// It runs only where the case is compiled, i.e. keeps its `cfg` attributes.
+    fn ignore_until_companion(
+        &self,
+        test_case_name: &Ident,
+        attrs: &[Attribute],
+        date: &LitStr,
+        reason: Option<&LitStr>,
+        placeholders: &[(String, String)],
+    ) -> TokenStream2 {
+        let (year, month, day) = parse_date(&date.value()).unwrap_or_default();
+        let reason = reason
+            .map(|reason| format!(": {}", interpolate(&reason.value(), placeholders)))
+            .unwrap_or_default();
+        let message = format!(
+            "case was ignored until {}, enable or snooze it again{reason}",
+            date.value()
+        );
+        let name = format_ident!("{}_ignore_until", test_case_name, span = date.span());
+        let cfgs = attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+        let vis = &self.vis;
+        quote! {
+            #(#cfgs)*
+            #[test]
+            #vis fn #name() {
+                ::test_case::__private::ignored_until((#year, #month, #day), #message);
+            }
+        }
+    }
+
+    /// Signature of `sig` without parameters not bound to case arguments.
+    fn case_signature(&self, sig: &Signature) -> Signature {
+        let mut sig = sig.clone();
+        // Value returned by `async_setup` is the first argument, case arguments follow it
+        if self
+            .expression
+            .as_ref()
+            .and_then(|expr| expr.async_setup())
+            .is_some()
+        {
+            sig.inputs = sig.inputs.into_iter().skip(1).collect();
+        }
+        sig
+    }
+
+    /// Values of case arguments for `{name}` (parameter name) and `{index}` placeholders.
+    fn placeholders(&self, sig: &Signature) -> Vec<(String, String)> {
+        self.argument_values(sig)
+            .into_iter()
+            .enumerate()
+            .flat_map(|(idx, (name, value))| {
+                let by_name = name.map(|name| (name, value.clone()));
+                by_name.into_iter().chain(Some((idx.to_string(), value)))
+            })
+            .collect()
+    }
+
+    /// Names of parameters (unless they are patterns) with text of corresponding case arguments.
+    fn argument_values(&self, sig: &Signature) -> Vec<(Option<String>, String)> {
+        let params = sig.inputs.iter().map(|input| match input {
+            FnArg::Typed(PatType { pat, .. }) => match pat.as_ref() {
+                Pat::Ident(pat) => Some(pat.ident.to_string()),
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        });
+
+        params
+            .zip(self.args.iter())
+            .map(|(name, arg)| {
+                let value = match arg {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) => lit.value(),
+                    arg => fmt_syn(arg),
+                };
+                (name, value)
+            })
+            .collect()
+    }
+
+    /// Binds case arguments to names of function parameters mentioned in `assertion`.
+    /// Such arguments are evaluated once, into hidden bindings declared by the first returned
+    /// stream before the call, and the function under test gets their clones, or the values
+    /// themselves if they aren't `Clone`. Returns also the values to call the function with and
+    /// the bindings of parameter names for `assertion`.
+    fn argument_bindings(
+        &self,
+        sig: &Signature,
+        assertion: &TokenStream2,
+    ) -> (TokenStream2, Vec<TokenStream2>, TokenStream2) {
+        let generics: Vec<&Ident> = sig
+            .generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) => Some(&param.ident),
+                GenericParam::Const(param) => Some(&param.ident),
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect();
+        let params = sig.inputs.iter().filter_map(|input| match input {
+            FnArg::Typed(typed) => Some(typed),
+            FnArg::Receiver(_) => None,
+        });
+
+        let mut params = params.map(Some).chain(std::iter::repeat(None));
+        let mut evaluated = TokenStream2::new();
+        let mut bindings = TokenStream2::new();
+        let values = self
+            .args
+            .iter()
+            .enumerate()
+            .map(|(idx, arg)| {
+                let param = match params.next().flatten() {
+                    Some(param)
+                        if any_ident(param.pat.to_token_stream(), &|ident| {
+                            ident != "mut"
+                                && ident != "ref"
+                                && any_ident(assertion.clone(), &|used| used == ident)
+                        }) =>
+                    {
+                        param
+                    }
+                    _ => return arg.to_token_stream(),
+                };
+                let hidden = format_ident!("_tc_arg{}", idx);
+                let pat = &param.pat;
+                let ty = &param.ty;
+                // Types mentioning generics, `impl Trait` or lifetimes can't be named in the test
+                let nameable = !any_ident(ty.to_token_stream(), &|ident| {
+                    ident == "impl" || generics.contains(&ident)
+                }) && !ty.to_token_stream().to_string().contains('\'');
+                evaluated.extend(if nameable {
+                    quote! { let #hidden: #ty = #arg; }
+                } else {
+                    quote! { let #hidden = #arg; }
+                });
+                evaluated.extend(quote! {
+                    let mut #hidden = ::test_case::__private::Argument(Some(#hidden));
+                });
+                bindings.extend(quote! {
+                    #[allow(unused_mut, unused_variables)]
+                    let #pat = (&mut #hidden).bound();
+                });
+                quote! { (&mut #hidden).pass() }
+            })
+            .collect();
+        if !evaluated.is_empty() {
+            evaluated = quote! {
+                use ::test_case::__private::{ArgumentClone as _, ArgumentMove as _};
+                #evaluated
+            };
+        }
+        (evaluated, values, bindings)
+    }
+
     fn test_case_name_ident<'a, I: Iterator<Item = &'a Expr>>(
         args: I,
         expression: Option<&TestCaseExpression>,
@@ -142,18 +678,38 @@ impl TestCase {
     ) -> Ident {
         let desc = Self::test_case_name_string(args, expression, comment);
 
-        crate::utils::escape_test_name(desc)
+        escape_test_name(Self::ordered_name(desc, expression))
     }
 
     fn prefixed_test_case_name<'a, I: Iterator<Item = &'a Expr>>(
         args: I,
         expression: Option<&TestCaseExpression>,
         prefix: &str,
-    ) -> Ident {
+    ) -> String {
         let generated_name = Self::test_case_name_string(args, expression, None);
-        let full_desc = format!("{prefix}_{generated_name}");
+        format!("{prefix}_{generated_name}")
+    }
+
+    /// Prefixes name of case with its `order`, libtest runs tests in alphabetical order.
+    fn ordered_name(desc: String, expression: Option<&TestCaseExpression>) -> String {
+        match expression.and_then(TestCaseExpression::order) {
+            Some(order) => format!("{order:04} {desc}"),
+            None => desc,
+        }
+    }
 
-        crate::utils::escape_test_name(full_desc)
+    /// Arguments followed by expectation phrased briefly, e.g. `2 3 returns 5`.
+    fn short_name_string(&self) -> String {
+        self.args
+            .iter()
+            .map(fmt_syn)
+            .chain(
+                self.expression
+                    .as_ref()
+                    .map(|expr| expr.short_description()),
+            )
+            .collect::<Vec<_>>()
+            .join(" ")
     }
 
     fn test_case_name_string<'a, I: Iterator<Item = &'a Expr>>(
@@ -179,3 +735,97 @@ impl TestCase {
             })
     }
 }
+
+/// How generated cases are run, declared with `#[test_case::runner(...)]`.
+enum Runner {
+    /// Test attribute put on cases instead of the default one, e.g. `tokio::test`.
+    Attribute(Attribute),
+    /// `block_on = path::to::block_on`: async body is driven by given function from a sync `#[test]`.
+    BlockOn(Expr),
+}
+
+/// Removes `#[test_case::runner(..)]` from `attrs`, returns runner declared by it.
+fn take_runner(attrs: &mut Vec<Attribute>) -> syn::Result<Option<Runner>> {
+    let is_runner = |attr: &Attribute| {
+        let path = attr.path();
+        path.is_ident("runner")
+            || (path.segments.len() == 2
+                && path.segments[0].ident == "test_case"
+                && path.segments[1].ident == "runner")
+    };
+    let mut runner = None;
+    for attr in attrs.iter().filter(|attr| is_runner(attr)) {
+        if runner.is_some() {
+            return Err(Error::new_spanned(
+                attr,
+                "only one `runner` can be declared",
+            ));
+        }
+        let meta: Meta = attr.parse_args()?;
+        runner = Some(match meta {
+            Meta::NameValue(meta) if meta.path.is_ident("block_on") => Runner::BlockOn(meta.value),
+            meta => Runner::Attribute(parse_quote! { #[#meta] }),
+        });
+    }
+    attrs.retain(|attr| !is_runner(attr));
+    Ok(runner)
+}
+
+/// Test attributes of generated case when no `runner` was declared.
+/// `runtime_args` are put into generated `#[tokio::test(...)]`.
+#[cfg_attr(not(feature = "with-tokio"), allow(unused_variables))]
+fn default_runners(
+    is_async: bool,
+    attrs: &[Attribute],
+    runtime_args: Option<TokenStream2>,
+) -> Vec<Attribute> {
+    let native: Option<Attribute> = if !is_async {
+        Some(parse_quote! { #[::core::prelude::v1::test] })
+    } else if has_test_attribute(attrs) {
+        return vec![];
+    } else {
+        // `with-tokio` takes precedence when both features are enabled
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "with-tokio")] {
+                let runtime_args = runtime_args.map(|args| quote! { , #args });
+                Some(parse_quote! {
+                    #[::test_case::tokio::test(crate = "::test_case::tokio" #runtime_args)]
+                })
+            } else if #[cfg(feature = "with-async-std")] {
+                Some(parse_quote! { #[::test_case::async_std::test] })
+            } else {
+                None
+            }
+        }
+    };
+
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "with-wasm-bindgen-test")] {
+            let wasm = parse_quote! {
+                #[cfg_attr(
+                    target_arch = "wasm32",
+                    ::test_case::wasm_bindgen_test::wasm_bindgen_test(
+                        crate = ::test_case::wasm_bindgen_test
+                    )
+                )]
+            };
+            let native = native.map(|attr| {
+                let meta = attr.meta;
+                parse_quote! { #[cfg_attr(not(target_arch = "wasm32"), #meta)] }
+            });
+            std::iter::once(wasm).chain(native).collect()
+        } else {
+            native.into_iter().collect()
+        }
+    }
+}
+
+/// Whether test harness attribute, e.g. `#[tokio::test]`, was already put on the function.
+fn has_test_attribute(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        matches!(
+            attr.path().segments.last(),
+            Some(segment) if segment.ident == "test" || segment.ident == "wasm_bindgen_test"
+        )
+    })
+}
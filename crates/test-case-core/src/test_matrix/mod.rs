@@ -1,6 +1,7 @@
 use std::{iter, mem};
 
-use proc_macro2::{Literal, Span};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
@@ -8,7 +9,7 @@ use syn::{
     Expr, ExprLit, ExprRange, Lit, RangeLimits, Token,
 };
 
-use crate::{comment::TestCaseComment, expr::TestCaseExpression, TestCase};
+use crate::{comment::TestCaseComment, expr::TestCaseExpression, utils::tokens_between, TestCase};
 
 mod matrix_product;
 
@@ -17,6 +18,8 @@ pub struct TestMatrix {
     variables: Vec<Vec<Expr>>,
     expression: Option<TestCaseExpression>,
     comment: Option<TestCaseComment>,
+    /// Tokens of the expected result, following the arguments.
+    expression_source: TokenStream,
 }
 
 impl TestMatrix {
@@ -27,8 +30,11 @@ impl TestMatrix {
     pub fn cases(&self) -> impl Iterator<Item = TestCase> {
         let expression = self.expression.clone();
         let comment = self.comment.clone();
+        let expression_source = self.expression_source.clone();
 
         matrix_product::multi_cartesian_product(self.variables.iter().cloned()).map(move |v| {
+            let args = v.iter();
+            let source = quote! { #(#args),* #expression_source #comment };
             if let Some(comment) = comment.clone() {
                 TestCase::new_with_prefixed_name(
                     v,
@@ -38,6 +44,7 @@ impl TestMatrix {
             } else {
                 TestCase::new(v, expression.clone(), None)
             }
+            .with_source(source)
         })
     }
 }
@@ -46,7 +53,9 @@ impl Parse for TestMatrix {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let args: Punctuated<Expr, Token![,]> = Punctuated::parse_separated_nonempty(input)?;
 
+        let begin = input.cursor();
         let expression = (!input.is_empty()).then(|| input.parse()).transpose();
+        let expression_source = tokens_between(begin, input.cursor());
         let comment = (!input.is_empty()).then(|| input.parse()).transpose();
         // if both are errors, pick the expression error since it is more likely to be informative.
         //
@@ -59,6 +68,7 @@ impl Parse for TestMatrix {
         let mut matrix = TestMatrix {
             expression,
             comment,
+            expression_source,
             ..Default::default()
         };
 
@@ -1,20 +1,30 @@
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::ToTokens;
+use syn::buffer::Cursor;
 
 pub fn escape_test_name(input: impl AsRef<str>) -> Ident {
+    escape_test_name_preserving_case(input.as_ref().to_ascii_lowercase())
+}
+
+/// Like [`escape_test_name`], but keeps uppercase letters.
+pub fn escape_test_name_preserving_case(input: impl AsRef<str>) -> Ident {
+    keyword_safe_ident(&sanitize_test_name(input))
+}
+
+/// Replaces characters not allowed in identifiers with single `_`, which may result in a keyword.
+pub fn sanitize_test_name(input: impl AsRef<str>) -> String {
     if input.as_ref().is_empty() {
-        return Ident::new("_empty", Span::call_site());
+        return "_empty".to_string();
     }
 
     let mut last_under = false;
     let mut ident: String = input
         .as_ref()
-        .to_ascii_lowercase()
         .chars()
         .filter_map(|c| match c {
             c if c.is_alphanumeric() => {
                 last_under = false;
-                Some(c.to_ascii_lowercase())
+                Some(c)
             }
             _ if !last_under => {
                 last_under = true;
@@ -28,17 +38,131 @@ pub fn escape_test_name(input: impl AsRef<str>) -> Ident {
         ident = format!("_{ident}");
     }
 
-    Ident::new(&ident, Span::call_site())
+    ident
+}
+
+/// Keywords, including reserved ones, which can't be used as names of functions or modules.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield", "_",
+];
+
+/// Whether `name` is a keyword, so it can't be used as identifier as is.
+pub fn is_keyword(name: &str) -> bool {
+    KEYWORDS.contains(&name)
+}
+
+/// Identifier named `name`, raw one if `name` is a keyword, e.g. `r#match`. Keywords which can't
+/// be raw identifiers, e.g. `self`, are prefixed with `_` instead.
+pub fn keyword_safe_ident(name: &str) -> Ident {
+    match name {
+        "crate" | "self" | "Self" | "super" | "_" => {
+            Ident::new(&format!("_{name}"), Span::call_site())
+        }
+        name if is_keyword(name) => Ident::new_raw(name, Span::call_site()),
+        name => Ident::new(name, Span::call_site()),
+    }
 }
 
 pub fn fmt_syn(syn: &(impl ToTokens + Clone)) -> String {
     syn.clone().into_token_stream().to_string()
 }
 
+/// Tokens parsed while `begin` was advanced to `end`.
+pub fn tokens_between(mut begin: Cursor, end: Cursor) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    while begin != end {
+        match begin.token_tree() {
+            Some((token, next)) => {
+                tokens.extend(Some(token));
+                begin = next;
+            }
+            None => break,
+        }
+    }
+    tokens
+}
+
+/// Whether any identifier in `tokens` (including nested groups) satisfies `predicate`.
+pub fn any_ident(tokens: TokenStream, predicate: &impl Fn(&Ident) -> bool) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => predicate(&ident),
+        TokenTree::Group(group) => any_ident(group.stream(), predicate),
+        _ => false,
+    })
+}
+
+/// Replaces `{name}` placeholders in `text` with corresponding values, unknown ones are kept.
+pub fn interpolate(text: &str, placeholders: &[(String, String)]) -> String {
+    placeholders
+        .iter()
+        .fold(text.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+/// Parses `YYYY-MM-DD` date into `(year, month, day)`.
+pub fn parse_date(date: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = date.splitn(3, '-');
+    let mut next = |len: usize| {
+        parts
+            .next()
+            .filter(|part| part.len() == len && part.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|part| part.parse::<u32>().ok())
+    };
+    let (year, month, day) = (next(4)?, next(2)?, next(2)?);
+    ((1..=12).contains(&month) && (1..=31).contains(&day)).then_some((year, month, day))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parses_dates() {
+        assert_eq!(parse_date("2025-12-01"), Some((2025, 12, 1)));
+        assert_eq!(parse_date("2025-13-01"), None);
+        assert_eq!(parse_date("2025-1-01"), None);
+        assert_eq!(parse_date("2025-12-01-"), None);
+        assert_eq!(parse_date("tomorrow"), None);
+    }
+
+    #[test]
+    fn escapes_keywords() {
+        assert_eq!(escape_test_name("match").to_string(), "r#match");
+        assert_eq!(escape_test_name("TRUE").to_string(), "r#true");
+        assert_eq!(escape_test_name("self").to_string(), "_self");
+        assert_eq!(escape_test_name("-").to_string(), "__");
+        assert_eq!(escape_test_name("matches").to_string(), "matches");
+    }
+
+    #[test]
+    fn collects_parsed_tokens() {
+        let parser = |input: syn::parse::ParseStream| {
+            input.parse::<syn::Ident>()?;
+            let begin = input.cursor();
+            input.parse::<syn::Expr>()?;
+            Ok(tokens_between(begin, input.cursor()))
+        };
+        let tokens = syn::parse::Parser::parse_str(parser, "a 1 + (2 * 3)").unwrap();
+        assert_eq!(tokens.to_string(), "1 + (2 * 3)");
+    }
+
+    #[test]
+    fn interpolates_known_placeholders() {
+        let placeholders = vec![
+            ("0".to_string(), "1".to_string()),
+            ("os".to_string(), "windows".to_string()),
+        ];
+        assert_eq!(
+            interpolate("flaky on {os} for {0}, see {issue}", &placeholders),
+            "flaky on windows for 1, see {issue}"
+        );
+    }
+
     mod escape_test_name {
         use super::*;
 
@@ -78,6 +202,14 @@ mod tests {
             );
         }
 
+        #[test]
+        fn preserves_case_on_demand() {
+            assert_eq!(
+                escape_test_name_preserving_case("MiXeD CaSe"),
+                Ident::new("MiXeD_CaSe", Span::call_site())
+            );
+        }
+
         #[test]
         fn handles_numeric_first_char() {
             assert_eq!(
@@ -2,13 +2,19 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 
-use proc_macro2::Span as Span2;
-use syn::{parse_macro_input, ItemFn, Path};
+use proc_macro2::{Span as Span2, TokenStream as TokenStream2};
+use syn::{
+    parse_macro_input, Attribute, Expr, Ident, ItemConst, ItemFn, Macro, Path, Signature,
+    Visibility,
+};
 
 use quote::quote;
 use syn::parse_quote;
 use syn::spanned::Spanned;
-use test_case_core::{TestCase, TestMatrix};
+use test_case_core::{
+    CaseSets, CsvCases, FileCases, IncludedCases, JsonCases, Naming, TestCase, TestCasesFrom,
+    TestMatrix, TomlCases, YamlCases,
+};
 
 /// Generates tests for given set of data
 ///
@@ -21,19 +27,47 @@ use test_case_core::{TestCase, TestMatrix};
 ///
 ///  When _expected result_ is provided, it is compared against the actual value generated with _test body_ using `assert_eq!`.
 /// _Test cases_ that don't provide _expected result_ should contain custom assertions within _test body_ or return `Result` similar to `#[test]` macro.
+///
+/// `#[test_case(files("tests/fixtures/*.txt"))]` generates a case per file matching the pattern,
+/// relative to the crate root, which passes the path of the file and, when the function takes
+/// second parameter, its contents (`&str`, `String` or `&[u8]`) read at compile time.
+///
+/// **`=> compile_fail` compiles the function standalone**: it is passed to `rustc` as a crate of
+/// its own, with the edition read from `Cargo.toml` of the crate under test (or of its workspace)
+/// and no dependencies. The function may use only `std` and items declared within it; using items
+/// of the crate or of other crates is rejected at compile time, and crate-level attributes, e.g.
+/// `#![feature(...)]`, don't apply to it.
+///
+/// `#[test_case(expand!(my_cases!(...)))]` takes cases generated by a macro, which is invoked with
+/// its arguments in parentheses followed by the function and returns the function with
+/// `#[test_case(...)]` attributes added, e.g.
+/// `(($($args:tt)*) $($item:tt)*) => { #[test_case(1, 2 => 3)] $($item)* }`.
 #[proc_macro_attribute]
 pub fn test_case(args: TokenStream, input: TokenStream) -> TokenStream {
-    let test_case = parse_macro_input!(args as TestCase);
+    let args = TokenStream2::from(args);
     let mut item = parse_macro_input!(input as ItemFn);
 
-    let mut test_cases = vec![(test_case, Span2::call_site())];
+    if let Some((carrier, carrier_args)) = expanded_macro(&args) {
+        return quote! { #carrier! { #carrier_args #item } }.into();
+    }
+    if let Some(expanded) = expand_carrier("test_case", &args, &item) {
+        return expanded;
+    }
+
+    let mut test_cases = match parse_test_case(args, &item.sig) {
+        Ok(cases) => cases
+            .into_iter()
+            .map(|case| (case, Span2::call_site()))
+            .collect::<Vec<_>>(),
+        Err(err) => return err.into_compile_error().into(),
+    };
 
     match expand_additional_test_case_macros(&mut item) {
         Ok(cases) => test_cases.extend(cases),
         Err(err) => return err.into_compile_error().into(),
     }
 
-    render_test_cases(&test_cases, item)
+    render_test_cases(test_cases, item)
 }
 
 /// Generates tests for the cartesian product of a given set of data
@@ -50,9 +84,14 @@ pub fn test_case(args: TokenStream, input: TokenStream) -> TokenStream {
 /// macro but are applied to every case generated by `#[test_matrix(...)]`.
 #[proc_macro_attribute]
 pub fn test_matrix(args: TokenStream, input: TokenStream) -> TokenStream {
+    let tokens = TokenStream2::from(args.clone());
     let matrix = parse_macro_input!(args as TestMatrix);
     let mut item = parse_macro_input!(input as ItemFn);
 
+    if let Some(expanded) = expand_carrier("test_matrix", &tokens, &item) {
+        return expanded;
+    }
+
     let mut test_cases = expand_test_matrix(&matrix, Span2::call_site());
 
     match expand_additional_test_case_macros(&mut item) {
@@ -60,31 +99,511 @@ pub fn test_matrix(args: TokenStream, input: TokenStream) -> TokenStream {
         Err(err) => return err.into_compile_error().into(),
     }
 
-    render_test_cases(&test_cases, item)
+    render_test_cases(test_cases, item)
+}
+
+/// Cases of `#[test_case(...)]` with `args`, i.e. the case itself or a case per file matched by
+/// `files("...")`.
+fn parse_test_case(args: TokenStream2, sig: &Signature) -> syn::Result<Vec<TestCase>> {
+    if FileCases::is_files(&args) {
+        syn::parse2::<FileCases>(args)?.cases(sig)
+    } else {
+        syn::parse2::<TestCase>(args).map(|test_case| vec![test_case])
+    }
 }
 
 fn expand_test_matrix(matrix: &TestMatrix, span: Span2) -> Vec<(TestCase, Span2)> {
     matrix.cases().map(|c| (c, span)).collect()
 }
 
-fn expand_additional_test_case_macros(item: &mut ItemFn) -> syn::Result<Vec<(TestCase, Span2)>> {
-    let mut additional_cases = vec![];
-    let mut attrs_to_remove = vec![];
+/// Generates tests for rows of a CSV file
+///
+/// E.g. `#[test_cases_from_csv("tests/data/cases.csv")]` reads given file, relative to the crate
+/// root, while the macro is expanded. First row names parameters of the function in order, each
+/// following row generates a case. Additional last column holds expected result, which is
+/// omitted when the cell is empty. Values of `&str` or `String` parameters and results are taken
+/// verbatim, other values are Rust expressions, e.g. `42` or `Some(1)`.
+#[proc_macro_attribute]
+pub fn test_cases_from_csv(args: TokenStream, input: TokenStream) -> TokenStream {
+    let tokens = TokenStream2::from(args.clone());
+    let csv = parse_macro_input!(args as CsvCases);
+    let item = parse_macro_input!(input as ItemFn);
+
+    if let Some(expanded) = expand_carrier("test_cases_from_csv", &tokens, &item) {
+        return expanded;
+    }
+
+    let cases = csv.cases(&item.sig);
+    render_generated_cases(cases, item)
+}
+
+/// Generates tests for objects in a JSON file
+///
+/// E.g. `#[test_cases_from_json("tests/data/cases.json")]` reads given file, relative to the crate
+/// root, while the macro is expanded. The file holds an array of objects, each generating a case
+/// from values of parameters under their names, optional `expected` result, `description` and
+/// `ignore` (`true` or reason).
+/// Strings are taken verbatim for `&str` or `String` parameters and results and are Rust
+/// expressions otherwise, e.g. `"Some(1)"`. Numbers, booleans and `null` (as `None`) are
+/// literals, arrays and objects are deserialized with `serde_json` (requires `with-json` feature).
+#[proc_macro_attribute]
+pub fn test_cases_from_json(args: TokenStream, input: TokenStream) -> TokenStream {
+    let tokens = TokenStream2::from(args.clone());
+    let json = parse_macro_input!(args as JsonCases);
+    let item = parse_macro_input!(input as ItemFn);
+
+    if let Some(expanded) = expand_carrier("test_cases_from_json", &tokens, &item) {
+        return expanded;
+    }
+
+    let cases = json.cases(&item.sig);
+    render_generated_cases(cases, item)
+}
+
+/// Generates tests for mappings in a YAML file
+///
+/// E.g. `#[test_cases_from_yaml("tests/data/cases.yaml")]` reads given file, relative to the crate
+/// root, while the macro is expanded. The file holds a sequence of mappings with the same keys as
+/// objects of `test_cases_from_json`: values of parameters under their names, optional `expected`
+/// result, `description` and `ignore`, which is `true` or a reason why the case is ignored.
+#[proc_macro_attribute]
+pub fn test_cases_from_yaml(args: TokenStream, input: TokenStream) -> TokenStream {
+    let tokens = TokenStream2::from(args.clone());
+    let yaml = parse_macro_input!(args as YamlCases);
+    let item = parse_macro_input!(input as ItemFn);
+
+    if let Some(expanded) = expand_carrier("test_cases_from_yaml", &tokens, &item) {
+        return expanded;
+    }
+
+    let cases = yaml.cases(&item.sig);
+    render_generated_cases(cases, item)
+}
+
+/// Generates tests for `[[case]]` tables in a TOML file
+///
+/// E.g. `#[test_cases_from_toml("tests/data/cases.toml")]` reads given file, relative to the crate
+/// root, while the macro is expanded. Each `[[case]]` table holds values of parameters under their
+/// names, optional `expected` result, `description` and `ignore`, like objects of
+/// `test_cases_from_json`. Nested tables, e.g. `[case.point]`, become struct literals of the
+/// parameter type, e.g. `Point { x: 1, y: 2 }`, and arrays are converted with `Into`, like
+/// strings nested in them, e.g. into `String` fields.
+#[proc_macro_attribute]
+pub fn test_cases_from_toml(args: TokenStream, input: TokenStream) -> TokenStream {
+    let tokens = TokenStream2::from(args.clone());
+    let toml = parse_macro_input!(args as TomlCases);
+    let item = parse_macro_input!(input as ItemFn);
+
+    if let Some(expanded) = expand_carrier("test_cases_from_toml", &tokens, &item) {
+        return expanded;
+    }
+
+    let cases = toml.cases(&item.sig);
+    render_generated_cases(cases, item)
+}
+
+/// Generates tests for elements of an array of cases
+///
+/// E.g. `#[test_cases(from = [(1, 2, 3), (4, 5, 9)])]` generates a case per tuple of arguments,
+/// optionally followed by expected result, e.g. `add(1, 2)` expecting `3` for `fn add(a, b)`.
+/// Functions taking a single argument take elements as they are.
+/// `#[test_cases(from = CASES)]` takes cases of a const declared with `#[test_case::cases]`
+/// above the function in the same module, so that its elements can be used by benchmarks or
+/// documentation as well.
+#[proc_macro_attribute]
+pub fn test_cases(args: TokenStream, input: TokenStream) -> TokenStream {
+    let tokens = TokenStream2::from(args.clone());
+    let from = parse_macro_input!(args as TestCasesFrom);
+    let item = parse_macro_input!(input as ItemFn);
+
+    if let Some(carrier) = from.carrier() {
+        return quote! { #carrier! { #item } }.into();
+    }
+    if let Some(expanded) = expand_carrier("test_cases", &tokens, &item) {
+        return expanded;
+    }
+
+    let cases = from.cases(&item.sig);
+    render_generated_cases(cases, item)
+}
+
+/// Declares const holding cases for `#[test_cases(from = ...)]`
+///
+/// E.g. `#[test_case::cases] const CASES: [(i32, i32, i32); 2] = [(1, 2, 3), (4, 5, 9)];` keeps the
+/// const as it is and declares macro `CASES!` providing its elements to `#[test_cases(from = CASES)]`
+/// of functions following it in the same module.
+#[proc_macro_attribute]
+pub fn cases(args: TokenStream, input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as ItemConst);
+
+    if !args.is_empty() {
+        return syn::Error::new(Span2::call_site(), "`cases` doesn't take arguments")
+            .into_compile_error()
+            .into();
+    }
+    let is_array = match item.expr.as_ref() {
+        Expr::Reference(reference) => matches!(reference.expr.as_ref(), Expr::Array(_)),
+        expr => matches!(expr, Expr::Array(_)),
+    };
+    if !is_array {
+        return syn::Error::new(
+            item.expr.span(),
+            "`cases` requires const initialized with array",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    let name = &item.ident;
+    let elements = &item.expr;
+    quote! {
+        #item
+
+        #[allow(unused_macros)]
+        macro_rules! #name {
+            ($($item:tt)*) => {
+                #[test_case::test_cases(from = #elements)]
+                $($item)*
+            };
+        }
+    }
+    .into()
+}
+
+/// Declares named sets of cases, which can be used by multiple functions
+///
+/// E.g. `case_set! { sums => { (1, 2 => 3), (4, 5 => 9 ; "larger") } }` declares set `sums` of
+/// cases written as arguments of `#[test_case(...)]`, which `#[use_case_set(sums)]` applies to
+/// functions following the declaration in the same module.
+/// `case_set! { pub conformance => { ... } }` exports the set from the crate, so that other crates
+/// can run its cases with `#[use_case_set(my_crate::conformance)]`. `pub(crate)` sets can be used
+/// by path, e.g. `#[use_case_set(crate::cases::sums)]`.
+#[proc_macro]
+pub fn case_set(input: TokenStream) -> TokenStream {
+    let sets = parse_macro_input!(input as CaseSets);
+    sets.render().into()
+}
+
+/// Generates tests for cases of a set declared with `case_set!`
+///
+/// E.g. `#[use_case_set(sums)]` generates cases of set `sums` for the function, together with
+/// cases of other attributes like `#[test_case(...)]`.
+#[proc_macro_attribute]
+pub fn use_case_set(args: TokenStream, input: TokenStream) -> TokenStream {
+    let set = parse_macro_input!(args as Path);
+    let item = parse_macro_input!(input as ItemFn);
+
+    quote! { #set! { #item } }.into()
+}
+
+/// Generates tests for cases of attributes included from a file
+///
+/// E.g. `#[test_cases_from(include = concat!(env!("OUT_DIR"), "/generated_cases.rs"))]` includes a
+/// file generated by build script, which holds attributes generating cases, e.g.
+/// `#[test_case(1, 2 => 3)]` or `#[test_matrix(...)]`, named as they would be in place of the
+/// attribute. Path is a string literal, `concat!(...)` or `env!(...)` of them, relative paths are
+/// relative to the crate root.
+#[proc_macro_attribute]
+pub fn test_cases_from(args: TokenStream, input: TokenStream) -> TokenStream {
+    let included = parse_macro_input!(args as IncludedCases);
+    let item = parse_macro_input!(input as ItemFn);
+
+    match included_attrs(&included) {
+        Ok((tracking, attrs)) => quote! {
+            #tracking
+            #(#attrs)*
+            #item
+        }
+        .into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// Attributes of file `included`, preceded by reference to the file, so that cases are
+/// regenerated when it changes.
+fn included_attrs(included: &IncludedCases) -> syn::Result<(TokenStream2, Vec<Attribute>)> {
+    let (full_path, attrs) = included.attrs()?;
+    if let Some(path) = attrs
+        .iter()
+        .map(Attribute::path)
+        .find(|path| !is_case_source(path))
+    {
+        return Err(syn::Error::new(
+            Span2::call_site(),
+            format!(
+                "`{full_path}` holds `#[{}]`, which doesn't generate cases",
+                quote! { #path }.to_string().replace(' ', "")
+            ),
+        ));
+    }
+    Ok((
+        quote! { const _: &[u8] = include_bytes!(#full_path); },
+        attrs,
+    ))
+}
+
+/// Expands macro carrying cases of the first attribute of `item` which requires it, e.g.
+/// `#[test_cases(from = CASES)]`, or includes attributes of `#[test_cases_from(include = ...)]`.
+/// Attribute `#[test_case::#name(#args)]` being expanded is put back in front of the item, to be
+/// expanded again together with cases the macro provides.
+fn expand_carrier(name: &str, args: &TokenStream2, item: &ItemFn) -> Option<TokenStream> {
+    let name = Ident::new(name, Span2::call_site());
+    if let Some(idx) = item
+        .attrs
+        .iter()
+        .position(|attr| is_test_cases_from(attr.path()))
+    {
+        let mut item = item.clone();
+        let attr = item.attrs.remove(idx);
+        let included = attr
+            .parse_args::<IncludedCases>()
+            .and_then(|included| included_attrs(&included));
+        return Some(match included {
+            Ok((tracking, attrs)) => quote! {
+                #tracking
+                #[test_case::#name(#args)]
+                #(#attrs)*
+                #item
+            }
+            .into(),
+            Err(err) => err.into_compile_error().into(),
+        });
+    }
+
+    let (idx, (carrier, carrier_args)) = item
+        .attrs
+        .iter()
+        .enumerate()
+        .find_map(|(idx, attr)| carrier(attr).map(|carrier| (idx, carrier)))?;
+    let mut item = item.clone();
+    item.attrs.remove(idx);
+    Some(
+        quote! {
+            #carrier! {
+                #carrier_args
+                #[test_case::#name(#args)]
+                #item
+            }
+        }
+        .into(),
+    )
+}
+
+/// Macro carrying cases of `attr`, which has to be expanded before the attribute, with arguments
+/// preceding the function it is invoked with.
+fn carrier(attr: &Attribute) -> Option<(Path, TokenStream2)> {
+    if is_test_case(attr.path()) {
+        let list = attr.meta.require_list().ok()?;
+        return expanded_macro(&list.tokens);
+    }
+    if is_use_case_set(attr.path()) {
+        let set = attr.parse_args::<Path>().ok()?;
+        return Some((set, TokenStream2::new()));
+    }
+    if !is_test_cases(attr.path()) {
+        return None;
+    }
+    let from = attr.parse_args::<TestCasesFrom>().ok()?;
+    from.carrier()
+        .map(|carrier| (carrier.clone(), TokenStream2::new()))
+}
+
+/// Macro invoked by `#[test_case(expand!(my_cases!(...)))]` arguments `args`, with its arguments
+/// in parentheses.
+fn expanded_macro(args: &TokenStream2) -> Option<(Path, TokenStream2)> {
+    let expand = syn::parse2::<Macro>(args.clone()).ok()?;
+    if !expand.path.is_ident("expand") {
+        return None;
+    }
+    let carrier = syn::parse2::<Macro>(expand.tokens).ok()?;
+    let carrier_args = carrier.tokens;
+    Some((carrier.path, quote! { (#carrier_args) }))
+}
+
+fn render_generated_cases(cases: syn::Result<Vec<TestCase>>, mut item: ItemFn) -> TokenStream {
+    let mut test_cases = match cases {
+        Ok(cases) => cases
+            .into_iter()
+            .map(|case| (case, Span2::call_site()))
+            .collect::<Vec<_>>(),
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    match expand_additional_test_case_macros(&mut item) {
+        Ok(cases) => test_cases.extend(cases),
+        Err(err) => return err.into_compile_error().into(),
+    }
+
+    render_test_cases(test_cases, item)
+}
+
+/// Declares how generated cases are named
+///
+/// E.g. `#[test_case::naming(name = "{fn}_{arg0}_{arg1}")]` names cases without description
+/// after given template, where `{fn}` is the name of the function and `{argN}` (or parameter name)
+/// is a value of the case argument. `#[naming(name = "case_{case}")]` numbers cases instead,
+/// i.e. `case_01`, `case_02`, ... `unicode = "transliterate"` or `unicode = "hex"` turns non-ASCII
+/// characters into ASCII ones. `casing = "snake_case"` or `casing = "preserve"` changes the default
+/// lowercasing, e.g. `MyType` becomes `my_type` or stays `MyType` instead of `mytype`.
+/// `max_len = 64` truncates longer names and suffixes them with a hash of the whole name.
+/// `expected = "short"` phrases expectation briefly, e.g. `returns_42` or `panics`.
+/// `nextest_safe = true` keeps names ASCII and without leading, trailing or doubled `_`.
+/// `module = "parser_cases"` names the module holding generated cases, instead of the function.
+/// `flat = true` generates cases next to the function, named `{fn}_{case}`, without a module.
+/// `nested = true` groups cases into modules named after all arguments but last, e.g. `width_4::height_2`.
+/// `vis = "pub(crate)"` sets visibility of generated module and cases, which are private by default.
+/// `imports = ["super::parse"]` replaces `use super::*` in generated module, `imports = []` omits it.
+/// `cfg = any(test, feature = "integration")` compiles generated cases under given predicate
+/// instead of `cfg(test)`.
+/// `groups = true` generates case described as `"parsing :: empty input"` in module `parsing`.
+/// `gate_original = true` compiles the function itself only under `cfg(test)` (or `cfg` option).
+/// `keyword_prefix = "kw_"` prefixes names which would be keywords, instead of using e.g. `r#match`.
+/// Has to be used together with `#[test_case(...)]` or `#[test_matrix(...)]`.
+#[proc_macro_attribute]
+pub fn naming(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = TokenStream2::from(args);
+    let mut item = parse_macro_input!(input as ItemFn);
+
+    if !item.attrs.iter().any(|attr| is_case_source(attr.path())) {
+        return syn::Error::new(
+            Span2::call_site(),
+            "`naming` requires `#[test_case(...)]` or `#[test_matrix(...)]` below it",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    // Placed above test cases, leave it for `#[test_case]` to pick up
+    item.attrs
+        .push(parse_quote! { #[test_case::naming(#args)] });
+    quote! { #item }.into()
+}
+
+/// Declares test attribute generated cases carry instead of `#[test]`
+///
+/// E.g. `#[test_case::runner(tokio::test(flavor = "multi_thread"))]`, `#[runner(wasm_bindgen_test)]`.
+/// `#[runner(block_on = pollster::block_on)]` runs async cases from a plain `#[test]` by passing
+/// their body to given function instead.
+/// Has to be used together with `#[test_case(...)]` or `#[test_matrix(...)]`.
+#[proc_macro_attribute]
+pub fn runner(args: TokenStream, input: TokenStream) -> TokenStream {
+    let args = TokenStream2::from(args);
+    let mut item = parse_macro_input!(input as ItemFn);
+
+    if !item.attrs.iter().any(|attr| is_case_source(attr.path())) {
+        return syn::Error::new(
+            Span2::call_site(),
+            "`runner` requires `#[test_case(...)]` or `#[test_matrix(...)]` below it",
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    // Placed above test cases, leave it for `#[test_case]` to pick up
+    item.attrs
+        .push(parse_quote! { #[test_case::runner(#args)] });
+    quote! { #item }.into()
+}
+
+fn is_test_case(path: &Path) -> bool {
     let legal_test_case_names: [Path; 4] = [
         parse_quote!(test_case),
         parse_quote!(test_case::test_case),
         parse_quote!(test_case::case),
         parse_quote!(case),
     ];
+    legal_test_case_names.contains(path)
+}
+
+fn is_test_matrix(path: &Path) -> bool {
     let legal_test_matrix_names: [Path; 2] = [
         parse_quote!(test_matrix),
         parse_quote!(test_case::test_matrix),
     ];
+    legal_test_matrix_names.contains(path)
+}
+
+fn is_test_cases_from_csv(path: &Path) -> bool {
+    let legal_names: [Path; 2] = [
+        parse_quote!(test_cases_from_csv),
+        parse_quote!(test_case::test_cases_from_csv),
+    ];
+    legal_names.contains(path)
+}
+
+fn is_test_cases_from_json(path: &Path) -> bool {
+    let legal_names: [Path; 2] = [
+        parse_quote!(test_cases_from_json),
+        parse_quote!(test_case::test_cases_from_json),
+    ];
+    legal_names.contains(path)
+}
+
+fn is_test_cases_from_yaml(path: &Path) -> bool {
+    let legal_names: [Path; 2] = [
+        parse_quote!(test_cases_from_yaml),
+        parse_quote!(test_case::test_cases_from_yaml),
+    ];
+    legal_names.contains(path)
+}
+
+fn is_test_cases_from_toml(path: &Path) -> bool {
+    let legal_names: [Path; 2] = [
+        parse_quote!(test_cases_from_toml),
+        parse_quote!(test_case::test_cases_from_toml),
+    ];
+    legal_names.contains(path)
+}
+
+fn is_test_cases(path: &Path) -> bool {
+    let legal_names: [Path; 2] = [
+        parse_quote!(test_cases),
+        parse_quote!(test_case::test_cases),
+    ];
+    legal_names.contains(path)
+}
+
+fn is_use_case_set(path: &Path) -> bool {
+    let legal_names: [Path; 2] = [
+        parse_quote!(use_case_set),
+        parse_quote!(test_case::use_case_set),
+    ];
+    legal_names.contains(path)
+}
+
+fn is_test_cases_from(path: &Path) -> bool {
+    let legal_names: [Path; 2] = [
+        parse_quote!(test_cases_from),
+        parse_quote!(test_case::test_cases_from),
+    ];
+    legal_names.contains(path)
+}
+
+/// Whether `path` names an attribute generating cases.
+fn is_case_source(path: &Path) -> bool {
+    is_test_case(path)
+        || is_test_matrix(path)
+        || is_test_cases_from_csv(path)
+        || is_test_cases_from_json(path)
+        || is_test_cases_from_yaml(path)
+        || is_test_cases_from_toml(path)
+        || is_test_cases(path)
+        || is_use_case_set(path)
+        || is_test_cases_from(path)
+}
+
+fn expand_additional_test_case_macros(item: &mut ItemFn) -> syn::Result<Vec<(TestCase, Span2)>> {
+    let mut additional_cases = vec![];
+    let mut attrs_to_remove = vec![];
 
     for (idx, attr) in item.attrs.iter().enumerate() {
-        if legal_test_case_names.contains(attr.path()) {
-            let test_case = match attr.parse_args::<TestCase>() {
-                Ok(test_case) => test_case,
+        if is_test_case(attr.path()) {
+            let test_cases = match attr
+                .meta
+                .require_list()
+                .and_then(|list| parse_test_case(list.tokens.clone(), &item.sig))
+            {
+                Ok(test_cases) => test_cases,
                 Err(err) => {
                     return Err(syn::Error::new(
                         attr.span(),
@@ -92,9 +611,10 @@ fn expand_additional_test_case_macros(item: &mut ItemFn) -> syn::Result<Vec<(Tes
                     ))
                 }
             };
-            additional_cases.push((test_case, attr.span()));
+            let span = attr.span();
+            additional_cases.extend(test_cases.into_iter().map(|c| (c, span)));
             attrs_to_remove.push(idx);
-        } else if legal_test_matrix_names.contains(attr.path()) {
+        } else if is_test_matrix(attr.path()) {
             let test_matrix = match attr.parse_args::<TestMatrix>() {
                 Ok(test_matrix) => test_matrix,
                 Err(err) => {
@@ -106,6 +626,56 @@ fn expand_additional_test_case_macros(item: &mut ItemFn) -> syn::Result<Vec<(Tes
             };
             additional_cases.extend(expand_test_matrix(&test_matrix, attr.span()));
             attrs_to_remove.push(idx);
+        } else if is_test_cases_from_csv(attr.path()) {
+            let csv = attr.parse_args::<CsvCases>().map_err(|err| {
+                syn::Error::new(
+                    attr.span(),
+                    format!("cannot parse test_cases_from_csv arguments: {err}"),
+                )
+            })?;
+            let span = attr.span();
+            additional_cases.extend(csv.cases(&item.sig)?.into_iter().map(|c| (c, span)));
+            attrs_to_remove.push(idx);
+        } else if is_test_cases_from_json(attr.path()) {
+            let json = attr.parse_args::<JsonCases>().map_err(|err| {
+                syn::Error::new(
+                    attr.span(),
+                    format!("cannot parse test_cases_from_json arguments: {err}"),
+                )
+            })?;
+            let span = attr.span();
+            additional_cases.extend(json.cases(&item.sig)?.into_iter().map(|c| (c, span)));
+            attrs_to_remove.push(idx);
+        } else if is_test_cases_from_yaml(attr.path()) {
+            let yaml = attr.parse_args::<YamlCases>().map_err(|err| {
+                syn::Error::new(
+                    attr.span(),
+                    format!("cannot parse test_cases_from_yaml arguments: {err}"),
+                )
+            })?;
+            let span = attr.span();
+            additional_cases.extend(yaml.cases(&item.sig)?.into_iter().map(|c| (c, span)));
+            attrs_to_remove.push(idx);
+        } else if is_test_cases_from_toml(attr.path()) {
+            let toml = attr.parse_args::<TomlCases>().map_err(|err| {
+                syn::Error::new(
+                    attr.span(),
+                    format!("cannot parse test_cases_from_toml arguments: {err}"),
+                )
+            })?;
+            let span = attr.span();
+            additional_cases.extend(toml.cases(&item.sig)?.into_iter().map(|c| (c, span)));
+            attrs_to_remove.push(idx);
+        } else if is_test_cases(attr.path()) {
+            let from = attr.parse_args::<TestCasesFrom>().map_err(|err| {
+                syn::Error::new(
+                    attr.span(),
+                    format!("cannot parse test_cases arguments: {err}"),
+                )
+            })?;
+            let span = attr.span();
+            additional_cases.extend(from.cases(&item.sig)?.into_iter().map(|c| (c, span)));
+            attrs_to_remove.push(idx);
         }
     }
 
@@ -116,15 +686,47 @@ fn expand_additional_test_case_macros(item: &mut ItemFn) -> syn::Result<Vec<(Tes
     Ok(additional_cases)
 }
 
-#[allow(unused_mut)]
-fn render_test_cases(test_cases: &[(TestCase, Span2)], mut item: ItemFn) -> TokenStream {
+fn render_test_cases(mut test_cases: Vec<(TestCase, Span2)>, mut item: ItemFn) -> TokenStream {
+    let naming = match Naming::take(&mut item.attrs) {
+        Ok(naming) => naming,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
+    let count = test_cases.len();
+    for (index, (test_case, _)) in test_cases.iter_mut().enumerate() {
+        test_case.apply_naming(&naming, &item.sig, index, count);
+    }
+    if let Err(err) = check_unique_names(&test_cases) {
+        return err.into_compile_error().into();
+    }
+
     let mut rendered_test_cases = vec![];
+    let mut nested_modules = ModuleTree::default();
 
-    for (test_case, span) in test_cases {
-        rendered_test_cases.push(test_case.render(item.clone(), *span));
+    for (test_case, span) in &test_cases {
+        let rendered = test_case.render(item.clone(), *span);
+        if test_case.modules().is_empty() {
+            rendered_test_cases.push(rendered);
+        } else {
+            nested_modules.insert(test_case.modules(), rendered);
+        }
     }
 
-    let mod_name = item.sig.ident.clone();
+    let mod_name = naming.module(&item.sig.ident);
+
+    // `#[async_std::test]` expands to code referring to `async_std` crate
+    #[cfg(feature = "with-async-std")]
+    let runtime_imports = quote! {
+        #[allow(unused_imports)]
+        use ::test_case::async_std;
+    };
+    #[cfg(not(feature = "with-async-std"))]
+    let runtime_imports = quote! {};
+    let vis = naming.visibility();
+    let imports = naming.imports();
+    let cfg = naming.cfg();
+    let item_cfg = naming.gates_original().then(|| quote! { #[cfg(#cfg)] });
+    let nested_modules = nested_modules.render(vis, &runtime_imports);
 
     // We don't want any external crate to alter main fn code, we are passing attributes to each sub-function anyway
     item.attrs.retain(|attr| {
@@ -134,18 +736,111 @@ fn render_test_cases(test_cases: &[(TestCase, Span2)], mut item: ItemFn) -> Toke
             .unwrap_or(false)
     });
 
+    if naming.is_flat() {
+        return quote! {
+            #item_cfg
+            #[allow(unused_attributes)]
+            #item
+
+            #(
+                #[cfg(#cfg)]
+                #rendered_test_cases
+            )*
+        }
+        .into();
+    }
+
     let output = quote! {
+        #item_cfg
         #[allow(unused_attributes)]
         #item
 
-        #[cfg(test)]
-        mod #mod_name {
-            #[allow(unused_imports)]
-            use super::*;
+        #[cfg(#cfg)]
+        #vis mod #mod_name {
+            #imports
+            #runtime_imports
 
             #(#rendered_test_cases)*
+            #nested_modules
         }
     };
 
     output.into()
 }
+
+/// Modules generated for `#[test_case::naming(nested = true)]`, with test cases rendered inside.
+#[derive(Default)]
+struct ModuleTree {
+    cases: Vec<TokenStream2>,
+    modules: Vec<(Ident, ModuleTree)>,
+}
+
+impl ModuleTree {
+    fn insert(&mut self, path: &[Ident], case: TokenStream2) {
+        let (first, rest) = match path.split_first() {
+            Some(split) => split,
+            None => return self.cases.push(case),
+        };
+        let idx = match self.modules.iter().position(|(name, _)| name == first) {
+            Some(idx) => idx,
+            None => {
+                self.modules.push((first.clone(), ModuleTree::default()));
+                self.modules.len() - 1
+            }
+        };
+        self.modules[idx].1.insert(rest, case);
+    }
+
+    fn render(&self, vis: Option<&Visibility>, runtime_imports: &TokenStream2) -> TokenStream2 {
+        let modules = self.modules.iter().map(|(name, module)| {
+            let cases = &module.cases;
+            let nested = module.render(vis, runtime_imports);
+            quote! {
+                #vis mod #name {
+                    #[allow(unused_imports)]
+                    use super::*;
+                    #runtime_imports
+
+                    #(#cases)*
+                    #nested
+                }
+            }
+        });
+        quote! { #(#modules)* }
+    }
+}
+
+/// Reports cases which would generate test functions of the same name, pointing at both of them.
+/// Cases restricted with `cfg` may share the name, e.g. `only(unix)` and `only(windows)`.
+fn check_unique_names(test_cases: &[(TestCase, Span2)]) -> syn::Result<()> {
+    let mut errors: Option<syn::Error> = None;
+    for (idx, (test_case, span)) in test_cases.iter().enumerate() {
+        if test_case.is_conditional() {
+            continue;
+        }
+        let name = test_case.test_case_name();
+        let first = test_cases[..idx].iter().find(|(other, _)| {
+            !other.is_conditional()
+                && other.test_case_name() == name
+                && other.modules() == test_case.modules()
+        });
+        if let Some((_, first_span)) = first {
+            let mut error = syn::Error::new(
+                *span,
+                format!(
+                    "test case name `{name}` is generated more than once, \
+                     add description to one of the cases, e.g. `; \"description\"`"
+                ),
+            );
+            error.combine(syn::Error::new(
+                *first_span,
+                format!("first case named `{name}` is declared here"),
+            ));
+            match errors.as_mut() {
+                Some(errors) => errors.combine(error),
+                None => errors = Some(error),
+            }
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
@@ -0,0 +1,612 @@
+//! Runtime helpers used by code generated by `test_case` macros. Not a public API.
+
+#[cfg(feature = "with-max-alloc")]
+pub use self::alloc::AllocBudget;
+pub use self::argument::{Argument, ArgumentClone, ArgumentMove, MovedArgument};
+pub use self::bytes::bytes_diff;
+pub use self::compile::compile_fail;
+pub use self::hooks::After;
+#[cfg(feature = "with-json")]
+pub use self::json::{json_diff, AsJson};
+pub use self::report::report_inconclusive;
+pub use self::retry::{expect_failure, retry, Attempt};
+pub use self::reuse::{Reused, ReusedClone, ReusedRef};
+pub use self::serial::serial_lock;
+pub use self::skip::{tags_selected, Skipped};
+pub use self::slow::SlowWarning;
+pub use self::until::ignored_until;
+
+mod retry {
+    use super::Skipped;
+    use std::any::Any;
+    use std::fmt::Debug;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    /// Value returned by a single attempt of a test case.
+    pub trait Attempt {
+        fn failure(&self) -> Option<String>;
+    }
+
+    impl Attempt for () {
+        fn failure(&self) -> Option<String> {
+            None
+        }
+    }
+
+    impl<T, E: Debug> Attempt for Result<T, E> {
+        fn failure(&self) -> Option<String> {
+            self.as_ref().err().map(|e| format!("{e:?}"))
+        }
+    }
+
+    /// Runs `attempt` until it succeeds, at most `retries + 1` times.
+    #[track_caller]
+    pub fn retry<T: Attempt>(retries: usize, mut attempt: impl FnMut() -> T) -> T {
+        let mut failures = vec![];
+        loop {
+            match catch_unwind(AssertUnwindSafe(&mut attempt)) {
+                Ok(value) => match value.failure() {
+                    Some(failure) => failures.push(failure),
+                    None => return value,
+                },
+                Err(payload) => failures.push(panic_message(payload.as_ref())),
+            }
+            if failures.len() > retries {
+                let attempts: Vec<_> = failures
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, failure)| format!("attempt {}: {}", idx + 1, failure))
+                    .collect();
+                panic!(
+                    "all {} attempts failed\n{}",
+                    failures.len(),
+                    attempts.join("\n")
+                )
+            }
+        }
+    }
+
+    /// Runs `attempt` which is expected to fail, panics when it succeeds.
+    #[track_caller]
+    pub fn expect_failure<T: Attempt + Skipped>(reason: &str, attempt: impl FnOnce() -> T) -> T {
+        let failure = match catch_unwind(AssertUnwindSafe(attempt)) {
+            Ok(value) => value.failure(),
+            Err(payload) => Some(panic_message(payload.as_ref())),
+        };
+        let reason = if reason.is_empty() {
+            String::new()
+        } else {
+            format!(" ({reason})")
+        };
+        match failure {
+            Some(failure) => {
+                println!("expected failure{reason}: {failure}");
+                T::skipped()
+            }
+            None => panic!("case marked as `xfail`{reason} now passes, remove `xfail`"),
+        }
+    }
+
+    fn panic_message(payload: &(dyn Any + Send)) -> String {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "Box<dyn Any>".to_string()
+        }
+    }
+}
+
+mod serial {
+    use std::sync::{Mutex, MutexGuard, PoisonError};
+
+    static GROUPS: Mutex<Vec<(String, &'static Mutex<()>)>> = Mutex::new(Vec::new());
+
+    /// Locks mutex shared by all `serial` cases of the given group, `""` being the default group.
+    pub fn serial_lock(group: &str) -> MutexGuard<'static, ()> {
+        let mutex = {
+            let mut groups = GROUPS.lock().unwrap_or_else(PoisonError::into_inner);
+            match groups.iter().find(|(name, _)| name == group) {
+                Some((_, mutex)) => *mutex,
+                None => {
+                    let mutex: &'static Mutex<()> = Box::leak(Box::new(Mutex::new(())));
+                    groups.push((group.to_string(), mutex));
+                    mutex
+                }
+            }
+        };
+        // a failed case poisons the mutex, which must not fail following cases
+        mutex.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+mod hooks {
+    /// Runs `after` hook of a case when dropped, so it also runs when the case panics.
+    pub struct After<F: FnOnce()>(Option<F>);
+
+    impl<F: FnOnce()> After<F> {
+        pub fn new(hook: F) -> Self {
+            Self(Some(hook))
+        }
+    }
+
+    impl<F: FnOnce()> Drop for After<F> {
+        fn drop(&mut self) {
+            if let Some(hook) = self.0.take() {
+                hook()
+            }
+        }
+    }
+}
+
+mod reuse {
+    /// Value wrapped in `Some`, `Ok` or `Err` which is checked again by following cases of `and` /
+    /// `or`: cloned if possible, so that it is checked as if it was moved, borrowed otherwise.
+    pub struct Reused<'a, T>(pub &'a T);
+
+    pub trait ReusedClone<T> {
+        fn reused(&self) -> T;
+    }
+
+    impl<T: Clone> ReusedClone<T> for Reused<'_, T> {
+        fn reused(&self) -> T {
+            self.0.clone()
+        }
+    }
+
+    pub trait ReusedRef<'a, T> {
+        fn reused(&self) -> &'a T;
+    }
+
+    impl<'a, T> ReusedRef<'a, T> for &Reused<'a, T> {
+        fn reused(&self) -> &'a T {
+            self.0
+        }
+    }
+}
+
+mod argument {
+    /// Case argument evaluated once and both passed to the function under test and bound to the
+    /// parameter name for the assertion: cloned if possible, moved into the function otherwise.
+    pub struct Argument<T>(pub Option<T>);
+
+    /// Binding of an argument which doesn't implement `Clone`, it was moved into the function.
+    pub struct MovedArgument;
+
+    pub trait ArgumentClone<T> {
+        fn pass(&mut self) -> T;
+        fn bound(&mut self) -> T;
+    }
+
+    impl<T: Clone> ArgumentClone<T> for Argument<T> {
+        fn pass(&mut self) -> T {
+            self.0.clone().expect("argument is passed once")
+        }
+
+        fn bound(&mut self) -> T {
+            self.0.take().expect("argument is bound once")
+        }
+    }
+
+    pub trait ArgumentMove<T> {
+        fn pass(&mut self) -> T;
+        fn bound(&mut self) -> MovedArgument;
+    }
+
+    impl<T> ArgumentMove<T> for &mut Argument<T> {
+        fn pass(&mut self) -> T {
+            self.0.take().expect("argument is passed once")
+        }
+
+        fn bound(&mut self) -> MovedArgument {
+            MovedArgument
+        }
+    }
+}
+
+mod until {
+    /// Fails once the UTC date reaches `until` as `(year, month, day)`, so that a case ignored
+    /// until then is enabled or snoozed again.
+    #[track_caller]
+    pub fn ignored_until(until: (u32, u32, u32), message: &str) {
+        if today() >= until {
+            panic!("{message}")
+        }
+    }
+
+    /// Current UTC date as `(year, month, day)`.
+    fn today() -> (u32, u32, u32) {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        civil_from_days(secs / 86_400)
+    }
+
+    /// Converts number of days since 1970-01-01 into a date, see
+    /// <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+    fn civil_from_days(days: u64) -> (u32, u32, u32) {
+        let z = days + 719_468;
+        let era = z / 146_097;
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + u64::from(month <= 2);
+        (year as u32, month as u32, day as u32)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::civil_from_days;
+
+        #[test]
+        fn converts_days_to_dates() {
+            assert_eq!(civil_from_days(0), (1970, 1, 1));
+            assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+            assert_eq!(civil_from_days(20_423), (2025, 12, 1));
+        }
+    }
+}
+
+mod slow {
+    use std::time::{Duration, Instant};
+
+    /// Prints a warning when dropped later than `threshold` after creation, unless the case panicked.
+    pub struct SlowWarning {
+        case: &'static str,
+        threshold: Duration,
+        started: Instant,
+    }
+
+    impl SlowWarning {
+        pub fn new(case: &'static str, threshold: Duration) -> Self {
+            Self {
+                case,
+                threshold,
+                started: Instant::now(),
+            }
+        }
+    }
+
+    impl Drop for SlowWarning {
+        fn drop(&mut self) {
+            let elapsed = self.started.elapsed();
+            if elapsed > self.threshold && !std::thread::panicking() {
+                let case = self
+                    .case
+                    .split_once("::")
+                    .map_or(self.case, |(_, case)| case);
+                eprintln!(
+                    "test-case slow: case={case:?} threshold={:?} elapsed={elapsed:?}",
+                    self.threshold
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "with-max-alloc")]
+mod alloc {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    thread_local! {
+        static ALLOCATED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Counts bytes allocated by each thread, installed by `with-max-alloc` feature.
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn count(bytes: usize) {
+        // thread locals are not available while thread is being torn down
+        let _ = ALLOCATED.try_with(|allocated| allocated.set(allocated.get().wrapping_add(bytes)));
+    }
+
+    fn allocated() -> usize {
+        ALLOCATED.try_with(Cell::get).unwrap_or_default()
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            count(layout.size());
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            count(layout.size());
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            count(new_size.saturating_sub(layout.size()));
+            System.realloc(ptr, layout, new_size)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    /// Measures bytes allocated by the thread running a case.
+    pub struct AllocBudget {
+        budget: usize,
+        limit: &'static str,
+        start: usize,
+    }
+
+    impl AllocBudget {
+        pub fn new(budget: usize, limit: &'static str) -> Self {
+            Self {
+                budget,
+                limit,
+                start: allocated(),
+            }
+        }
+
+        /// Fails the case when more than `budget` bytes were allocated since creation.
+        #[track_caller]
+        pub fn check(self) {
+            let allocated = allocated().wrapping_sub(self.start);
+            if allocated > self.budget {
+                panic!(
+                    "case allocated {allocated} bytes, more than `max_alloc = {}`",
+                    self.limit
+                )
+            }
+        }
+    }
+}
+
+mod compile {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+    /// Compiles `source` as a standalone library crate, returns compiler errors on failure.
+    fn compile(source: &str, edition: &str) -> Result<(), String> {
+        let out_dir = std::env::temp_dir().join(format!(
+            "test-case-compile-fail-{}-{}",
+            std::process::id(),
+            BUILDS.fetch_add(1, Ordering::SeqCst)
+        ));
+        let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+        let mut child = Command::new(rustc)
+            .args([
+                "-",
+                "--crate-name",
+                "test_case_compile_fail",
+                "--crate-type",
+                "lib",
+            ])
+            .args([
+                "--edition",
+                edition,
+                "--emit",
+                "metadata",
+                "--cap-lints",
+                "allow",
+            ])
+            .arg("--out-dir")
+            .arg(&out_dir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("failed to run rustc");
+        child
+            .stdin
+            .take()
+            .expect("rustc stdin")
+            .write_all(source.as_bytes())
+            .expect("failed to pass source to rustc");
+        let output = child.wait_with_output().expect("failed to run rustc");
+        let _ = std::fs::remove_dir_all(&out_dir);
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).into_owned())
+        }
+    }
+
+    /// Checks that `function` compiles on its own with `edition` of the crate under test, but
+    /// `call` of it does not. Returns errors reported by the compiler for the call.
+    #[track_caller]
+    pub fn compile_fail(function: &str, call: &str, edition: &str) -> String {
+        if let Err(errors) = compile(function, edition) {
+            panic!(
+                "function has to compile standalone, using only `std` and edition {edition}:\n{errors}"
+            )
+        }
+        let source = format!("{function}\nfn _test_case_compile_fail() {{ let _ = {call}; }}");
+        match compile(&source, edition) {
+            Ok(()) => panic!("expected `{call}` to fail to compile, but it compiled"),
+            Err(errors) => errors,
+        }
+    }
+}
+
+mod report {
+    use std::fs::OpenOptions;
+    use std::io::Write;
+
+    /// Prints a line describing inconclusive case which was run anyway, e.g. with `--include-ignored`,
+    /// and appends it to file named by `TEST_CASE_INCONCLUSIVE_SUMMARY`, if set.
+    pub fn report_inconclusive(case: &str, reason: &str, args: &str) {
+        let case = case.split_once("::").map_or(case, |(_, case)| case);
+        let line = format!("test-case inconclusive: case={case:?} reason={reason:?} args={args:?}");
+        println!("{line}");
+        if let Some(path) = std::env::var_os("TEST_CASE_INCONCLUSIVE_SUMMARY") {
+            let written = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .and_then(|mut file| writeln!(file, "{line}"));
+            if let Err(e) = written {
+                eprintln!("failed to write inconclusive summary to {path:?}: {e}");
+            }
+        }
+    }
+}
+
+mod skip {
+    /// Value returned by a test case skipped at runtime.
+    pub trait Skipped {
+        fn skipped() -> Self;
+    }
+
+    impl Skipped for () {
+        fn skipped() -> Self {}
+    }
+
+    impl<T: Skipped, E> Skipped for Result<T, E> {
+        fn skipped() -> Self {
+            Ok(T::skipped())
+        }
+    }
+
+    /// Whether case with given tags is selected by comma separated `TEST_CASE_TAGS`,
+    /// where `tag` selects cases having it and `!tag` excludes them.
+    pub fn tags_selected(tags: &[&str]) -> bool {
+        let filter = std::env::var("TEST_CASE_TAGS").unwrap_or_default();
+        let mut included = vec![];
+        for item in filter
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            match item.strip_prefix('!') {
+                Some(excluded) if tags.contains(&excluded) => return false,
+                Some(_) => {}
+                None => included.push(item),
+            }
+        }
+        included.is_empty() || included.iter().any(|item| tags.contains(item))
+    }
+}
+
+mod bytes {
+    use std::fmt::Write;
+
+    const ROW: usize = 8;
+
+    /// Side-by-side hexdump of both slices, rows containing differences are marked with `>`
+    /// and the first differing byte is put in brackets.
+    pub fn bytes_diff(actual: &[u8], expected: &[u8]) -> String {
+        let first = actual
+            .iter()
+            .zip(expected)
+            .position(|(actual, expected)| actual != expected)
+            .unwrap_or_else(|| actual.len().min(expected.len()));
+        let width = ROW * 4;
+
+        let mut out = format!(
+            "first difference at offset {first} (0x{first:x}), actual length {}, expected length {}\n",
+            actual.len(),
+            expected.len()
+        );
+        let _ = writeln!(out, "  offset    {:width$} |  expected", " actual");
+        for row in (0..actual.len().max(expected.len())).step_by(ROW) {
+            let range = row..row + ROW;
+            let differs = range
+                .clone()
+                .any(|idx| actual.get(idx) != expected.get(idx));
+            let _ = writeln!(
+                out,
+                "{} {row:08x}  {:width$} | {}",
+                if differs { '>' } else { ' ' },
+                hex_row(actual, row, first),
+                hex_row(expected, row, first).trim_end(),
+            );
+        }
+        out
+    }
+
+    fn hex_row(bytes: &[u8], row: usize, first: usize) -> String {
+        (row..row + ROW)
+            .map(|idx| match bytes.get(idx) {
+                Some(byte) if idx == first => format!("[{byte:02x}]"),
+                Some(byte) => format!(" {byte:02x} "),
+                None => "    ".to_string(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "with-json")]
+mod json {
+    use serde_json::Value;
+
+    pub trait AsJson {
+        fn as_json(&self) -> Value;
+    }
+
+    impl AsJson for str {
+        #[track_caller]
+        fn as_json(&self) -> Value {
+            match serde_json::from_str(self) {
+                Ok(value) => value,
+                Err(e) => panic!("result is not valid JSON: {e}\n{self}"),
+            }
+        }
+    }
+
+    impl AsJson for String {
+        #[track_caller]
+        fn as_json(&self) -> Value {
+            self.as_str().as_json()
+        }
+    }
+
+    impl AsJson for Value {
+        fn as_json(&self) -> Value {
+            self.clone()
+        }
+    }
+
+    /// Lists every place where `actual` differs from `expected`, e.g. `$.a[1]: expected 2, found 3`.
+    pub fn json_diff(actual: &Value, expected: &Value) -> Vec<String> {
+        let mut diffs = vec![];
+        diff_at("$", actual, expected, &mut diffs);
+        diffs
+    }
+
+    fn diff_at(path: &str, actual: &Value, expected: &Value, diffs: &mut Vec<String>) {
+        match (actual, expected) {
+            (Value::Object(actual), Value::Object(expected)) => {
+                for (key, expected) in expected {
+                    let path = format!("{path}.{key}");
+                    match actual.get(key) {
+                        Some(actual) => diff_at(&path, actual, expected, diffs),
+                        None => diffs.push(format!("{path}: expected {expected}, found nothing")),
+                    }
+                }
+                for (key, actual) in actual {
+                    if !expected.contains_key(key) {
+                        diffs.push(format!("{path}.{key}: expected nothing, found {actual}"));
+                    }
+                }
+            }
+            (Value::Array(actual_items), Value::Array(expected_items))
+                if actual_items.len() == expected_items.len() =>
+            {
+                for (idx, (actual, expected)) in actual_items.iter().zip(expected_items).enumerate()
+                {
+                    diff_at(&format!("{path}[{idx}]"), actual, expected, diffs);
+                }
+            }
+            (actual, expected) if actual != expected => {
+                diffs.push(format!("{path}: expected {expected}, found {actual}"))
+            }
+            _ => {}
+        }
+    }
+}
@@ -90,9 +90,47 @@
 //! # Documentation
 //!
 //! Most up to date documentation is available in our [wiki](https://github.com/frondeus/test-case/wiki).
+pub use test_case_macros::case_set;
+pub use test_case_macros::cases;
+pub use test_case_macros::naming;
+pub use test_case_macros::runner;
 pub use test_case_macros::test_case;
 pub use test_case_macros::test_case as case;
+pub use test_case_macros::test_cases;
+pub use test_case_macros::test_cases_from;
+pub use test_case_macros::test_cases_from_csv;
+pub use test_case_macros::test_cases_from_json;
+pub use test_case_macros::test_cases_from_toml;
+pub use test_case_macros::test_cases_from_yaml;
 pub use test_case_macros::test_matrix;
+pub use test_case_macros::use_case_set;
 
 #[cfg(feature = "with-regex")]
 pub use regex::*;
+
+#[cfg(feature = "with-approx")]
+pub use approx;
+
+#[cfg(feature = "with-json")]
+pub use serde_json;
+
+#[cfg(feature = "with-pretty-assertions")]
+pub use pretty_assertions;
+
+#[cfg(feature = "with-googletest")]
+pub use googletest;
+
+#[cfg(feature = "with-tokio")]
+pub use tokio;
+
+#[cfg(feature = "with-async-std")]
+pub use async_std;
+
+#[cfg(all(feature = "with-wasm-bindgen-test", target_arch = "wasm32"))]
+pub use wasm_bindgen_test;
+
+#[doc(hidden)]
+pub mod __private;
+
+mod test_eq;
+pub use test_eq::TestEq;
@@ -0,0 +1,24 @@
+/// Equality used by `=> expected` and `=> oracle f` assertions.
+///
+/// Every `PartialEq` type implements it already, so only types without `PartialEq` can implement
+/// it, to keep using plain `=> expected` syntax:
+///
+/// ```rust
+/// #[derive(Debug)]
+/// pub struct Meters(pub f64);
+///
+/// impl test_case::TestEq for Meters {
+///     fn test_eq(&self, other: &Self) -> bool {
+///         (self.0 - other.0).abs() < 1e-9
+///     }
+/// }
+/// ```
+pub trait TestEq<Rhs: ?Sized = Self> {
+    fn test_eq(&self, other: &Rhs) -> bool;
+}
+
+impl<T: ?Sized + PartialEq<Rhs>, Rhs: ?Sized> TestEq<Rhs> for T {
+    fn test_eq(&self, other: &Rhs) -> bool {
+        self == other
+    }
+}
@@ -12,3 +12,44 @@ async fn works_seamlessly_with_tokio(arg: i32) -> usize {
 async fn works_seamlessly_with_async_std(arg: i32) -> usize {
         arg as usize
     }
+
+async fn load_golden(name: &str) -> usize {
+    name.len()
+}
+
+#[test_case("a.json" => load_golden("b.json").await)]
+#[test_case("abc.json" => is gt load_golden("a.json").await)]
+#[test_case("a.json" => matches len if len == load_golden("a.json").await)]
+#[test_case("a.json" => (load_golden("b.json").await) ; "parenthesized")]
+#[tokio::test]
+async fn expected_value_can_be_awaited(name: &str) -> usize {
+    load_golden(name).await
+}
+
+#[test_case::test_matrix(["a", "b"] => load_golden("c").await)]
+#[tokio::test]
+async fn matrix_expected_value_can_be_awaited(name: &str) -> usize {
+    load_golden(name).await
+}
+
+struct Pool {
+    connections: usize,
+}
+
+async fn connect() -> Pool {
+    tokio::task::yield_now().await;
+    Pool { connections: 4 }
+}
+
+#[test_case(1 => async_setup = connect 3)]
+#[test_case(4 => async_setup = connect 0)]
+#[tokio::test]
+async fn fixture_is_passed_as_first_argument(pool: Pool, used: usize) -> usize {
+    pool.connections - used
+}
+
+#[test_case::test_matrix([1, 2] => async_setup = connect is gt pool_size)]
+#[tokio::test]
+async fn fixture_is_passed_to_every_matrix_case(pool: Pool, pool_size: usize) -> usize {
+    pool.connections
+}
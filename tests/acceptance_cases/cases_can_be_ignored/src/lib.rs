@@ -21,3 +21,42 @@ fn ignore_void(input: u8) {
 fn descriptions(_: ()) {
     unreachable!()
 }
+
+#[test_case("linux", 1 => ignore["flaky on {os} with {retries} retries"] ())]
+#[test_case("windows", 2 => ignore["flaky on {0}, see {issue}"] ())]
+fn interpolated_reasons(os: &str, retries: u8) {
+    unreachable!("{os} {retries}")
+}
+
+#[test_case::test_matrix(
+    [1, 2],
+    ['a', 'b']
+    => ignore["not supported for {x} and {c}"]
+)]
+fn interpolated_matrix_reasons(x: u8, c: char) {
+    unreachable!("{x} {c}")
+}
+
+#[test_case(1 => ignore_if(cfg(test)) 1)]
+#[test_case(2 => ignore_if(cfg(all(test, not(miri))))["too slow for {x}"] 2)]
+#[test_case(3 => ignore_if(cfg(miri)) 3)]
+#[test_case(4 => skip_under_miri 4)]
+#[test_case(5 => skip_under_miri["calls {x} foreign functions"] 5)]
+#[test_case(6 => ignore_until("2999-01-01", "waiting on upstream fix for {x}") 6)]
+#[test_case(7 => ignore_until("2999-01-01") 7)]
+fn conditionally_ignored(x: u8) -> u8 {
+    x
+}
+
+#[test_case(1 => ignore_until("2020-01-01", "waiting on upstream fix for {x}") 1)]
+#[test_case(2 => ignore_until("2020-01-01") 2)]
+#[test_case(3 => 3)]
+fn expired_ignore(x: u8) -> u8 {
+    x
+}
+
+#[test_case(1 => with(#[ignore = "passed through"], #[allow(unused_mut)]) 1)]
+#[test_case(2 => 2)]
+fn passed_through_attributes(x: u8) -> u8 {
+    x
+}
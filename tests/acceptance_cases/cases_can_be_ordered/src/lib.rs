@@ -0,0 +1,21 @@
+#![cfg(test)]
+use std::sync::Mutex;
+use test_case::{test_case, test_matrix};
+
+static LOG: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+fn record(name: &'static str) -> Vec<&'static str> {
+    let mut log = LOG.lock().unwrap();
+    log.push(name);
+    log.clone()
+}
+
+#[test_case("expensive" => vec!["smoke", "sanity", "expensive"])]
+#[test_case("sanity" => order = 2 vec!["smoke", "sanity"])]
+#[test_case("smoke" => order 1 vec!["smoke"] ; "smoke test")]
+fn run(name: &'static str) -> Vec<&'static str> {
+    record(name)
+}
+
+#[test_matrix([1, 2] => order = 10)]
+fn matrix(_: u32) {}
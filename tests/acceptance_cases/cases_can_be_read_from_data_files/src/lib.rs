@@ -0,0 +1,100 @@
+#![cfg(test)]
+use std::path::{Path, PathBuf};
+use test_case::{
+    test_case, test_cases_from_csv, test_cases_from_json, test_cases_from_toml,
+    test_cases_from_yaml,
+};
+
+#[test_cases_from_csv("tests/data/parse.csv")]
+fn parse(input: &str) -> Option<u8> {
+    input.parse().ok()
+}
+
+#[test_cases_from_csv("tests/data/add.csv")]
+#[test_case(0, 0 => 0)]
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[test_case("hello" => "HELLO")]
+#[test_cases_from_csv("tests/data/upper.csv")]
+fn upper(text: &str) -> String {
+    text.to_uppercase()
+}
+
+#[test_cases_from_csv("tests/data/shout.csv")]
+fn shout(name: String, times: usize) -> String {
+    name.to_uppercase().repeat(times)
+}
+
+#[test_cases_from_json("tests/data/greet.json")]
+fn greet(name: &str, excited: bool) -> String {
+    format!("Hello, {name}{}", if excited { "!" } else { "." })
+}
+
+#[test_cases_from_json("tests/data/greet.json")]
+#[test_cases_from_yaml("tests/data/greet.yaml")]
+fn greet_owned(name: String, excited: bool) -> String {
+    greet(&name, excited)
+}
+
+#[derive(Debug, serde::Deserialize)]
+enum Shape {
+    Rect { width: u32, height: u32 },
+    Square(u32),
+}
+
+#[test_cases_from_json("tests/data/area.json")]
+fn area(shape: Shape) -> u32 {
+    match shape {
+        Shape::Rect { width, height } => width * height,
+        Shape::Square(side) => side * side,
+    }
+}
+
+#[test_cases_from_yaml("tests/data/checksum.yaml")]
+fn checksum(frame: Vec<u8>) -> u8 {
+    frame.iter().fold(0, |sum, byte| sum.wrapping_add(*byte))
+}
+
+#[derive(Debug)]
+struct Point<T> {
+    x: T,
+    y: T,
+}
+
+#[test_cases_from_toml("tests/data/route.toml")]
+fn route(from: Point<i32>, to: &Point<i32>) -> i32 {
+    let (dx, dy) = (f64::from(to.x - from.x), f64::from(to.y - from.y));
+    dx.hypot(dy) as i32
+}
+
+#[derive(Debug, PartialEq)]
+struct Scaled {
+    values: Vec<i32>,
+    label: String,
+}
+
+#[test_cases_from_toml("tests/data/scale.toml")]
+fn scale(factor: i32, values: Vec<i32>) -> Scaled {
+    let label = match factor {
+        0 => "none",
+        _ => "doubled",
+    };
+    Scaled {
+        values: values.iter().map(|value| value * factor).collect(),
+        label: label.to_string(),
+    }
+}
+
+#[test_case(files("tests/fixtures/*.txt"))]
+fn fixture_is_lowercase(path: &Path, contents: &str) {
+    assert_eq!(path.extension().unwrap(), "txt");
+    assert_eq!(contents, contents.to_lowercase());
+}
+
+#[test_case(files("tests/fixtures/**/*.txt") => true ; "golden")]
+#[test_case(PathBuf::from("Cargo.toml") => true)]
+fn fixture_exists(path: PathBuf) -> bool {
+    path.is_file()
+}
@@ -0,0 +1,52 @@
+#![cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use test_case::test_case;
+
+static FLAKY_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[test_case(2 => retries 3 "ok")]
+fn flaky(failures: usize) -> &'static str {
+    let call = FLAKY_CALLS.fetch_add(1, Ordering::SeqCst);
+    if call < failures {
+        panic!("connection refused on call {call}")
+    }
+    "ok"
+}
+
+static ASSIGNED_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[test_case(1 => retries = 1 "ok")]
+fn retries_assigned(failures: usize) -> &'static str {
+    let call = ASSIGNED_CALLS.fetch_add(1, Ordering::SeqCst);
+    if call < failures {
+        panic!("connection refused on call {call}")
+    }
+    "ok"
+}
+
+static ALWAYS_FAILING_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[test_case(() => retries 2 ; "reports every attempt")]
+fn always_failing(_: ()) {
+    let call = ALWAYS_FAILING_CALLS.fetch_add(1, Ordering::SeqCst);
+    panic!("failure on call {call}")
+}
+
+static ASSERTION_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[test_case(1 => retries 1 is gt 0)]
+fn retries_assertion(x: usize) -> usize {
+    ASSERTION_CALLS.fetch_add(x, Ordering::SeqCst)
+}
+
+static RESULT_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[test_case(3 => retries 3 timeout 1s ; "result errors are retried")]
+fn result(failures: usize) -> Result<(), String> {
+    let call = RESULT_CALLS.fetch_add(1, Ordering::SeqCst);
+    if call < failures {
+        Err(format!("error on call {call}"))
+    } else {
+        Ok(())
+    }
+}
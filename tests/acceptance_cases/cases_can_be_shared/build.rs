@@ -0,0 +1,11 @@
+use std::fmt::Write;
+
+// Generates cases included with `#[test_cases_from(include = ...)]`
+fn main() {
+    let mut cases = String::new();
+    for n in 1..=3u32 {
+        writeln!(cases, "#[test_case({n} => {} ; \"generated {n}\")]", n * n).unwrap();
+    }
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(format!("{out_dir}/generated_cases.rs"), cases).unwrap();
+}
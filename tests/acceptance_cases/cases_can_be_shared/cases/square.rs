@@ -0,0 +1,2 @@
+#[test_case(0 => 0)]
+#[test_case(5 => 25 ; "five")]
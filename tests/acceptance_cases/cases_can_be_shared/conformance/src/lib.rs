@@ -0,0 +1,10 @@
+use test_case::case_set;
+
+case_set! {
+    /// Cases every parity check has to pass.
+    pub parity_conformance => {
+        (0 => true),
+        (1 => false),
+        (u64::MAX => false ; "max"),
+    }
+}
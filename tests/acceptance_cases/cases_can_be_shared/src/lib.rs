@@ -0,0 +1,113 @@
+#![cfg(test)]
+use test_case::{case_set, cases, test_case, test_cases, test_cases_from, use_case_set};
+
+#[cases]
+const SUMS: [(i32, i32, i32); 3] = [(1, 2, 3), (4, 5, 9), (-1, 1, 0)];
+
+#[cases]
+const WORDS: &[&str] = &["alpha", "beta"];
+
+#[test_cases(from = SUMS)]
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[test_case(0, 0 => 0)]
+#[test_cases(from = SUMS)]
+fn add_wrapping(a: i32, b: i32) -> i32 {
+    a.wrapping_add(b)
+}
+
+#[test_cases(from = SUMS)]
+fn sum_is_consistent(a: i32, b: i32, sum: i32) {
+    assert_eq!(sum - a, b);
+}
+
+#[test_cases(from = WORDS)]
+fn is_lowercase(word: &str) {
+    assert_eq!(word, word.to_lowercase());
+}
+
+#[test_cases(from = [(2, 3, 6), (0, 7, 0)])]
+fn mul(a: i32, b: i32) -> i32 {
+    a * b
+}
+
+#[test]
+fn const_is_kept() {
+    assert_eq!(SUMS.len(), 3);
+    assert_eq!(WORDS, ["alpha", "beta"]);
+}
+
+macro_rules! squares {
+    (($($value:literal),*) $($item:tt)*) => {
+        $(#[test_case($value => $value * $value)])*
+        $($item)*
+    };
+}
+
+macro_rules! edge_cases {
+    (() $($item:tt)*) => {
+        #[test_case(0 => 0 ; "zero")]
+        #[test_case(i32::MAX => panics)]
+        $($item)*
+    };
+}
+
+#[test_case(expand!(squares!(1, 2, 3)))]
+fn square(value: i32) -> i32 {
+    value * value
+}
+
+#[test_case(-2 => 4)]
+#[test_case(expand!(squares!(5)))]
+#[test_case(expand!(edge_cases!()))]
+fn checked_square(value: i32) -> i32 {
+    value.checked_mul(value).unwrap()
+}
+
+case_set! {
+    sums => {
+        (1, 2 => 3),
+        (4, 5 => 9),
+        (i64::MAX, 1 => panics "overflow"),
+    },
+    identities => { (0, 7 => 7), (7, 0 => 7) }
+}
+
+#[use_case_set(sums)]
+#[use_case_set(identities)]
+fn fast_sum(a: i64, b: i64) -> i64 {
+    a.checked_add(b).expect("overflow")
+}
+
+#[test_case(-1, 1 => 0)]
+#[use_case_set(sums)]
+fn slow_sum(a: i64, b: i64) -> i64 {
+    (0..b).fold(a, |sum, _| sum.checked_add(1).expect("overflow"))
+}
+
+mod shared {
+    use test_case::case_set;
+
+    case_set! {
+        pub(crate) small_numbers => { (2 => true), (3 => false) }
+    }
+}
+
+#[use_case_set(cases_can_be_shared_conformance::parity_conformance)]
+#[use_case_set(crate::shared::small_numbers)]
+fn is_even_by_bit(n: u64) -> bool {
+    n & 1 == 0
+}
+
+#[test_cases_from(include = concat!(env!("OUT_DIR"), "/generated_cases.rs"))]
+fn generated_square(n: u32) -> u32 {
+    n * n
+}
+
+#[test_case(4 => 16)]
+#[test_cases_from(include = "cases/square.rs")]
+fn square_by_sum(n: u32) -> u32 {
+    (0..n).map(|_| n).sum()
+}
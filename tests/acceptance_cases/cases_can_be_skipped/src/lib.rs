@@ -0,0 +1,19 @@
+#![cfg(test)]
+use test_case::test_case;
+
+#[test_case("example.com" => skip_if_env("TEST_CASE_OFFLINE") 200)]
+#[test_case("example.org" => skip_if_env("TEST_CASE_NOT_SET") 200)]
+fn fetch(host: &str) -> u16 {
+    panic!("no network access to {host}")
+}
+
+#[test_case(1 => skip_if_env("TEST_CASE_NOT_SET") 1)]
+#[test_case(2 => skip_if_env("TEST_CASE_OFFLINE") serial 3)]
+fn runs_when_not_set(x: u8) -> u8 {
+    x
+}
+
+#[test_case(1 => skip_if_env("TEST_CASE_OFFLINE") ; "result is skipped")]
+fn result(_: u8) -> Result<(), String> {
+    Err("should be skipped".to_string())
+}
@@ -0,0 +1,15 @@
+#![cfg(test)]
+use test_case::test_case;
+
+#[test_case(1 => tags = ["fast"] 1)]
+#[test_case(2 => tags = ["slow"] 0 ; "not selected")]
+#[test_case(3 => tags["fast", "db"] 0 ; "excluded")]
+#[test_case(4 => 4 ; "untagged")]
+fn selected_by_tags(x: u8) -> u8 {
+    x
+}
+
+#[test_case(() => tags["slow"] ; "result is skipped")]
+fn result(_: ()) -> Result<(), String> {
+    Err("should be skipped".to_string())
+}
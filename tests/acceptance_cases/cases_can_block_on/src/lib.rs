@@ -0,0 +1,58 @@
+#![cfg(test)]
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+use test_case::{runner, test_case, test_matrix};
+
+fn busy_block_on<F: Future>(future: F) -> F::Output {
+    fn raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            raw_waker()
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+
+    let waker = unsafe { Waker::from_raw(raw_waker()) };
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+async fn double(x: u32) -> u32 {
+    futures_lite::future::yield_now().await;
+    x * 2
+}
+
+#[test_case(1 => 2)]
+#[test_case(2 => 4)]
+#[test_case::runner(block_on = futures_lite::future::block_on)]
+async fn with_futures_lite(x: u32) -> u32 {
+    double(x).await
+}
+
+#[runner(block_on = busy_block_on)]
+#[test_matrix([1, 2], [3, 4])]
+async fn with_bespoke_executor(x: u32, y: u32) {
+    assert!(double(x).await < y * 2)
+}
+
+#[test_case(0 ; "zero")]
+#[test_case(1 => panics "odd")]
+#[runner(block_on = busy_block_on)]
+async fn panicking(x: u32) {
+    futures_lite::future::yield_now().await;
+    assert!(x % 2 == 0, "odd")
+}
+
+#[test_case(10 => timeout 1s 20)]
+#[test_case(1 => retries 1 2)]
+#[runner(block_on = futures_lite::future::block_on)]
+async fn with_sync_modifiers(x: u32) -> u32 {
+    double(x).await
+}
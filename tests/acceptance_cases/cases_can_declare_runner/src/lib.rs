@@ -0,0 +1,26 @@
+#![cfg(test)]
+use test_case::{runner, test_case, test_matrix};
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+fn flavor() -> RuntimeFlavor {
+    Handle::current().runtime_flavor()
+}
+
+#[test_case(1 => RuntimeFlavor::MultiThread)]
+#[test_case(2 => RuntimeFlavor::MultiThread)]
+#[test_case::runner(tokio::test(flavor = "multi_thread", worker_threads = 2))]
+async fn multi_thread(_: u8) -> RuntimeFlavor {
+    flavor()
+}
+
+#[runner(tokio::test)]
+#[test_matrix([1, 2])]
+async fn declared_above_cases(_: u8) {
+    assert_eq!(flavor(), RuntimeFlavor::CurrentThread)
+}
+
+#[test_case(1 => 1)]
+#[runner(test)]
+fn sync_runner(x: u8) -> u8 {
+    x
+}
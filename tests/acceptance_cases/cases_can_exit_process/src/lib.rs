@@ -0,0 +1,22 @@
+#![cfg(test)]
+use test_case::test_case;
+
+#[test_case(2 => exits 2)]
+#[test_case(0 => exits 0)]
+#[test_case(3 => exits 4)]
+fn exiting(code: i32) {
+    std::process::exit(code)
+}
+
+#[test_case(true => aborts)]
+#[test_case(false => aborts)]
+fn aborting(abort: bool) {
+    if abort {
+        std::process::abort()
+    }
+}
+
+#[test_case(() => exits 1)]
+fn panicking_is_not_exiting(_: ()) {
+    panic!("this is not an exit")
+}
@@ -0,0 +1,17 @@
+#![cfg(test)]
+use test_case::test_case;
+
+#[test_case(1 => 1)]
+#[test_case(String::new() => compile_fail "the trait bound `String: Copy` is not satisfied")]
+#[test_case(vec![1, 2] => compile_fail ; "vector is not copy")]
+#[test_case(2u8 => compile_fail ; "fails because it compiles")]
+#[test_case(Box::new(1) => compile_fail "mismatched types" ; "fails because of other error")]
+fn requires_copy<T: Copy>(value: T) -> T {
+    value
+}
+
+#[test_case(200 => Some(200))]
+#[test_case("200" => compile_fail "mismatched types" ; "uses prelude of the crate edition")]
+fn narrow(value: u16) -> Option<u8> {
+    u8::try_from(value).ok()
+}
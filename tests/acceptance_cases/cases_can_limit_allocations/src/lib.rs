@@ -0,0 +1,29 @@
+#![cfg(test)]
+use test_case::{test_case, test_matrix};
+
+fn buffer(len: usize) -> usize {
+    vec![0u8; len].len()
+}
+
+#[test_case(100 => max_alloc = 1KB 100)]
+#[test_case(1_000 => max_alloc = 1KiB 1_000)]
+#[test_case(2_000_000 => max_alloc = 1MB 2_000_000 ; "over budget")]
+#[test_case(3_000_000 => max_alloc 2MiB panics "out of memory" ; "panic takes precedence")]
+fn allocates(len: usize) -> usize {
+    if len > 2_500_000 {
+        panic!("out of memory")
+    }
+    buffer(len)
+}
+
+#[test_matrix([1_000, 10_000, 100_000] => max_alloc = 20KB)]
+fn sizes(len: usize) {
+    buffer(len);
+}
+
+#[test_case(1_000 => max_alloc = 1KB 1_000)]
+fn reallocates(len: usize) -> usize {
+    let mut v = Vec::with_capacity(len / 2);
+    v.extend(std::iter::repeat(0u8).take(len));
+    v.len()
+}
@@ -20,6 +20,65 @@ fn panicking(_: ()) {
     panic!("It has to panic")
 }
 
+#[test_case(() => panics "has to")]
+fn panicking_substring(_: ()) {
+    panic!("It has to panic")
+}
+
+#[test_case(1 => panics starts_with "Error code" and ends_with "1")]
+#[test_case(2 => panics eq_ignore_case "error CODE 2")]
+#[test_case(3 => panics eq "Error code 4")]
+#[test_case(0 => panics eq "Error code 0")]
+fn panicking_message(code: u32) {
+    if code > 0 {
+        panic!("Error code {code}")
+    }
+}
+
+#[test_case(() => panics eq "static message")]
+fn panicking_static_message(_: ()) {
+    panic!("static message")
+}
+
+#[derive(Debug)]
+pub struct MyPanicPayload {
+    pub code: u32,
+    pub reason: &'static str,
+}
+
+#[derive(Debug)]
+pub enum PanicKind {
+    Fatal(u32),
+    Recoverable,
+}
+
+#[test_case(42 => panics with MyPanicPayload { code: 42, .. })]
+#[test_case(7 => panics with MyPanicPayload { code, .. } if *code < 10)]
+#[test_case(8 => panics with MyPanicPayload { code: 42, .. })]
+#[test_case(9 => panics with PanicKind::Recoverable)]
+fn panicking_with_payload(code: u32) {
+    std::panic::panic_any(MyPanicPayload {
+        code,
+        reason: "test",
+    })
+}
+
+#[test_case(1 => panics with PanicKind::Fatal(n) if *n == 1)]
+#[test_case(0 => panics with PanicKind::Recoverable)]
+fn panicking_with_enum_payload(code: u32) {
+    if code > 0 {
+        std::panic::panic_any(PanicKind::Fatal(code))
+    }
+    std::panic::panic_any(PanicKind::Recoverable)
+}
+
+#[test_case(1, 2 => does_not_panic)]
+#[test_case(1, 0 => does_not_panic)]
+#[test_case(4, 2 => does_not_panic ; "smoke test")]
+fn not_panicking(x: u32, y: u32) -> u32 {
+    x / y
+}
+
 #[test_case(() => panics)]
 fn panics_without_value(_: ()) {
     panic!("Message doesn't matter")
@@ -34,3 +93,26 @@ assertion `left == right` failed
 fn result_which_panics(x: u32, y: u32) -> u32 {
     x + y
 }
+
+#[test_case(1 => with(#[should_panic(expected = "odd input")]) ; "odd")]
+#[test_case(2 ; "even")]
+fn per_case_attributes(x: u32) {
+    if x % 2 == 1 {
+        panic!("odd input: {x}")
+    }
+}
+
+#[test_case(2, 2 => xfail 5 ; "known bug")]
+#[test_case(2, 3 => xfail["issue 42"] 5 ; "fixed bug")]
+#[test_case(0, 0 => xfail 0 ; "panicking")]
+fn expected_failures(x: u32, y: u32) -> u32 {
+    if x == 0 {
+        panic!("zero")
+    }
+    x + y
+}
+
+#[test_case(1 => xfail matches Ok(_) ; "result error")]
+fn expected_failure_result(_: u8) -> Result<(), String> {
+    Err("known issue".to_string())
+}
@@ -0,0 +1,22 @@
+#![cfg(test)]
+use test_case::{test_case, test_matrix};
+
+#[test_case(100i32 => 100usize)]
+#[test_case(-1i32 => panics "negative")]
+async fn runs_on_async_std(arg: i32) -> usize {
+    test_case::async_std::task::yield_now().await;
+    usize::try_from(arg).expect("negative")
+}
+
+#[test_matrix([1, 2], [3, 4])]
+async fn matrix(x: u8, y: u8) {
+    test_case::async_std::task::yield_now().await;
+    assert!(x < y)
+}
+
+#[test_case(10 => timeout 2s 10)]
+#[test_case(5000 => timeout 100ms panics "test case timed out after 100ms")]
+async fn times_out(millis: u64) -> u64 {
+    test_case::async_std::task::sleep(std::time::Duration::from_millis(millis)).await;
+    millis
+}
@@ -0,0 +1,60 @@
+#![cfg(test)]
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+use tokio::runtime::{Handle, RuntimeFlavor};
+use test_case::{test_case, test_matrix};
+
+#[test_case(100i32 => 100usize)]
+#[test_case(-1i32 => panics "negative")]
+async fn runs_on_tokio(arg: i32) -> usize {
+    tokio::time::sleep(Duration::from_millis(1)).await;
+    usize::try_from(arg).expect("negative")
+}
+
+#[test_matrix([1, 2], [3, 4])]
+async fn matrix(x: u8, y: u8) {
+    tokio::task::yield_now().await;
+    assert!(x < y)
+}
+
+#[test_case(100i32 => 100usize)]
+#[tokio::test(flavor = "current_thread")]
+async fn explicit_attribute_is_kept(arg: i32) -> usize {
+    arg as usize
+}
+
+#[test_case(1 => timeout 2s 1)]
+#[test_case(3600 => timeout 1s panics "test case timed out after 1s")]
+#[test_case::runner(tokio::test(start_paused = true))]
+async fn times_out_with_paused_time(secs: u64) -> u64 {
+    tokio::time::sleep(Duration::from_secs(secs)).await;
+    secs
+}
+
+#[test_case(10 => timeout 2s 10)]
+#[test_case(5000 => timeout 100ms panics "test case timed out after 100ms")]
+async fn times_out_on_current_thread(millis: u64) -> u64 {
+    tokio::time::sleep(Duration::from_millis(millis)).await;
+    millis
+}
+
+#[test_case(3 => local_set 6)]
+#[test_case(0 => local_set timeout 1s 0)]
+async fn holds_rc_across_await(x: u32) -> u32 {
+    let total = Rc::new(RefCell::new(x));
+    let task = tokio::task::spawn_local({
+        let total = Rc::clone(&total);
+        async move { *total.borrow_mut() += x }
+    });
+    tokio::task::yield_now().await;
+    task.await.expect("local task");
+    let total = *total.borrow();
+    total
+}
+
+#[test_case(() => rt(flavor = "multi_thread", worker_threads = 2) RuntimeFlavor::MultiThread)]
+#[test_case(() => RuntimeFlavor::CurrentThread ; "default runtime")]
+async fn runtime_can_be_configured_per_case(_: ()) -> RuntimeFlavor {
+    Handle::current().runtime_flavor()
+}
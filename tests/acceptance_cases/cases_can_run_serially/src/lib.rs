@@ -0,0 +1,43 @@
+#![cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::sleep;
+use std::time::Duration;
+use test_case::test_case;
+
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+fn exclusive_section(millis: u64) -> usize {
+    let running = RUNNING.fetch_add(1, Ordering::SeqCst) + 1;
+    sleep(Duration::from_millis(millis));
+    RUNNING.fetch_sub(1, Ordering::SeqCst);
+    running
+}
+
+#[test_case(10 => serial 1)]
+#[test_case(20 => serial 1)]
+#[test_case(30 => serial 1)]
+fn default_group(millis: u64) -> usize {
+    exclusive_section(millis)
+}
+
+static RUNNING_IN_GROUP: AtomicUsize = AtomicUsize::new(0);
+
+#[test_case(10 => serial("env") 1)]
+#[test_case(20 => serial("env") 1)]
+#[test_case(30 => serial["env"] 1 ; "bracketed group")]
+fn named_group(millis: u64) -> usize {
+    let running = RUNNING_IN_GROUP.fetch_add(1, Ordering::SeqCst) + 1;
+    sleep(Duration::from_millis(millis));
+    RUNNING_IN_GROUP.fetch_sub(1, Ordering::SeqCst);
+    running
+}
+
+#[test_case(true => serial panics "poisons the lock")]
+#[test_case(false => serial 1 ; "runs after poisoned lock")]
+fn poisoning(fail: bool) -> usize {
+    let running = exclusive_section(5);
+    if fail {
+        panic!("poisons the lock")
+    }
+    running
+}
@@ -0,0 +1,19 @@
+#![cfg(test)]
+use test_case::{test_case, test_matrix};
+
+#[test_case(1, 2 => 3)]
+#[test_case(2, 2 => 4)]
+fn sync_cases(x: u8, y: u8) -> u8 {
+    x + y
+}
+
+#[test_matrix([1, 2], [3, 4])]
+fn sync_matrix(x: u8, y: u8) {
+    assert!(x < y)
+}
+
+#[test_case(1 => 1)]
+#[cfg_attr(not(target_arch = "wasm32"), tokio::test)]
+async fn async_cases(x: u8) -> u8 {
+    x
+}
@@ -0,0 +1,36 @@
+#![cfg(test)]
+use std::thread::sleep;
+use std::time::Duration;
+use test_case::test_case;
+
+#[test_case(10 => timeout 2s 10)]
+#[test_case(2000 => timeout 100ms 2000)]
+#[test_case(20 => timeout 2s 30)]
+#[test_case(30 => timeout = 2s 30)]
+fn sleeping(millis: u64) -> u64 {
+    sleep(Duration::from_millis(millis));
+    millis
+}
+
+#[test_case(0 => timeout 1s panics "division by zero")]
+#[test_case(1 => timeout 1s is gt 0)]
+fn dividing(divisor: u32) -> u32 {
+    if divisor == 0 {
+        panic!("division by zero")
+    }
+    10 / divisor
+}
+
+#[test_case(5 => ignore timeout 1s 5)]
+fn ignored_with_timeout(millis: u64) -> u64 {
+    millis
+}
+
+#[test_case(1 => timeout 1s ; "returns result")]
+fn result(x: u8) -> Result<(), String> {
+    if x == 1 {
+        Ok(())
+    } else {
+        Err("not one".to_string())
+    }
+}
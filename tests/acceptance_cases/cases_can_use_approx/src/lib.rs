@@ -0,0 +1,41 @@
+#![cfg(test)]
+
+use test_case::test_case;
+
+#[test_case(0.1 => is abs_diff_eq 0.3)]
+#[test_case(0.1 => is abs_diff_eq 0.3 epsilon 0.01)]
+#[test_case(0.1 => is abs_diff_eq 0.2)]
+#[test_case(0.1 => is relative_eq 0.3)]
+#[test_case(0.1 => is relative_eq 0.31 epsilon 0.001 max_relative 0.05)]
+#[test_case(0.1 => is relative_eq 0.31 max_relative 0.01)]
+#[test_case(0.1 => is ulps_eq 0.3)]
+#[test_case(0.1 => is ulps_eq 0.3 max_ulps 4)]
+#[test_case(0.1 => is ulps_eq 0.3, 8)]
+#[test_case(0.1 => is not ulps_eq 0.31)]
+fn approx_f64(input: f64) -> f64 {
+    input * 3.0
+}
+
+#[derive(Debug, PartialEq)]
+struct Vec2 {
+    x: f32,
+    y: f32,
+}
+
+impl test_case::approx::AbsDiffEq for Vec2 {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+    }
+}
+
+#[test_case(0.1, 0.2 => it abs_diff_eq Vec2 { x: 0.3, y: 0.6 })]
+#[test_case(0.1, 0.2 => it abs_diff_eq Vec2 { x: 0.3, y: 0.61 } epsilon 0.001)]
+fn approx_custom_type(x: f32, y: f32) -> Vec2 {
+    Vec2 { x: x * 3.0, y: y * 3.0 }
+}
@@ -0,0 +1,23 @@
+#![cfg(test)]
+
+use test_case::test_case;
+
+#[test_case(2 => gtest eq(4))]
+#[test_case(3 => gtest eq(4))]
+#[test_case(5 => gtest all!(gt(5), lt(20)))]
+#[test_case(0 => gtest anything())]
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+#[test_case("world" => gtest contains_substring("hello"))]
+#[test_case("rust" => gtest ends_with("rust!"))]
+fn greeting(name: &str) -> String {
+    format!("hello {name}")
+}
+
+#[test_case(3 => gtest elements_are![eq(&0), eq(&1), eq(&2)])]
+#[test_case(2 => gtest contains(eq(&5)))]
+fn range(n: u8) -> Vec<u8> {
+    (0..n).collect()
+}
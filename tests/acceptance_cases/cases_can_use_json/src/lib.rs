@@ -0,0 +1,37 @@
+#![cfg(test)]
+
+use test_case::test_case;
+
+#[test_case(r#"{"a": 1, "b": [2, 3]}"# => json!({"a": 1, "b": [2, 3]}))]
+#[test_case(r#"{ "b": [2, 3], "a": 1 }"# => json!({"a": 1, "b": [2, 3]}) ; "ignores key order")]
+#[test_case(r#"{"a": 2, "b": [2, 4], "c": null}"# => json!({"a": 1, "b": [2, 3]}))]
+#[test_case(r#"{"a": 1}"# => json!({"a": 1, "b": [2, 3]}))]
+#[test_case(r#"{"a": 1"# => json!({"a": 1}) ; "invalid json")]
+fn json_str(body: &str) -> &str {
+    body
+}
+
+#[test_case(1 => json!({"id": 1, "tags": []}))]
+#[test_case(2 => is json!({"id": 2, "tags": []}) or json!({"id": 2, "tags": ["admin"]}))]
+fn json_string(id: u32) -> String {
+    format!(r#"{{"id": {id}, "tags": []}}"#)
+}
+
+#[test_case(3 => json!([3, "three"]))]
+fn json_value(n: u32) -> test_case::serde_json::Value {
+    test_case::serde_json::json!([n, "three"])
+}
+
+#[derive(serde::Serialize)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+    pub label: Option<String>,
+}
+
+#[test_case(1, 2 => serde_eq Point { x: 1, y: 2, label: None })]
+#[test_case(1, 3 => serde_eq Point { x: 1, y: 2, label: Some("a".into()) })]
+#[test_case(2, 2 => is serde_eq test_case::serde_json::json!({"x": 2, "y": 2, "label": null}))]
+fn serde_point(x: i32, y: i32) -> Point {
+    Point { x, y, label: None }
+}
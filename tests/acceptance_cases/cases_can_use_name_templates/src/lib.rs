@@ -0,0 +1,236 @@
+#![cfg(test)]
+use test_case::{naming, test_case, test_matrix};
+
+#[test_case(2, 3 => 5)]
+#[test_case(-1, 1 => 0)]
+#[test_case(0, 0 => 0 ; "description takes precedence")]
+#[test_case::naming(name = "{fn}_{arg0}_{arg1}")]
+fn add(x: i32, y: i32) -> i32 {
+    x + y
+}
+
+#[naming(name = "parse {input}")]
+#[test_case("forty two" => None)]
+#[test_case("42" => Some(42))]
+fn parse(input: &str) -> Option<u32> {
+    input.parse().ok()
+}
+
+#[test_matrix(["a", "b"], [1, 2])]
+#[naming(name = "repeat {0} {times} times")]
+fn repeat(text: &str, times: usize) {
+    assert_eq!(text.repeat(times).len(), times)
+}
+
+#[test_case(1 => order = 1 1)]
+#[test_case(2 => 2)]
+#[naming(name = "identity_{arg0}")]
+fn ordered(x: u8) -> u8 {
+    x
+}
+
+#[test_case(2, 3 => 6)]
+#[test_case(4, 0 => panics "zero")]
+#[naming(name = "given_{arg0}_when_multiplied_by_{arg1}_then_{expected}")]
+fn multiply(x: u8, y: u8) -> u8 {
+    if y == 0 {
+        panic!("zero")
+    }
+    x * y
+}
+
+#[test_matrix(["a", "bb", "ccc"], ["x", "yy", "zzz", "wwww"])]
+#[naming(name = "case_{case}")]
+fn numbered(left: &str, right: &str) {
+    assert!(!format!("{left}{right}").is_empty())
+}
+
+#[test_case("żółć" => 4)]
+#[test_case("Straße" => 6)]
+#[test_case("→" => 1 ; "strzałka →")]
+#[naming(unicode = "transliterate")]
+fn transliterated(word: &str) -> usize {
+    word.chars().count()
+}
+
+#[test_case("→" => 1)]
+#[test_case("⟶" => 1)]
+#[naming(name = "arrow {0}", unicode = "hex")]
+fn hex_escaped(arrow: &str) -> usize {
+    arrow.chars().count()
+}
+
+#[derive(Debug, PartialEq)]
+enum HttpStatus {
+    NotFound,
+    ServerError,
+}
+
+#[test_case(HttpStatus::NotFound => 404)]
+#[test_case(HttpStatus::ServerError => 500)]
+#[naming(casing = "snake_case")]
+fn snake_cased(status: HttpStatus) -> u16 {
+    match status {
+        HttpStatus::NotFound => 404,
+        HttpStatus::ServerError => 500,
+    }
+}
+
+#[test_case(HttpStatus::NotFound => "NotFound")]
+#[naming(casing = "preserve")]
+fn case_preserved(status: HttpStatus) -> String {
+    format!("{status:?}")
+}
+
+#[derive(Debug)]
+struct Config {
+    name: &'static str,
+    retries: u8,
+    timeout_ms: u32,
+}
+
+#[test_case(Config { name: "primary", retries: 3, timeout_ms: 1000 } => true)]
+#[test_case(Config { name: "secondary", retries: 3, timeout_ms: 1000 } => true)]
+#[test_case(Config { name: "x", retries: 0, timeout_ms: 0 } => false)]
+#[naming(max_len = 32)]
+fn truncated(config: Config) -> bool {
+    !config.name.is_empty() && config.retries > 0 && config.timeout_ms > 0
+}
+
+#[test_case(84 => 42)]
+#[test_case(84 => ignore 42)]
+#[test_case(0 => panics)]
+#[test_case(1 => panics "odd")]
+#[test_case(2 => matches 1)]
+#[test_case(4 => is gt 1)]
+#[naming(expected = "short")]
+fn halve(x: u8) -> u8 {
+    assert!(x != 0);
+    assert!(x % 2 == 0, "odd");
+    x / 2
+}
+
+#[test_case("a--b" => 2 ; " splits on \"--\" ")]
+#[test_case("ą--ę" => 2)]
+#[test_case("" => 1)]
+#[naming(nextest_safe = true)]
+fn nextest_safe(text: &str) -> usize {
+    text.split("--").count()
+}
+
+#[test_case("42", 42 ; "parses {arg0} as {arg1}")]
+#[test_case("7", 7 ; "parses {input} as {value}")]
+#[test_matrix(["1", "2"], [0] ; "{input} differs from {value}")]
+fn described_with_arguments(input: &str, value: u8) {
+    assert_eq!(input.parse::<u8>().unwrap() == value, input != "1" && input != "2")
+}
+
+mod tokenize {
+    pub fn words(text: &str) -> usize {
+        text.split_whitespace().count()
+    }
+}
+
+#[test_case("a b" => 2)]
+#[test_case("" => 0)]
+#[naming(module = "tokenize_cases")]
+fn tokenize(text: &str) -> usize {
+    tokenize::words(text)
+}
+
+mod flat {
+    use test_case::test_case;
+
+    #[test_case(2 => 4)]
+    #[test_case(3 => 9 ; "three")]
+    #[test_case::naming(flat = true)]
+    fn square(x: u32) -> u32 {
+        x * x
+    }
+
+    #[test_case(2 => 8)]
+    #[test_case::naming(flat = true, name = "of_{x}")]
+    fn cube(x: u32) -> u32 {
+        x * x * x
+    }
+}
+
+#[test_matrix([4, 8], [2, 3], [true, false])]
+#[naming(nested = true)]
+fn area(width: u32, height: u32, even: bool) {
+    assert_eq!((width * height) % 2 == 0, even || height == 2 || width % 2 == 0)
+}
+
+mod visible {
+    use test_case::test_case;
+
+    #[test_case(2 => 1)]
+    #[test_case::naming(vis = "pub(crate)")]
+    pub fn half(x: u32) -> u32 {
+        x / 2
+    }
+}
+
+#[test]
+fn calls_visible_case() {
+    visible::half::_2_expects_1()
+}
+
+mod explicit_imports {
+    use test_case::test_case;
+
+    const LIMIT: u32 = 10;
+
+    #[test_case(LIMIT => true)]
+    #[test_case::naming(imports = ["super::LIMIT"])]
+    fn within_limit(x: u32) -> bool {
+        x <= LIMIT
+    }
+
+    #[test_case(1 => 2)]
+    #[test_case::naming(imports = [])]
+    fn double(x: u32) -> u32 {
+        x * 2
+    }
+}
+
+#[test_case(3 => 1)]
+#[naming(cfg = any(test, doc))]
+fn third(x: u32) -> u32 {
+    x / 3
+}
+
+#[test_case(3 => 0)]
+#[naming(cfg = all(test, target_os = "none"))]
+fn never_compiled(x: u32) -> u32 {
+    x
+}
+
+#[test_case("" => 0 ; "edge cases :: empty")]
+#[test_case(" " => 0 ; "edge cases :: blank")]
+#[test_case("a b" => 2 ; "words :: two")]
+#[test_case("a" => 1 ; "single word")]
+#[naming(groups = true)]
+fn count_words(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+#[test_case(4 => 2)]
+#[naming(gate_original = true)]
+fn test_only_helper(x: u32) -> u32 {
+    x / 2
+}
+
+#[test_case("match" ; "match")]
+#[test_case("self" ; "self")]
+#[test_case("type" ; "type :: true")]
+#[naming(groups = true)]
+fn keywords(word: &str) {
+    assert!(!word.is_empty())
+}
+
+#[test_case("match" ; "match")]
+#[naming(keyword_prefix = "kw_")]
+fn prefixed_keywords(word: &str) {
+    assert!(!word.is_empty())
+}
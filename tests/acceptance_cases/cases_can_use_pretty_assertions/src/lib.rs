@@ -0,0 +1,26 @@
+#![cfg(test)]
+
+use test_case::test_case;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub name: String,
+    pub retries: u32,
+    pub tags: Vec<&'static str>,
+}
+
+#[test_case(3 => Config { name: "svc".into(), retries: 3, tags: vec!["a", "b"] })]
+#[test_case(4 => Config { name: "svc".into(), retries: 3, tags: vec!["a", "b"] })]
+fn config(retries: u32) -> Config {
+    Config {
+        name: "svc".into(),
+        retries,
+        tags: vec!["a", "b"],
+    }
+}
+
+#[test_case("a\nb\nc" => "a\nb\nc")]
+#[test_case("a\nB\nc" => "a\nb\nc" ; "differs in one line")]
+fn multiline(s: &str) -> &str {
+    s
+}
@@ -11,3 +11,17 @@ use test_case::test_case;
 fn regex_test(text: &str) -> &str {
     text
 }
+
+#[test_case(1 => matches_regex r#"^user-\d+$"#)]
+#[test_case(2 => matching_regex r#"^user-2$"#)]
+#[test_case(3 => is not matches_regex r#"^admin-"#)]
+fn regex_owned_string(id: u32) -> String {
+    format!("user-{id}")
+}
+
+#[test_case(5 => panics matches_regex r"the index is \d+")]
+#[test_case(1 => panics matches_regex r"the index is \d+")]
+#[test_case(7 => panics not matching_regex r"the len is [3-9]")]
+fn regex_panic_message(index: usize) -> u8 {
+    [1u8, 2][index]
+}
@@ -0,0 +1,20 @@
+#![cfg(test)]
+use std::thread::sleep;
+use std::time::Duration;
+use test_case::{test_case, test_matrix};
+
+#[test_case(1 => warn_if_slower_than = 1s 1)]
+#[test_case(100 => warn_if_slower_than = 10ms 100)]
+#[test_case(200 => warn_if_slower_than 10ms panics "too slow and failing")]
+fn sleeping(millis: u64) -> u64 {
+    sleep(Duration::from_millis(millis));
+    if millis > 100 {
+        panic!("too slow and failing")
+    }
+    millis
+}
+
+#[test_matrix([1, 2], [0, 50] => warn_if_slower_than = 20ms)]
+fn combinations(_: u32, millis: u64) {
+    sleep(Duration::from_millis(millis))
+}
@@ -0,0 +1,11 @@
+#![cfg(test)]
+use test_case::test_case;
+
+#[test_case(1, 'a' => inconclusive["unstable on {name}"] 1)]
+#[test_case(2, 'b' => inconclusive 2 ; "without reason")]
+#[test_case(3, 'c' => ignore["known issue"] 3)]
+#[test_case(4, 'd' => 4)]
+fn reported(x: u8, name: char) -> u8 {
+    let _ = name;
+    x
+}
@@ -131,4 +131,46 @@ mod test_cases {
     fn bar(_: &str) -> String {
         String::default()
     }
+
+    #[derive(Debug)]
+    pub struct Meters(pub f64);
+
+    impl test_case::TestEq for Meters {
+        fn test_eq(&self, other: &Self) -> bool {
+            (self.0 - other.0).abs() < 1e-9
+        }
+    }
+
+    #[test_case(0.1, 0.2 => Meters(0.3))]
+    #[test_case(1.0, 2.5 => Meters(3.5))]
+    fn custom_test_eq(x: f64, y: f64) -> Meters {
+        Meters(x + y)
+    }
+
+    /// Tags compared regardless of their order, without `PartialEq`
+    #[derive(Debug)]
+    pub struct Tags(Vec<&'static str>);
+
+    impl test_case::TestEq for Tags {
+        fn test_eq(&self, other: &Self) -> bool {
+            let mut left = self.0.clone();
+            let mut right = other.0.clone();
+            left.sort_unstable();
+            right.sort_unstable();
+            left == right
+        }
+    }
+
+    #[test_case("b,a" => Tags(vec!["a", "b"]))]
+    #[test_case("c,a,b" => Tags(vec!["a", "b", "c"]))]
+    fn tags_test_eq(tags: &'static str) -> Tags {
+        Tags(tags.split(',').collect())
+    }
+
+    #[test_case(3, 4 => a + b)]
+    #[test_case(5, 6 => is gt a and leq a + b)]
+    #[test_case(7, 8 => matches n if n > a.max(b) ; "guard refers to arguments")]
+    fn expected_refers_to_arguments(a: u8, b: u8) -> u8 {
+        a + b
+    }
 }
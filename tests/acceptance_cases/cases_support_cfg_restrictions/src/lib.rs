@@ -0,0 +1,26 @@
+#![cfg(test)]
+use test_case::test_case;
+
+#[test_case("a/b" => only(unix) "a/b" ; "native separator")]
+#[test_case("a/b" => only(windows) r"a\b" ; "native separator")]
+fn native_path(path: &str) -> String {
+    std::path::Path::new(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join(&std::path::MAIN_SEPARATOR.to_string())
+}
+
+#[test_case(1 => only(test) 1)]
+#[test_case(2 => only(miri) 3)]
+#[test_case(3 => only(not(miri)) 3)]
+fn compiled_out(x: u8) -> u8 {
+    x
+}
+
+#[test_case("sqlite" => requires_feature = "sqlite" true)]
+#[test_case("postgres" => requires_feature = "postgres" true)]
+#[test_case("sqlite" => requires_feature("sqlite") is eq true ; "parenthesized feature")]
+fn backend_available(name: &str) -> bool {
+    ["sqlite", "postgres"].contains(&name)
+}
@@ -18,6 +18,43 @@ fn complex_tests(input: f64) -> f64 {
     input * 2.0
 }
 
+#[test_case(98.5 => is close_to 100.0 within 2%)]
+#[test_case(0.0 => is close_to 0.0 within 1%)]
+#[test_case(97.0 => is not close_to 100.0 within 2%)]
+fn close_to_f64(input: f64) -> f64 {
+    input
+}
+
+#[test_case(1000 => is close_to 1010 within 1%)]
+#[test_case(1000 => is close_to 1100 within 5%)]
+fn close_to_u32(input: u32) -> u32 {
+    input
+}
+
+#[test_case(0.1 => is ulps_eq 0.3)]
+#[test_case(0.1 => is ulps_eq 0.3, 1)]
+#[test_case(0.1 => is ulps_eq 0.3 max_ulps 0)]
+#[test_case(0.1 => is (ulps_eq 0.3, 1) and (gt 0.2))]
+#[test_case(0.1 => is not ulps_eq 0.31)]
+fn ulps_f64(input: f64) -> f64 {
+    input * 3.0
+}
+
+#[test_case(0.1 => is ulps_eq 0.3, 2)]
+fn ulps_f32(input: f32) -> f32 {
+    input * 3.0
+}
+
+#[test_case(f64::NAN => is eq_nan f64::NAN)]
+#[test_case(1.5 => is eq_nan 1.5)]
+#[test_case(1.5 => is not eq_nan f64::NAN)]
+#[test_case(f64::NAN => is bitwise_eq f64::NAN)]
+#[test_case(0.0 => is not bitwise_eq -0.0)]
+#[test_case(f64::NAN => is eq 2.0 or eq_nan f64::NAN)]
+fn nan_aware(input: f64) -> f64 {
+    input
+}
+
 #[test_case("Cargo.toml" => is existing_path)]
 #[test_case("src/lib.rs" => is file)]
 #[test_case("src/" => is dir ; "short_dir")]
@@ -32,6 +69,173 @@ fn contains_tests(items: Vec<u64>) -> Vec<u64> {
     items
 }
 
+#[test_case(&[1, 2, 3] => it contains 2)]
+#[test_case(&[1, 2, 3] => it contains 5)]
+fn contains_slice(items: &[i32]) -> &[i32] {
+    items
+}
+
+#[test_case(&[1, 2, 3] => it contains 3)]
+#[test_case(&[1, 2, 3] => it not contains 4)]
+fn contains_hash_set(items: &[i32]) -> std::collections::HashSet<i32> {
+    items.iter().copied().collect()
+}
+
+#[test_case(&[1, 2, 3, 4, 5] => it contains_in_order [1, 3, 5])]
+#[test_case(&[1, 2, 3, 4, 5] => it contains_in_order vec![2, 5])]
+#[test_case(&[1, 2, 3] => it contains_in_order [1, 2, 3, 4])]
+#[test_case(&[1, 2, 3] => it not contains_in_order [3, 1])]
+fn contains_in_order_slice(items: &[i32]) -> &[i32] {
+    items
+}
+
+#[test_case(&[1, 2, 3] => it eq_unordered [3, 1, 2])]
+#[test_case(&[1, 2] => it not eq_unordered [2, 1, 3])]
+fn eq_unordered_hash_set(items: &[i32]) -> std::collections::HashSet<i32> {
+    items.iter().copied().collect()
+}
+
+#[test_case(&[1, 2, 2] => it eq_unordered vec![2, 1, 2])]
+#[test_case(&[1, 2, 2] => it eq_unordered [1, 1, 2])]
+fn eq_unordered_vec(items: &[i32]) -> Vec<i32> {
+    items.to_vec()
+}
+
+fn is_positive(x: &i32) -> bool {
+    *x > 0
+}
+
+#[test_case(&[1, 2, 3] => it all is_positive)]
+#[test_case(&[1, 0, 3] => it all is_positive)]
+#[test_case(&[1, 2, 3] => it all |x| *x < 10)]
+#[test_case(&[1, 2, 3] => it all |&x| x > 0 and len 3)]
+#[test_case(&[1, 2, 3] => it not all |x| *x % 2 == 1)]
+#[test_case(&[1, 2, 3] => is all |x| *x > 0)]
+fn all_vec(items: &[i32]) -> Vec<i32> {
+    items.to_vec()
+}
+
+#[test_case(&[0, 3] => it any is_positive)]
+#[test_case(&[-1, 0] => it any is_positive)]
+#[test_case(&[1, 2, 3] => it any |x| *x == 2 and all is_positive)]
+#[test_case(&[1, 2, 3] => it not any |&x| x > 5)]
+#[test_case(&[1, 2, 3] => is any |x| *x > 2)]
+fn any_vec(items: &[i32]) -> Vec<i32> {
+    items.to_vec()
+}
+
+#[test_case(Some(42) => is some eq 42)]
+#[test_case(Some(5) => is some (gt 3 and lt 10))]
+#[test_case(Some(1) => is some gt 3)]
+#[test_case(None => is some eq 1)]
+#[test_case(None => is not some eq 1)]
+fn some_i32(v: Option<i32>) -> Option<i32> {
+    v
+}
+
+#[test_case(Some(1.0) => is some close_to 1.01 within 2%)]
+fn some_f64(v: Option<f64>) -> Option<f64> {
+    v
+}
+
+#[test_case(Some(vec![1, 2]) => is some (len 2 and contains 2))]
+#[test_case(Some(vec![1, 2]) => is some all is_positive)]
+#[test_case(Some(vec![1, 0]) => is some all is_positive)]
+fn some_vec(v: Option<Vec<i32>>) -> Option<Vec<i32>> {
+    v
+}
+
+#[test_case(Some("ab".to_string()) => is some (len 2) and some (eq "ab"))]
+#[test_case(Some("abc".to_string()) => is some (len 2) or some (eq "abc"))]
+#[test_case(Some("abc".to_string()) => is some (len 2) and some (eq "abc"))]
+fn some_string(v: Option<String>) -> Option<String> {
+    v
+}
+
+#[derive(Debug)]
+pub enum MyError {
+    Timeout { secs: u64 },
+    Other,
+}
+
+#[test_case(Ok(7) => is ok eq 7)]
+#[test_case(Ok(7) => is ok (gt 5 and lt 10))]
+#[test_case(Ok(7) => is err matches MyError::Other)]
+#[test_case(Err(MyError::Timeout { secs: 5 }) => is err matches MyError::Timeout { .. })]
+#[test_case(Err(MyError::Timeout { secs: 5 }) => is err matches MyError::Other)]
+#[test_case(Err(MyError::Other) => is ok eq 7)]
+#[test_case(Err(MyError::Other) => is not ok eq 7)]
+#[test_case(Ok(7) => is matches Ok(7))]
+#[test_case(Ok(7) => is matches Ok(n) if n > 5)]
+#[test_case(Ok(7) => is ok matches n if n > 10)]
+#[test_case(Err(MyError::Timeout { secs: 5 }) => is err matches MyError::Timeout { secs } if secs < 10)]
+fn ok_err(v: Result<u32, MyError>) -> Result<u32, MyError> {
+    v
+}
+
+#[test_case(Ok("ab".to_string()) => is ok (len 2) and ok (eq "ab"))]
+#[test_case(Err(MyError::Timeout { secs: 5 }) => is err (matches MyError::Timeout { .. }) and err (matches MyError::Timeout { secs } if secs < 10))]
+fn ok_err_string(v: Result<String, MyError>) -> Result<String, MyError> {
+    v
+}
+
+#[test_case(50 => is gt 0 and lt 100)]
+#[test_case(150 => is gt 0 and lt 100)]
+#[test_case(2 => is eq 1 or eq 2)]
+#[test_case(3 => is eq 1 or eq 2)]
+#[test_case(200 => is eq 200 or gt 0 and lt 100)]
+#[test_case(50 => is eq 200 or gt 0 and lt 100)]
+#[test_case(50 => is not eq 1 and not eq 2)]
+fn precedence(v: i32) -> i32 {
+    v
+}
+
+#[test_case(50 => in 0..100)]
+#[test_case(100 => in 0..100)]
+#[test_case(100 => in 1..=100)]
+#[test_case(7 => is in ..10 and not in 3..5)]
+fn range_i32(v: i32) -> i32 {
+    v
+}
+
+#[test_case(0.5 => in 0.0..=1.0)]
+#[test_case(1.5 => in 0.0..=1.0)]
+#[test_case(1.5 => in 1.0..)]
+fn range_f64(v: f64) -> f64 {
+    v
+}
+
+pub struct User {
+    pub id: u32,
+    pub name: String,
+}
+
+fn same_name(actual: &User, expected: &&str) -> bool {
+    actual.name == *expected
+}
+
+#[test_case(1, "alice" => cmp same_name, "alice")]
+#[test_case(2, "bob" => cmp same_name, "alice")]
+#[test_case(4, "bob" => is (cmp same_name, "bob") and (cmp |u: &User, id: &u32| u.id == *id, 4))]
+#[test_case(3, "bob" => is cmp |u: &User, id: &u32| u.id == *id, 3 and cmp same_name, "bob")]
+fn cmp_user(id: u32, name: &str) -> User {
+    User {
+        id,
+        name: name.to_string(),
+    }
+}
+
+#[test_case(&["a", "b"] => not contains "error")]
+#[test_case(&["error"] => not contains "error")]
+fn bare_not(items: &[&'static str]) -> Vec<&'static str> {
+    items.to_vec()
+}
+
+#[test_case(&["a", "b"] => it contains "b")]
+fn contains_strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
 #[test_case(1.0 => is not eq 2.5)]
 #[test_case(1.0 => is not almost 2.1 precision 0.01)]
 fn not_complex(input: f32) -> f32 { input * 1.0 }
@@ -43,6 +247,38 @@ fn not_path(path: std::path::PathBuf) -> String {
     path.to_string_lossy().to_string()
 }
 
+#[test_case("user-42" => it starts_with "user-")]
+#[test_case("user-42" => it ends_with "42")]
+#[test_case("user-42" => it starts_with "user" and ends_with "42")]
+#[test_case("user-42" => it not starts_with "admin")]
+#[test_case("admin-42" => it starts_with "user-")]
+fn affix_string(s: &str) -> String {
+    s.to_string()
+}
+
+#[test_case("hello" => is eq_ignore_case "HELLO")]
+#[test_case("hello" => is eq_ignore_case "HeLLo".to_string())]
+#[test_case("żółć" => is eq_ignore_case unicode "ŻÓŁĆ")]
+#[test_case("żółć" => is eq_ignore_case "ŻÓŁĆ")]
+fn ignore_case(s: &str) -> &str {
+    s
+}
+
+#[test_case("  hello world\n" => is eq_trimmed "hello world")]
+#[test_case("hello  world" => is eq_trimmed "hello world")]
+#[test_case("  a\n\t b   c \r\n" => is eq_normalized_ws "a b c")]
+#[test_case("a b c".to_string() => is eq_normalized_ws "  a  b\n c")]
+#[test_case("abc" => is eq_normalized_ws "a b c")]
+fn whitespace(s: impl AsRef<str>) -> String {
+    s.as_ref().to_string()
+}
+
+#[test_case(vec![1, 2, 3] => it starts_with &[1, 2])]
+#[test_case(vec![1, 2, 3] => it ends_with &[3])]
+fn affix_vec(v: Vec<u8>) -> Vec<u8> {
+    v
+}
+
 #[test_case(vec![1, 2, 3, 4] => it not contains 5)]
 #[test_case(vec![1, 2, 3, 4] => it not contains_in_order [3, 2])]
 fn not_contains_tests(items: Vec<u64>) -> Vec<u64> {
@@ -77,10 +313,17 @@ fn combinators_with_arrays(a: Vec<u8>) -> Vec<u8> {
 
 #[test_case(vec![0, 1, 3] => is len 3)]
 #[test_case(vec![0, 1] => it has_length 2)]
+#[test_case(vec![0, 1, 3] => it has_length gt 2)]
+#[test_case(vec![0, 1, 3] => it has_length leq 2)]
 fn len_vec(v: Vec<u8>) -> Vec<u8> {
     v
 }
 
+#[test_case(&[0, 1, 3, 4] => it has_length geq 3)]
+fn len_exact_size_iter(v: &[u8]) -> std::slice::Iter<'_, u8> {
+    v.iter()
+}
+
 #[test_case("abc" => is len 3)]
 #[test_case("ab" => it has_length 2)]
 fn len_str(v: &str) -> &str {
@@ -101,6 +344,7 @@ fn len_byte_str(v: &[u8]) -> &[u8] {
 
 #[test_case(vec![0, 1, 3] => is count 3)]
 #[test_case(vec![0, 1] => it has_count 2)]
+#[test_case(vec![0, 1] => it has_count lt 3)]
 fn count_vec(v: Vec<u8>) -> Vec<u8> {
     v
 }
@@ -117,3 +361,140 @@ fn count_general<T>(v: impl IntoIterator<Item = T>) -> impl IntoIterator<Item =
 fn empty(v: Vec<u8>) -> Vec<u8> {
     v
 }
+
+#[test_case("" => is empty)]
+#[test_case("a" => is not empty)]
+#[test_case(" " => is empty ; "whitespace is not empty")]
+fn empty_str(s: &str) -> String {
+    s.to_string()
+}
+
+#[test_case(&[] => is empty)]
+#[test_case(&[(1, 2)] => is not empty)]
+fn empty_map(entries: &[(u8, u8)]) -> std::collections::HashMap<u8, u8> {
+    entries.iter().copied().collect()
+}
+
+#[test_case(&[("id", 7), ("age", 30)] => it has_key "id")]
+#[test_case(&[("age", 30)] => it has_key "id")]
+#[test_case(&[("id", 7)] => it has_entry ("id", 7))]
+#[test_case(&[("id", 8)] => it has_entry ("id", 7))]
+#[test_case(&[("id", 7), ("age", 30)] => it has_key "id" and has_entry ("age", 30))]
+fn has_entry_hash_map(entries: &[(&str, u32)]) -> std::collections::HashMap<String, u32> {
+    entries.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+}
+
+#[test_case(&[(1, 'a')] => it has_key 1)]
+#[test_case(&[(1, 'a'), (2, 'b')] => it has_entry (2, 'b'))]
+fn has_entry_btree_map(entries: &[(u8, char)]) -> std::collections::BTreeMap<u8, char> {
+    entries.iter().copied().collect()
+}
+
+#[derive(Debug)]
+pub struct Measurement {
+    pub id: u32,
+    pub name: String,
+    pub value: f64,
+    pub tags: Vec<String>,
+}
+
+#[test_case(3, "x", 10.1 => has_fields { id: 3, name: "x" })]
+#[test_case(3, "y", 10.2 => has_fields { id: 3, name: "x" })]
+#[test_case(4, "x", 10.3 => has_fields { id: 1 + 3, value: close_to 10.0 within 5% })]
+#[test_case(4, "x", 12.0 => has_fields { id: 1 + 3, value: close_to 10.0 within 5% })]
+#[test_case(5, "x", 10.0 => has_fields { tags: contains "x".to_string() and len 1 })]
+fn has_fields_struct(id: u32, name: &str, value: f64) -> Measurement {
+    Measurement {
+        id,
+        name: name.to_string(),
+        value,
+        tags: vec![name.to_string()],
+    }
+}
+
+#[test_case(3, "x" => has_fields { id: 3, name: "x" } and has_fields { name: "x" })]
+#[test_case(3, "y" => has_fields { name: "x" } or has_fields { name: "y", tags: len 1 })]
+fn has_fields_reused(id: u32, name: &str) -> Measurement {
+    has_fields_struct(id, name, 0.0)
+}
+
+#[test_case(3, "x" => has_fields { id: 3, name: "x", tags: len 1 })]
+fn has_fields_borrowed(id: u32, name: &str) -> &'static Measurement {
+    Box::leak(Box::new(has_fields_struct(id, name, 0.0)))
+}
+
+#[test_case(1 => it has_fields { 0: 1, 1: gt 1 })]
+fn has_fields_tuple(x: u8) -> (u8, u8) {
+    (x, x + 1)
+}
+
+#[derive(Debug)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[test_case(1, 2 => debug_eq "Point { x: 1, y: 2 }")]
+#[test_case(2, 2 => debug_eq "Point { x: 1, y: 2 }")]
+#[test_case(3, 4 => is debug_eq format!("Point {{ x: {}, y: {} }}", 3, 4) and has_fields { y: 4 })]
+fn debug_eq_point(x: i32, y: i32) -> Point {
+    Point { x, y }
+}
+
+#[test_case(0 => debug_eq "Some(0)")]
+fn debug_eq_option(x: u8) -> Option<u8> {
+    Some(x)
+}
+
+#[test_case("AbC" => via str::to_lowercase eq "abc")]
+#[test_case("XyZ" => via str::to_lowercase eq "XyZ")]
+#[test_case("AbCd" => map |r| r.len() => eq 3)]
+#[test_case("xyz" => is via |r| r.len() eq 3 and via str::to_uppercase starts_with "X")]
+#[test_case("xyz" => via |r| r.chars().next() some eq 'x')]
+fn via_string(s: &str) -> String {
+    s.to_string()
+}
+
+#[test_case(1, 0.75 => (eq 1, gt 0.5, matches Some(_)))]
+#[test_case(2, 0.25 => (eq 1, gt 0.5, matches Some(_)))]
+#[test_case(3, 1.0 => is (3, lt 2.0, some eq 3) and (gt 0, geq 1.0, matches Some(3)))]
+fn tuple_elements(x: u8, y: f64) -> (u8, f64, Option<u8>) {
+    (x, y, Some(x))
+}
+
+#[test_case("ab", 2 => is (eq "ab", 2))]
+#[test_case("ab", 2 => is (eq "ab", gt 1) and (len 2, lt 3))]
+#[test_case("cd", 3 => is (eq "ab", 2) or (eq "cd", 3))]
+#[test_case("ab", 3 => is (eq "ab", 2) or (eq "cd", 2))]
+fn tuple_borrowed(text: &str, n: u8) -> &'static (String, u8) {
+    Box::leak(Box::new((text.to_string(), n)))
+}
+
+#[test_case(() => is (eq std::collections::HashMap::<u8, u8>::new()))]
+#[test_case(() => is (len 0) and (eq std::collections::HashMap::<u8, u8>::from([(1, 2)])))]
+fn parenthesized_generic_matcher(_: ()) -> std::collections::HashMap<u8, u8> {
+    std::collections::HashMap::new()
+}
+
+use std::time::Duration;
+
+#[test_case(1000 => within Duration::from_millis(50) of Duration::from_secs(1))]
+#[test_case(1030 => within Duration::from_millis(50) of Duration::from_secs(1))]
+#[test_case(1100 => within Duration::from_millis(50) of Duration::from_secs(1))]
+#[test_case(900 => is not within Duration::from_millis(50) of Duration::from_secs(1))]
+fn duration_within(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+#[test_case(10 => it within 5 of 12)]
+#[test_case(20 => it within 5 of 12)]
+fn integer_within(x: i32) -> i32 {
+    x
+}
+
+#[test_case(&[0xca, 0xfe] => bytes_eq [0xca, 0xfe])]
+#[test_case(&[0xca, 0xfe, 0xba] => bytes_eq [0xca, 0xfe, 0xbe])]
+#[test_case(b"abc" => bytes_eq b"abcd")]
+fn bytes_vec(bytes: &[u8]) -> Vec<u8> {
+    bytes.to_vec()
+}
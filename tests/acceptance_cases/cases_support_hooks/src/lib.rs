@@ -0,0 +1,27 @@
+#![cfg(test)]
+use std::cell::Cell;
+use std::env;
+use test_case::test_case;
+
+thread_local! {
+    static CLEANED_UP: Cell<bool> = Cell::new(false);
+}
+
+fn clean_up() {
+    CLEANED_UP.with(|cleaned_up| cleaned_up.set(true));
+}
+
+#[test_case("HOOKS_ENABLED" => before = env::set_var("HOOKS_ENABLED", "yes") after = env::remove_var("HOOKS_ENABLED") Some("yes".to_string()))]
+#[test_case("HOOKS_DISABLED" => None)]
+fn env_var(name: &str) -> Option<String> {
+    env::var(name).ok()
+}
+
+#[test_case(true => after = clean_up() panics "failed")]
+#[test_case(false => after = clean_up() false ; "after runs once body returned")]
+fn cleaned_up(fail: bool) -> bool {
+    if fail {
+        panic!("failed")
+    }
+    CLEANED_UP.with(Cell::get)
+}
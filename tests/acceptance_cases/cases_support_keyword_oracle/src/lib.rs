@@ -0,0 +1,56 @@
+#![cfg(test)]
+use test_case::{test_case, test_matrix};
+
+mod naive {
+    pub fn sum_to(n: u64) -> u64 {
+        (1..=n).sum()
+    }
+
+    pub fn sorted(mut v: Vec<i32>) -> Vec<i32> {
+        v.sort();
+        v
+    }
+}
+
+fn buggy_sum_to(n: u64) -> u64 {
+    if n == 3 {
+        return 0;
+    }
+    n * (n + 1) / 2
+}
+
+#[test_case(0 => oracle naive::sum_to)]
+#[test_case(10 => oracle naive::sum_to)]
+#[test_case(1000 => oracle naive::sum_to)]
+fn sum_to(n: u64) -> u64 {
+    n * (n + 1) / 2
+}
+
+#[test_case(2 => oracle naive::sum_to)]
+#[test_case(3 => oracle naive::sum_to)]
+#[test_case(4 => oracle |n| buggy_sum_to(n) + 1)]
+fn buggy(n: u64) -> u64 {
+    buggy_sum_to(n)
+}
+
+#[test_case(vec![3, 1, 2] => oracle naive::sorted)]
+#[test_case(vec![] => oracle naive::sorted)]
+fn insertion_sort(mut v: Vec<i32>) -> Vec<i32> {
+    for i in 1..v.len() {
+        let mut j = i;
+        while j > 0 && v[j - 1] > v[j] {
+            v.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+    v
+}
+
+#[test_matrix(
+    [1, 2, 5],
+    [0, 3]
+    => oracle i64::pow
+)]
+fn fast_pow(base: i64, exp: u32) -> i64 {
+    (0..exp).fold(1, |acc, _| acc * base)
+}
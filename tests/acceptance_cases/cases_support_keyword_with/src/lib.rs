@@ -1,4 +1,5 @@
 #![cfg(test)]
+use std::sync::atomic::{AtomicU32, Ordering};
 use test_case::test_case;
 
 #[test_case(1.0 => with |v: f64| assert!(v.is_infinite()))]
@@ -6,3 +7,72 @@ use test_case::test_case;
 fn divide_by_zero_f64_with_lambda(input: f64) -> f64 {
     input / 0.0f64
 }
+
+#[test_case(3 => with |result| {
+    assert!(result > input);
+    assert!(result < input * 2);
+})]
+#[test_case(10 => with |result| {
+    assert!(result > input);
+    assert!(result < input * 2);
+})]
+fn grows_but_not_doubles(input: u32) -> u32 {
+    input + 4
+}
+
+#[test_case(vec![3, 1, 2], "xyz" => with |sorted| {
+    assert_eq!(sorted.len(), v.len());
+    assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(label.len(), 3);
+})]
+fn sort_keeps_elements(mut v: Vec<i32>, label: &str) -> Vec<i32> {
+    v.sort();
+    let _ = label;
+    v
+}
+
+fn at_least(min: usize) -> impl Fn(usize) {
+    move |len| assert!(len >= min)
+}
+
+#[test_case("abc", 3 => using at_least(n))]
+#[test_case("a", 3 => using at_least(n))]
+fn len_against_argument(s: &str, n: usize) -> usize {
+    let _ = n;
+    s.len()
+}
+
+#[test_case([1, 2] => with |r| assert_eq!(r.len(), items.len()))]
+fn generic_argument<T: Clone, const N: usize>(items: [T; N]) -> Vec<T> {
+    items.to_vec()
+}
+
+#[test_case([1, 2, 3] => with |r: usize| assert_eq!(r, bytes.len()))]
+fn const_generic_argument<const N: usize>(bytes: [u8; N]) -> usize {
+    N
+}
+
+static EVALUATIONS: AtomicU32 = AtomicU32::new(0);
+
+fn evaluated(value: u32) -> u32 {
+    EVALUATIONS.fetch_add(1, Ordering::SeqCst);
+    value
+}
+
+#[test_case(evaluated(5) => with |result| {
+    assert_eq!(result, value * 2);
+    assert_eq!(EVALUATIONS.load(Ordering::SeqCst), 1);
+})]
+fn argument_evaluated_once(value: u32) -> u32 {
+    value * 2
+}
+
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test_case(&Point { x: 1, y: 2 } => with |sum| assert_eq!(sum, point.x + point.y))]
+fn temporary_argument(point: &Point) -> i32 {
+    point.x + point.y
+}
@@ -0,0 +1,18 @@
+#![cfg(test)]
+use test_case::test_case;
+
+pub struct Local;
+
+pub trait Shape {}
+
+#[test_case(Local => compile_fail)]
+fn not_standalone(_: Local) {}
+
+#[test_case(1 => compile_fail)]
+fn bound_of_crate<T: crate::Shape>(_: T) {}
+
+#[test_case("1" => compile_fail "mismatched types")]
+fn declared_within(x: u8) -> u8 {
+    struct Wrapper(u8);
+    Wrapper(x).0
+}
@@ -0,0 +1,17 @@
+#![cfg(test)]
+use test_case::{test_case, test_matrix};
+
+#[test_case(1 => 1)]
+#[test_case(2 => 2 ; "same")]
+#[test_case(3 => 3 ; "same")]
+fn same_descriptions(x: u8) -> u8 {
+    x
+}
+
+#[test_case("a b")]
+#[test_case("a-b")]
+fn arguments_escaped_to_same_name(_: &str) {}
+
+#[test_matrix([1, 2], [3, 4])]
+#[test_case::naming(name = "case {arg0}")]
+fn template_without_all_arguments(_: u8, _: u8) {}
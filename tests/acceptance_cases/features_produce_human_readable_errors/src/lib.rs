@@ -6,3 +6,23 @@ use test_case::test_case;
 fn fail_on_missing_with_regex_feature(_: u8) -> String {
     todo!()
 }
+
+#[test_case(2.0 => is abs_diff_eq 2.0)]
+fn fail_on_missing_with_approx_feature(_: f64) -> f64 {
+    todo!()
+}
+
+#[test_case(2 => max_alloc = 1KB 2)]
+fn fail_on_missing_with_max_alloc_feature(_: u8) -> u8 {
+    todo!()
+}
+
+#[test_case(2 => local_set 2)]
+async fn fail_on_missing_with_tokio_feature(_: u8) -> u8 {
+    todo!()
+}
+
+#[test_case(2 => rt(flavor = "multi_thread") 2)]
+async fn fail_on_missing_with_tokio_feature_for_rt(_: u8) -> u8 {
+    todo!()
+}
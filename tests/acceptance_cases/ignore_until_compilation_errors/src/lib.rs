@@ -0,0 +1,7 @@
+#![cfg(test)]
+use test_case::test_case;
+
+#[test_case(1 => ignore_until("next week") 1)]
+fn malformed_date(x: u8) -> u8 {
+    x
+}
@@ -8,11 +8,11 @@ use std::path::PathBuf;
 use std::process::Command;
 
 macro_rules! run_acceptance_test {
-    ($cmd:expr, $case_name:expr) => {
+    ($cmd:expr, $case_name:expr, [$($arg:expr),*]) => {
         with_settings!({snapshot_path => get_snapshot_directory()}, {
             let subcommand = Command::new("cargo")
                 .current_dir(PathBuf::from("tests").join("acceptance_cases").join($case_name))
-                .args(&[$cmd])
+                .args(&[$cmd, $($arg),*])
                 .output()
                 .expect("Failed to spawn cargo subcommand");
 
@@ -22,9 +22,12 @@ macro_rules! run_acceptance_test {
 
             let output = sanitize_lines(output);
 
-            insta::assert_display_snapshot!(output);
+            insta::assert_snapshot!(output);
         })
     };
+    ($cmd:expr, $case_name:expr) => {
+        run_acceptance_test!($cmd, $case_name, [])
+    };
     ($case_name:expr) => {
         run_acceptance_test!("test", $case_name)
     }
@@ -40,6 +43,8 @@ fn get_snapshot_directory() -> String {
 
 fn sanitize_lines(s: String) -> String {
     let re_time = Regex::new(r"\d+\.\d{2}s").expect("Building regex");
+    let re_thread_id = Regex::new(r"' \(\d+\) panicked at").expect("Building regex");
+    let re_elapsed = Regex::new(r"elapsed=\S+").expect("Building regex");
 
     let mut s = s
         .lines()
@@ -53,6 +58,8 @@ fn sanitize_lines(s: String) -> String {
         .map(|line| line.replace('\\', "/"))
         .map(|line| line.replace(".exe", ""))
         .map(|line| re_time.replace_all(&line, "0.00s").to_string())
+        .map(|line| re_thread_id.replace_all(&line, "' panicked at").to_string())
+        .map(|line| re_elapsed.replace_all(&line, "elapsed=0ms").to_string())
         .collect::<Vec<_>>();
 
     s.sort_unstable();
@@ -110,6 +117,11 @@ fn cases_support_generics() {
     run_acceptance_test!("cases_support_generics")
 }
 
+#[test]
+fn cases_support_keyword_oracle() {
+    run_acceptance_test!("cases_support_keyword_oracle")
+}
+
 #[test]
 fn cases_support_keyword_using() {
     run_acceptance_test!("cases_support_keyword_using")
@@ -130,11 +142,36 @@ fn cases_support_pattern_matching() {
     run_acceptance_test!("cases_support_pattern_matching")
 }
 
+#[test]
+fn cases_can_exit_process() {
+    run_acceptance_test!("cases_can_exit_process")
+}
+
+#[test]
+fn cases_can_use_googletest() {
+    run_acceptance_test!("cases_can_use_googletest")
+}
+
+#[test]
+fn cases_can_use_json() {
+    run_acceptance_test!("cases_can_use_json")
+}
+
+#[test]
+fn cases_can_use_pretty_assertions() {
+    run_acceptance_test!("cases_can_use_pretty_assertions")
+}
+
 #[test]
 fn cases_can_use_regex() {
     run_acceptance_test!("cases_can_use_regex")
 }
 
+#[test]
+fn cases_can_use_approx() {
+    run_acceptance_test!("cases_can_use_approx")
+}
+
 #[test]
 fn features_produce_human_readable_errors() {
     run_acceptance_test!("features_produce_human_readable_errors")
@@ -149,3 +186,126 @@ fn allow_stays_on_fn() {
 fn matrices_compilation_errors() {
     run_acceptance_test!("matrices_compilation_errors")
 }
+
+#[test]
+fn ignore_until_compilation_errors() {
+    run_acceptance_test!("ignore_until_compilation_errors")
+}
+
+#[test]
+fn duplicated_names_compilation_errors() {
+    run_acceptance_test!("duplicated_names_compilation_errors")
+}
+
+#[test]
+fn cases_can_time_out() {
+    run_acceptance_test!("cases_can_time_out")
+}
+
+#[test]
+fn cases_can_be_retried() {
+    run_acceptance_test!("cases_can_be_retried")
+}
+
+#[test]
+fn cases_can_run_serially() {
+    run_acceptance_test!("cases_can_run_serially")
+}
+
+#[test]
+fn cases_can_be_skipped() {
+    run_acceptance_test!("cases_can_be_skipped")
+}
+
+#[test]
+fn cases_support_cfg_restrictions() {
+    run_acceptance_test!("cases_support_cfg_restrictions")
+}
+
+#[test]
+fn cases_can_be_tagged() {
+    run_acceptance_test!("cases_can_be_tagged")
+}
+
+#[test]
+fn cases_report_inconclusive() {
+    run_acceptance_test!(
+        "test",
+        "cases_report_inconclusive",
+        ["--", "--include-ignored", "--test-threads=1", "--nocapture"]
+    )
+}
+
+#[test]
+fn cases_can_be_ordered() {
+    run_acceptance_test!("test", "cases_can_be_ordered", ["--", "--test-threads=1"])
+}
+
+#[test]
+fn cases_support_hooks() {
+    run_acceptance_test!("cases_support_hooks")
+}
+
+#[test]
+fn cases_can_warn_when_slow() {
+    run_acceptance_test!(
+        "test",
+        "cases_can_warn_when_slow",
+        ["--", "--test-threads=1", "--nocapture"]
+    )
+}
+
+#[test]
+fn cases_can_limit_allocations() {
+    run_acceptance_test!("cases_can_limit_allocations")
+}
+
+#[test]
+fn cases_can_fail_to_compile() {
+    run_acceptance_test!("cases_can_fail_to_compile")
+}
+
+#[test]
+fn compile_fail_compilation_errors() {
+    run_acceptance_test!("compile_fail_compilation_errors")
+}
+
+#[test]
+fn cases_can_run_async_with_tokio() {
+    run_acceptance_test!("cases_can_run_async_with_tokio")
+}
+
+#[test]
+fn cases_can_declare_runner() {
+    run_acceptance_test!("cases_can_declare_runner")
+}
+
+#[test]
+fn cases_can_run_async_with_async_std() {
+    run_acceptance_test!("cases_can_run_async_with_async_std")
+}
+
+#[test]
+fn cases_can_block_on() {
+    run_acceptance_test!("cases_can_block_on")
+}
+
+#[test]
+fn cases_can_be_read_from_data_files() {
+    run_acceptance_test!("cases_can_be_read_from_data_files")
+}
+
+#[test]
+fn cases_can_be_shared() {
+    run_acceptance_test!("cases_can_be_shared")
+}
+
+#[test]
+fn cases_can_use_name_templates() {
+    run_acceptance_test!("cases_can_use_name_templates")
+}
+
+#[test]
+fn cases_can_run_with_wasm_bindgen_test() {
+    run_acceptance_test!("cases_can_run_with_wasm_bindgen_test")
+}